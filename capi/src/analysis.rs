@@ -1,9 +1,18 @@
 //! The analysis module provides a variety of functions for calculating
 //! information about runs.
 
-use super::time_span::{NullableOwnedTimeSpan, OwnedTimeSpan};
+use super::{
+    output_vec,
+    time_span::{NullableOwnedTimeSpan, OwnedTimeSpan},
+    Json,
+};
 use livesplit_core::{
-    analysis::{sum_of_segments::calculate_best, total_playtime::calculate},
+    analysis::{
+        distributions::{self, Options},
+        sum_of_segments::calculate_best,
+        total_playtime::calculate,
+        trends::{self, Bucketing},
+    },
     Run, Timer, TimingMethod,
 };
 
@@ -38,3 +47,50 @@ pub extern "C" fn Analysis_calculate_total_playtime_for_run(run: &Run) -> OwnedT
 pub extern "C" fn Analysis_calculate_total_playtime_for_timer(timer: &Timer) -> OwnedTimeSpan {
     Box::new(calculate(timer))
 }
+
+/// Calculates the trends of the passed Run for the timing method provided and
+/// encodes them as JSON. The attempt history is split into buckets that
+/// either consist of the specified number of attempts or, if `by_days` is
+/// <TRUE>, of the attempts started within the specified number of days. For
+/// each bucket and segment, statistics like the median segment time, its
+/// standard deviation, the reset rate and the improvement rate are provided.
+#[no_mangle]
+pub extern "C" fn Analysis_calculate_trends_as_json(
+    run: &Run,
+    method: TimingMethod,
+    by_days: bool,
+    bucket_size: u32,
+) -> Json {
+    let bucketing = if by_days {
+        Bucketing::Days(bucket_size)
+    } else {
+        Bucketing::Attempts(bucket_size)
+    };
+    output_vec(|o| {
+        trends::calculate(run, method, bucketing)
+            .write_json(o)
+            .unwrap();
+    })
+}
+
+/// Calculates the distributions of the segment times and split times of each
+/// segment of the passed Run for the timing method provided and encodes them as
+/// JSON. Each distribution consists of a histogram with the specified number
+/// of bins, the 10th, 25th, 50th, 75th and 90th percentiles, the mean and the
+/// median.
+#[no_mangle]
+pub extern "C" fn Analysis_calculate_distributions_as_json(
+    run: &Run,
+    method: TimingMethod,
+    bins: u32,
+) -> Json {
+    let options = Options {
+        bins,
+        ..Default::default()
+    };
+    output_vec(|o| {
+        distributions::calculate(run, method, &options)
+            .write_json(o)
+            .unwrap();
+    })
+}
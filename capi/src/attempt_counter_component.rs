@@ -0,0 +1,54 @@
+//! The Attempt Counter Component is a component that shows how many attempts
+//! were started during the current session, today or overall, how many of
+//! them were completed, or how often the run got reset since the last personal
+//! best.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::attempt_counter::Component as AttemptCounterComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedAttemptCounterComponent = Box<AttemptCounterComponent>;
+
+/// Creates a new Attempt Counter Component.
+#[no_mangle]
+pub extern "C" fn AttemptCounterComponent_new() -> OwnedAttemptCounterComponent {
+    Box::new(AttemptCounterComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn AttemptCounterComponent_drop(this: OwnedAttemptCounterComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn AttemptCounterComponent_into_generic(
+    this: OwnedAttemptCounterComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn AttemptCounterComponent_state_as_json(
+    this: &AttemptCounterComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn AttemptCounterComponent_state(
+    this: &AttemptCounterComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(timer))
+}
@@ -0,0 +1,32 @@
+//! A Comparison Registry knows about all the kinds of Comparison Generators
+//! that can be recreated when parsing a splits file or that can be added to a
+//! Run through the Run Editor.
+
+use super::str;
+use livesplit_core::comparison::Registry as ComparisonRegistry;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedComparisonRegistry = Box<ComparisonRegistry>;
+
+/// Creates a new Comparison Registry that knows about all the Comparison
+/// Generators provided by livesplit-core.
+#[no_mangle]
+pub extern "C" fn ComparisonRegistry_new() -> OwnedComparisonRegistry {
+    Box::new(ComparisonRegistry::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ComparisonRegistry_drop(this: OwnedComparisonRegistry) {
+    drop(this);
+}
+
+/// Returns <TRUE> if the kind of Comparison Generator is registered.
+#[no_mangle]
+pub unsafe extern "C" fn ComparisonRegistry_contains(
+    this: &ComparisonRegistry,
+    kind: *const c_char,
+) -> bool {
+    this.contains(str(kind))
+}
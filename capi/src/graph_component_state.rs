@@ -1,7 +1,9 @@
 //! The state object describes the information to visualize for this component.
 //! All the coordinates are in the range 0..1.
 
-use livesplit_core::component::graph::State as GraphComponentState;
+use super::output_str;
+use livesplit_core::component::graph::{LineStyle, State as GraphComponentState};
+use std::os::raw::c_char;
 
 /// type
 pub type OwnedGraphComponentState = Box<GraphComponentState>;
@@ -102,3 +104,120 @@ pub extern "C" fn GraphComponentState_is_live_delta_active(this: &GraphComponent
 pub extern "C" fn GraphComponentState_is_flipped(this: &GraphComponentState) -> bool {
     this.is_flipped
 }
+
+/// Returns the amount of lines of additional comparisons to overlay on the
+/// graph.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_comparison_lines_len(this: &GraphComponentState) -> usize {
+    this.comparison_lines.len()
+}
+
+/// Accesses the name of the comparison of the line specified. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_comparison_line_name(
+    this: &GraphComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.comparison_lines[index].comparison)
+}
+
+/// Accesses the style of the line specified. It is either `Solid`, `Dashed`
+/// or `Dotted`. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_comparison_line_style(
+    this: &GraphComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(match this.comparison_lines[index].line_style {
+        LineStyle::Solid => "Solid",
+        LineStyle::Dashed => "Dashed",
+        LineStyle::Dotted => "Dotted",
+    })
+}
+
+/// Returns the amount of points of the line specified. Connect all of them to
+/// visualize the line. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_comparison_line_points_len(
+    this: &GraphComponentState,
+    index: usize,
+) -> usize {
+    this.comparison_lines[index].points.len()
+}
+
+/// Returns the x coordinate of the point of the line specified. You may not
+/// provide out of bounds indices.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_comparison_line_point_x(
+    this: &GraphComponentState,
+    line_index: usize,
+    point_index: usize,
+) -> f32 {
+    this.comparison_lines[line_index].points[point_index].x
+}
+
+/// Returns the y coordinate of the point of the line specified. You may not
+/// provide out of bounds indices.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_comparison_line_point_y(
+    this: &GraphComponentState,
+    line_index: usize,
+    point_index: usize,
+) -> f32 {
+    this.comparison_lines[line_index].points[point_index].y
+}
+
+/// Returns the amount of bars visualizing the deltas of the individual
+/// segments. Each bar spans from the x-axis to its y coordinate.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_bars_len(this: &GraphComponentState) -> usize {
+    this.bars.len()
+}
+
+/// Returns the x coordinate of the start of the bar specified. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_bar_start(this: &GraphComponentState, index: usize) -> f32 {
+    this.bars[index].start
+}
+
+/// Returns the x coordinate of the end of the bar specified. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_bar_end(this: &GraphComponentState, index: usize) -> f32 {
+    this.bars[index].end
+}
+
+/// Returns the y coordinate of the end of the bar specified that is not on
+/// the x-axis. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_bar_y(this: &GraphComponentState, index: usize) -> f32 {
+    this.bars[index].y
+}
+
+/// Returns the amount of labels of the horizontal grid lines.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_axis_labels_len(this: &GraphComponentState) -> usize {
+    this.axis_labels.len()
+}
+
+/// Returns the y coordinate of the grid line the label specified belongs to.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_axis_label_y(
+    this: &GraphComponentState,
+    index: usize,
+) -> f32 {
+    this.axis_labels[index].y
+}
+
+/// Accesses the text of the label specified. You may not provide an out of
+/// bounds index.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_axis_label_text(
+    this: &GraphComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.axis_labels[index].text)
+}
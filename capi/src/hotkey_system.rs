@@ -7,7 +7,9 @@
 use std::{os::raw::c_char, str::FromStr};
 
 use crate::{hotkey_config::OwnedHotkeyConfig, output_str, shared_timer::OwnedSharedTimer, str};
-use livesplit_core::{hotkey::KeyCode, HotkeySystem};
+use livesplit_core::{
+    component::input_display::Component as InputDisplayComponent, hotkey::KeyCode, HotkeySystem,
+};
 
 /// type
 pub type OwnedHotkeySystem = Box<HotkeySystem>;
@@ -70,6 +72,26 @@ pub extern "C" fn HotkeySystem_set_config(
     this.set_config(*config).is_ok()
 }
 
+/// Forwards the presses and releases of all the keys the Input Display
+/// Component visualizes to it. Connect the component before turning it into a
+/// generic component. Any previously connected component stops receiving key
+/// events. Returns <FALSE> if the operation failed.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_connect_input_display(
+    this: &mut HotkeySystem,
+    component: &InputDisplayComponent,
+) -> bool {
+    this.set_key_event_feed(component.key_event_feed().clone(), component.key_codes())
+        .is_ok()
+}
+
+/// Stops forwarding key events to the Input Display Component that was
+/// previously connected. Returns <FALSE> if the operation failed.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_disconnect_input_display(this: &mut HotkeySystem) -> bool {
+    this.clear_key_event_feed().is_ok()
+}
+
 /// Resolves the key according to the current keyboard layout.
 #[no_mangle]
 pub unsafe extern "C" fn HotkeySystem_resolve(
@@ -0,0 +1,47 @@
+//! The Image Component is a component that shows an image, such as a route
+//! map or character art, the icon of the current segment or the game's icon.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::image_component_state::OwnedImageComponentState;
+use livesplit_core::component::image::Component as ImageComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedImageComponent = Box<ImageComponent>;
+
+/// Creates a new Image Component.
+#[no_mangle]
+pub extern "C" fn ImageComponent_new() -> OwnedImageComponent {
+    Box::new(ImageComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ImageComponent_drop(this: OwnedImageComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn ImageComponent_into_generic(this: OwnedImageComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn ImageComponent_state_as_json(this: &mut ImageComponent, timer: &Timer) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn ImageComponent_state(
+    this: &mut ImageComponent,
+    timer: &Timer,
+) -> OwnedImageComponentState {
+    Box::new(this.state(timer))
+}
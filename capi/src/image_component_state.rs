@@ -0,0 +1,58 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::image::{AspectMode, ImageAlignment, State as ImageComponentState};
+use std::{os::raw::c_char, ptr};
+
+/// type
+pub type OwnedImageComponentState = Box<ImageComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ImageComponentState_drop(this: OwnedImageComponentState) {
+    drop(this);
+}
+
+/// The data of the image. This value is only specified whenever the image
+/// changes. If you explicitly want to query this value, remount the component.
+/// The buffer may be empty. This indicates that there is no image. If no
+/// change occurred, <NULL> is returned instead.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_image_change_ptr(this: &ImageComponentState) -> *const u8 {
+    this.image_change
+        .as_ref()
+        .map_or_else(ptr::null, |i| i.as_ptr())
+}
+
+/// The length of the image data.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_image_change_len(this: &ImageComponentState) -> usize {
+    this.image_change.as_ref().map_or(0, |i| i.len())
+}
+
+/// The size of the component.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_size(this: &ImageComponentState) -> u32 {
+    this.size
+}
+
+/// Describes how the image is fit into the space of the component. This is
+/// either `Fit` or `Stretch`.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_aspect_mode(this: &ImageComponentState) -> *const c_char {
+    output_str(match this.aspect_mode {
+        AspectMode::Fit => "Fit",
+        AspectMode::Stretch => "Stretch",
+    })
+}
+
+/// Describes how the image is aligned horizontally. This is either `Left`,
+/// `Center` or `Right`.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_alignment(this: &ImageComponentState) -> *const c_char {
+    output_str(match this.alignment {
+        ImageAlignment::Left => "Left",
+        ImageAlignment::Center => "Center",
+        ImageAlignment::Right => "Right",
+    })
+}
@@ -0,0 +1,48 @@
+//! The Input Display Component visualizes which keys are currently held down.
+//! The key presses are provided by a Hotkey System that the component got
+//! connected to via `HotkeySystem_connect_input_display`.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::input_display_component_state::OwnedInputDisplayComponentState;
+use livesplit_core::component::input_display::Component as InputDisplayComponent;
+
+/// type
+pub type OwnedInputDisplayComponent = Box<InputDisplayComponent>;
+
+/// Creates a new Input Display Component.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponent_new() -> OwnedInputDisplayComponent {
+    Box::new(InputDisplayComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn InputDisplayComponent_drop(this: OwnedInputDisplayComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponent_into_generic(
+    this: OwnedInputDisplayComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponent_state_as_json(this: &InputDisplayComponent) -> Json {
+    output_vec(|o| {
+        this.state().write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the key events received so far.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponent_state(
+    this: &InputDisplayComponent,
+) -> OwnedInputDisplayComponentState {
+    Box::new(this.state())
+}
@@ -0,0 +1,85 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::input_display::State as InputDisplayComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedInputDisplayComponentState = Box<InputDisplayComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_drop(this: OwnedInputDisplayComponentState) {
+    drop(this);
+}
+
+/// The number of keys shown in each row of the grid.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_keys_per_row(
+    this: &InputDisplayComponentState,
+) -> u32 {
+    this.keys_per_row
+}
+
+/// Returns the amount of keys to visualize.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_len(this: &InputDisplayComponentState) -> usize {
+    this.keys.len()
+}
+
+/// Returns the label of the key specified. You may not provide an out of
+/// bounds index.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_label(
+    this: &InputDisplayComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.keys[index].label)
+}
+
+/// Returns the number of times the key specified got pressed. This is empty if
+/// the press counts are not supposed to be shown. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_press_count(
+    this: &InputDisplayComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.keys[index].press_count)
+}
+
+/// Returns whether the key specified is currently held down. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_is_pressed(
+    this: &InputDisplayComponentState,
+    index: usize,
+) -> bool {
+    this.keys[index].is_pressed
+}
+
+/// Returns whether the recently pressed keys are shown below the grid.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_show_recent_keys(
+    this: &InputDisplayComponentState,
+) -> bool {
+    this.show_recent_keys
+}
+
+/// Returns the amount of recently pressed keys to show.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_recent_keys_len(
+    this: &InputDisplayComponentState,
+) -> usize {
+    this.recent_keys.len()
+}
+
+/// Returns the label of the recently pressed key specified. The most recently
+/// pressed key has the index 0. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn InputDisplayComponentState_recent_key(
+    this: &InputDisplayComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.recent_keys[index])
+}
@@ -11,9 +11,12 @@ use livesplit_core::{
     component::{
         blank_space::State as BlankSpaceComponentState,
         detailed_timer::State as DetailedTimerComponentState, graph::State as GraphComponentState,
-        key_value::State as KeyValueComponentState, separator::State as SeparatorComponentState,
-        splits::State as SplitsComponentState, text::State as TextComponentState,
-        timer::State as TimerComponentState, title::State as TitleComponentState,
+        image::State as ImageComponentState, input_display::State as InputDisplayComponentState,
+        key_value::State as KeyValueComponentState,
+        segment_notes::State as SegmentNotesComponentState,
+        separator::State as SeparatorComponentState, splits::State as SplitsComponentState,
+        text::State as TextComponentState, timer::State as TimerComponentState,
+        title::State as TitleComponentState, variable::State as VariableComponentState,
     },
     layout::{ComponentState, LayoutState},
 };
@@ -58,12 +61,16 @@ pub extern "C" fn LayoutState_component_type(this: &LayoutState, index: usize) -
         ComponentState::BlankSpace(_) => "BlankSpace\0",
         ComponentState::DetailedTimer(_) => "DetailedTimer\0",
         ComponentState::Graph(_) => "Graph\0",
+        ComponentState::Image(_) => "Image\0",
+        ComponentState::InputDisplay(_) => "InputDisplay\0",
         ComponentState::KeyValue(_) => "KeyValue\0",
+        ComponentState::SegmentNotes(_) => "SegmentNotes\0",
         ComponentState::Separator(_) => "Separator\0",
         ComponentState::Splits(_) => "Splits\0",
         ComponentState::Text(_) => "Text\0",
         ComponentState::Timer(_) => "Timer\0",
         ComponentState::Title(_) => "Title\0",
+        ComponentState::Variable(_) => "Variable\0",
     })
     .as_ptr()
     .cast()
@@ -105,6 +112,30 @@ pub extern "C" fn LayoutState_component_as_graph(
     }
 }
 
+/// Gets the Image component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_image(
+    this: &LayoutState,
+    index: usize,
+) -> &ImageComponentState {
+    match &this.components[index] {
+        ComponentState::Image(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
+/// Gets the Input Display component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_input_display(
+    this: &LayoutState,
+    index: usize,
+) -> &InputDisplayComponentState {
+    match &this.components[index] {
+        ComponentState::InputDisplay(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
 /// Gets the Key Value component state at the specified index.
 #[no_mangle]
 pub extern "C" fn LayoutState_component_as_key_value(
@@ -117,6 +148,18 @@ pub extern "C" fn LayoutState_component_as_key_value(
     }
 }
 
+/// Gets the Segment Notes component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_segment_notes(
+    this: &LayoutState,
+    index: usize,
+) -> &SegmentNotesComponentState {
+    match &this.components[index] {
+        ComponentState::SegmentNotes(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
+
 /// Gets the Separator component state at the specified index.
 #[no_mangle]
 pub extern "C" fn LayoutState_component_as_separator(
@@ -176,3 +219,15 @@ pub extern "C" fn LayoutState_component_as_title(
         _ => panic!("wrong component state type"),
     }
 }
+
+/// Gets the Variable component state at the specified index.
+#[no_mangle]
+pub extern "C" fn LayoutState_component_as_variable(
+    this: &LayoutState,
+    index: usize,
+) -> &VariableComponentState {
+    match &this.components[index] {
+        ComponentState::Variable(x) => x,
+        _ => panic!("wrong component state type"),
+    }
+}
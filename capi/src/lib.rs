@@ -15,9 +15,12 @@ use std::{
 pub mod analysis;
 pub mod atomic_date_time;
 pub mod attempt;
+pub mod attempt_counter_component;
 pub mod auto_splitting_runtime;
 pub mod blank_space_component;
 pub mod blank_space_component_state;
+pub mod clock_component;
+pub mod comparison_registry;
 pub mod component;
 pub mod current_comparison_component;
 pub mod current_pace_component;
@@ -30,6 +33,10 @@ pub mod graph_component;
 pub mod graph_component_state;
 pub mod hotkey_config;
 pub mod hotkey_system;
+pub mod image_component;
+pub mod image_component_state;
+pub mod input_display_component;
+pub mod input_display_component_state;
 pub mod key_value_component_state;
 pub mod layout;
 pub mod layout_editor;
@@ -52,9 +59,12 @@ pub mod segment;
 pub mod segment_history;
 pub mod segment_history_element;
 pub mod segment_history_iter;
+pub mod segment_notes_component;
+pub mod segment_notes_component_state;
 pub mod segment_time_component;
 pub mod separator_component;
 pub mod separator_component_state;
+pub mod session_component;
 pub mod setting_value;
 pub mod shared_timer;
 pub mod software_renderer;
@@ -62,9 +72,11 @@ pub mod splits_component;
 pub mod splits_component_state;
 pub mod sum_of_best_cleaner;
 pub mod sum_of_best_component;
+pub mod survival_chance_component;
 pub mod text_component;
 pub mod text_component_state;
 pub mod time;
+pub mod time_lost_component;
 pub mod time_span;
 pub mod timer;
 pub mod timer_component;
@@ -74,6 +86,8 @@ pub mod timer_write_lock;
 pub mod title_component;
 pub mod title_component_state;
 pub mod total_playtime_component;
+pub mod variable_component;
+pub mod variable_component_state;
 
 use crate::{
     run_metadata_custom_variable::RunMetadataCustomVariable,
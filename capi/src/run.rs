@@ -6,6 +6,7 @@ use crate::{
     segment::OwnedSegment, with_vec,
 };
 use livesplit_core::{
+    comparison::Registry as ComparisonRegistry,
     run::{
         parser,
         saver::{self, livesplit::IoWrite},
@@ -59,6 +60,38 @@ pub unsafe extern "C" fn Run_parse(
     Box::new(parser::composite::parse(slice::from_raw_parts(data, length), load_files_path).ok())
 }
 
+/// Attempts to parse a splits file from an array by invoking the corresponding
+/// parser for the file format detected. Unlike Run_parse, the Comparison
+/// Generators of the splits file are recreated from the Comparison Registry
+/// provided, so kinds of Comparison Generators registered at runtime can be
+/// loaded as well. Additionally you can provide the path of the splits file so
+/// additional files, like external images, can be loaded. If you are using
+/// livesplit-core in a server-like environment, set this to <NULL>. Only
+/// client-side applications should provide a path here.
+#[no_mangle]
+pub unsafe extern "C" fn Run_parse_with_registry(
+    data: *const u8,
+    length: usize,
+    load_files_path: *const c_char,
+    registry: &ComparisonRegistry,
+) -> OwnedParseRunResult {
+    let load_files_path = str(load_files_path);
+    let load_files_path = if !load_files_path.is_empty() {
+        Some(Path::new(load_files_path))
+    } else {
+        None
+    };
+
+    Box::new(
+        parser::composite::parse_with_registry(
+            slice::from_raw_parts(data, length),
+            load_files_path,
+            registry,
+        )
+        .ok(),
+    )
+}
+
 /// Attempts to parse a splits file from a file by invoking the corresponding
 /// parser for the file format detected. Additionally you can provide the path
 /// of the splits file so additional files, like external images, can be loaded.
@@ -5,9 +5,10 @@
 
 use super::{output_vec, str, Json};
 use crate::{
-    linked_layout::OwnedLinkedLayout, run::OwnedRun, sum_of_best_cleaner::OwnedSumOfBestCleaner,
+    linked_layout::OwnedLinkedLayout, run::OwnedRun, setting_value::OwnedSettingValue,
+    sum_of_best_cleaner::OwnedSumOfBestCleaner,
 };
-use livesplit_core::{Run, RunEditor, TimingMethod};
+use livesplit_core::{comparison::Registry as ComparisonRegistry, Run, RunEditor, TimingMethod};
 use std::{os::raw::c_char, slice};
 
 /// type
@@ -298,6 +299,12 @@ pub unsafe extern "C" fn RunEditor_active_set_name(this: &mut RunEditor, name: *
     this.active_segment().set_name(str(name));
 }
 
+/// Sets the notes of the active segment.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_active_set_notes(this: &mut RunEditor, notes: *const c_char) {
+    this.active_segment().set_notes(str(notes));
+}
+
 /// Parses a split time from a string and sets it for the active segment with
 /// the chosen timing method.
 #[no_mangle]
@@ -379,6 +386,98 @@ pub unsafe extern "C" fn RunEditor_remove_comparison(
     this.remove_comparison(str(comparison));
 }
 
+/// Adds a new Comparison Generator of the kind specified with its default
+/// settings. The Comparison Generator is created from the Comparison Registry
+/// provided. Returns <FALSE> if the kind is not registered or if the name of
+/// its comparison starts with `[Race]` or already exists.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_add_comparison_generator(
+    this: &mut RunEditor,
+    registry: &ComparisonRegistry,
+    kind: *const c_char,
+) -> bool {
+    registry
+        .create(str(kind))
+        .is_some_and(|generator| this.add_comparison_generator(generator).is_ok())
+}
+
+/// Adds a new Linked Comparison that compares against the Personal Best of the
+/// Run provided. The path of the file the Run comes from is stored, so that the
+/// comparison can be refreshed whenever the file changes. Returns <FALSE> if
+/// the name of the comparison starts with `[Race]` or already exists.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_add_linked_comparison(
+    this: &mut RunEditor,
+    comparison: *const c_char,
+    path: *const c_char,
+    source: &Run,
+) -> bool {
+    this.add_linked_comparison(str(comparison), str(path), source)
+        .is_ok()
+}
+
+/// Refreshes the Linked Comparison at the index specified with the Personal
+/// Best of the Run provided. Returns <FALSE> if the Comparison Generator at
+/// that index is not a Linked Comparison. The index is based on the
+/// `comparison_generators` field of the Run Editor's state.
+///
+/// This panics if the index is out of bounds.
+#[no_mangle]
+pub extern "C" fn RunEditor_refresh_linked_comparison(
+    this: &mut RunEditor,
+    index: usize,
+    source: &Run,
+) -> bool {
+    this.refresh_linked_comparison(index, source)
+}
+
+/// Removes the Comparison Generator at the index specified, along with the
+/// comparison times it generated. The index is based on the
+/// `comparison_generators` field of the Run Editor's state.
+///
+/// This panics if the index is out of bounds.
+#[no_mangle]
+pub extern "C" fn RunEditor_remove_comparison_generator(this: &mut RunEditor, index: usize) {
+    this.remove_comparison_generator(index);
+}
+
+/// Encodes a generic description of the settings available for the Comparison
+/// Generator at the index specified and their current values as JSON. The
+/// index is based on the `comparison_generators` field of the Run Editor's
+/// state.
+///
+/// This panics if the index is out of bounds.
+#[no_mangle]
+pub extern "C" fn RunEditor_comparison_generator_settings_description_as_json(
+    this: &RunEditor,
+    index: usize,
+) -> Json {
+    output_vec(|o| {
+        serde_json::to_writer(o, &this.comparison_generator_settings_description(index)).unwrap();
+    })
+}
+
+/// Sets a setting's value of the Comparison Generator at the index specified by
+/// its setting index to the given value. The comparison gets regenerated
+/// afterwards. Returns <FALSE> if the setting renames the comparison and the
+/// new name of the comparison starts with `[Race]` or already exists. The
+/// index is based on the `comparison_generators` field of the Run Editor's
+/// state.
+///
+/// This panics if the type of the value to be set is not compatible with the
+/// type of the setting's value. A panic can also occur if either of the indices
+/// is out of bounds.
+#[no_mangle]
+pub extern "C" fn RunEditor_set_comparison_generator_value(
+    this: &mut RunEditor,
+    index: usize,
+    setting_index: usize,
+    value: OwnedSettingValue,
+) -> bool {
+    this.set_comparison_generator_value(index, setting_index, *value)
+        .is_ok()
+}
+
 /// Renames a comparison. The comparison can't be renamed if the new name of
 /// the comparison starts with `[Race]` or it already exists.
 #[no_mangle]
@@ -449,3 +548,16 @@ pub extern "C" fn RunEditor_clean_sum_of_best(
 ) -> OwnedSumOfBestCleaner {
     Box::new(this.clean_sum_of_best())
 }
+
+/// Creates a Sum of Best Cleaner that, in addition to the combined segment
+/// times, also points out best segments that are likely to be the result of a
+/// mistake, such as splitting too early. Only best segments with a
+/// suspiciousness score of at least the threshold provided are pointed out.
+/// A threshold of 5 is a reasonable default.
+#[no_mangle]
+pub extern "C" fn RunEditor_clean_sum_of_best_with_suspicious_golds(
+    this: &'static mut RunEditor,
+    threshold: f64,
+) -> OwnedSumOfBestCleaner {
+    Box::new(this.clean_sum_of_best_with_suspicious_golds(threshold))
+}
@@ -40,6 +40,13 @@ pub extern "C" fn Segment_icon_len(this: &Segment) -> usize {
     this.icon().data().len()
 }
 
+/// Accesses the notes of the segment. These may consist of multiple lines and
+/// may be formatted as Markdown.
+#[no_mangle]
+pub extern "C" fn Segment_notes(this: &Segment) -> *const c_char {
+    output_str(this.notes())
+}
+
 /// Accesses the specified comparison's time. If there's none for this
 /// comparison, an empty time is being returned (but not stored in the
 /// segment).
@@ -0,0 +1,51 @@
+//! The Segment Notes Component shows the notes the runner stored for the
+//! current segment, such as route notes.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::segment_notes_component_state::OwnedSegmentNotesComponentState;
+use livesplit_core::{component::segment_notes::Component as SegmentNotesComponent, Timer};
+
+/// type
+pub type OwnedSegmentNotesComponent = Box<SegmentNotesComponent>;
+
+/// Creates a new Segment Notes Component.
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponent_new() -> OwnedSegmentNotesComponent {
+    Box::new(SegmentNotesComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponent_drop(this: OwnedSegmentNotesComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponent_into_generic(
+    this: OwnedSegmentNotesComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponent_state_as_json(
+    this: &SegmentNotesComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponent_state(
+    this: &SegmentNotesComponent,
+    timer: &Timer,
+) -> OwnedSegmentNotesComponentState {
+    Box::new(this.state(timer))
+}
@@ -0,0 +1,39 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::segment_notes::State as SegmentNotesComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedSegmentNotesComponentState = Box<SegmentNotesComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponentState_drop(this: OwnedSegmentNotesComponentState) {
+    drop(this);
+}
+
+/// The name of the segment the notes belong to. If the name is not supposed to
+/// be shown, an empty string is returned instead.
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponentState_segment_name(
+    this: &SegmentNotesComponentState,
+) -> *const c_char {
+    output_str(this.segment_name.as_deref().unwrap_or_default())
+}
+
+/// The notes of the segment. These may consist of multiple lines.
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponentState_notes(
+    this: &SegmentNotesComponentState,
+) -> *const c_char {
+    output_str(&this.notes)
+}
+
+/// Specifies whether the notes are supposed to be interpreted as Markdown.
+#[no_mangle]
+pub extern "C" fn SegmentNotesComponentState_is_markdown(
+    this: &SegmentNotesComponentState,
+) -> bool {
+    this.is_markdown
+}
@@ -0,0 +1,48 @@
+//! The Session Component is a component that shows a statistic about the
+//! current play session, such as the number of attempts or the best time
+//! achieved during it.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::session::Component as SessionComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedSessionComponent = Box<SessionComponent>;
+
+/// Creates a new Session Component.
+#[no_mangle]
+pub extern "C" fn SessionComponent_new() -> OwnedSessionComponent {
+    Box::new(SessionComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SessionComponent_drop(this: OwnedSessionComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SessionComponent_into_generic(this: OwnedSessionComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SessionComponent_state_as_json(this: &SessionComponent, timer: &Timer) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn SessionComponent_state(
+    this: &SessionComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(timer))
+}
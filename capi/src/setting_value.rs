@@ -4,18 +4,26 @@
 use crate::{output_vec, str, Json};
 use livesplit_core::{
     component::{
-        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
-        timer::DeltaGradient,
+        attempt_counter::AttemptStatistic,
+        clock::ClockMode,
+        graph::LineStyle,
+        image::{AspectMode, ImageAlignment, ImageMode},
+        session::SessionStatistic,
+        splits::{
+            ChartKind, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, SubsplitVisibility,
+        },
+        timer::{CountdownMode, DeltaGradient},
+        total_playtime::PlaytimeScope,
     },
     layout::LayoutDirection,
     settings::{
-        Alignment, Color, ColumnKind, Font, FontStretch, FontStyle, FontWeight, Gradient,
+        Alignment, Color, ColumnKind, Font, FontStretch, FontStyle, FontWeight, Gradient, Image,
         ListGradient, Value as SettingValue,
     },
     timing::formatter::{Accuracy, DigitsFormat},
-    TimingMethod,
+    TimeSpan, TimingMethod,
 };
-use std::os::raw::c_char;
+use std::{os::raw::c_char, slice};
 
 /// type
 pub type OwnedSettingValue = Box<SettingValue>;
@@ -244,11 +252,59 @@ pub unsafe extern "C" fn SettingValue_from_column_kind(
     let value = match value {
         "Time" => ColumnKind::Time,
         "Variable" => ColumnKind::Variable,
+        "Chart" => ColumnKind::Chart,
         _ => return None,
     };
     Some(Box::new(value.into()))
 }
 
+/// Creates a new setting value from the chart kind with the name provided. If
+/// it doesn't match a known chart kind, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_chart_kind(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Sparkline" => ChartKind::Sparkline,
+        "Histogram" => ChartKind::Histogram,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the countdown mode with the name provided.
+/// If it doesn't match a known countdown mode, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_countdown_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Off" => CountdownMode::Off,
+        "GoalTime" => CountdownMode::GoalTime,
+        "Comparison" => CountdownMode::Comparison,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value with the type `optional time` by parsing the
+/// time provided. If the time can't be parsed, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_optional_time_span(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value).parse::<TimeSpan>().ok()?;
+    Some(Box::new(Some(value).into()))
+}
+
+/// Creates a new empty setting value with the type `optional time`.
+#[no_mangle]
+pub extern "C" fn SettingValue_from_optional_empty_time_span() -> OwnedSettingValue {
+    Box::new(None::<TimeSpan>.into())
+}
+
 /// Creates a new setting value from the column start with the name provided. If
 /// it doesn't match a known column start with, <NULL> is returned.
 #[no_mangle]
@@ -261,6 +317,8 @@ pub unsafe extern "C" fn SettingValue_from_column_start_with(
         "ComparisonTime" => ColumnStartWith::ComparisonTime,
         "ComparisonSegmentTime" => ColumnStartWith::ComparisonSegmentTime,
         "PossibleTimeSave" => ColumnStartWith::PossibleTimeSave,
+        "GoldProbability" => ColumnStartWith::GoldProbability,
+        "ExpectedTimeSave" => ColumnStartWith::ExpectedTimeSave,
         _ => return None,
     };
     Some(Box::new(value.into()))
@@ -281,6 +339,8 @@ pub unsafe extern "C" fn SettingValue_from_column_update_with(
         "SegmentTime" => ColumnUpdateWith::SegmentTime,
         "SegmentDelta" => ColumnUpdateWith::SegmentDelta,
         "SegmentDeltaWithFallback" => ColumnUpdateWith::SegmentDeltaWithFallback,
+        "GoldProbability" => ColumnUpdateWith::GoldProbability,
+        "ExpectedTimeSave" => ColumnUpdateWith::ExpectedTimeSave,
         _ => return None,
     };
     Some(Box::new(value.into()))
@@ -385,3 +445,163 @@ pub unsafe extern "C" fn SettingValue_from_delta_gradient(
     };
     Some(Box::new(value.into()))
 }
+
+/// Creates a new setting value from the playtime scope with the name provided.
+/// If it doesn't match a known playtime scope, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_playtime_scope(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Total" => PlaytimeScope::Total,
+        "Today" => PlaytimeScope::Today,
+        "Session" => PlaytimeScope::Session,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the session statistic with the name
+/// provided. If it doesn't match a known session statistic, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_session_statistic(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Attempts" => SessionStatistic::Attempts,
+        "Resets" => SessionStatistic::Resets,
+        "BestSegments" => SessionStatistic::BestSegments,
+        "BestTime" => SessionStatistic::BestTime,
+        "Playtime" => SessionStatistic::Playtime,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the subsplit visibility with the name
+/// provided. If it doesn't match a known subsplit visibility, <NULL> is
+/// returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_subsplit_visibility(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "CurrentSection" => SubsplitVisibility::CurrentSection,
+        "Always" => SubsplitVisibility::Always,
+        "Never" => SubsplitVisibility::Never,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the line style with the name provided. If
+/// it doesn't match a known line style, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_line_style(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Solid" => LineStyle::Solid,
+        "Dashed" => LineStyle::Dashed,
+        "Dotted" => LineStyle::Dotted,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the clock mode with the name provided. If
+/// it doesn't match a known clock mode, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_clock_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Time" => ClockMode::Time,
+        "DateTime" => ClockMode::DateTime,
+        "SessionTime" => ClockMode::SessionTime,
+        "Uptime" => ClockMode::Uptime,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the attempt statistic with the name
+/// provided. If it doesn't match a known attempt statistic, <NULL> is
+/// returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_attempt_statistic(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Session" => AttemptStatistic::Session,
+        "Today" => AttemptStatistic::Today,
+        "Total" => AttemptStatistic::Total,
+        "CompletionPercentage" => AttemptStatistic::CompletionPercentage,
+        "ResetsSinceLastPb" => AttemptStatistic::ResetsSinceLastPb,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the image data provided. The data may be
+/// empty, which indicates that there is no image.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image(
+    data: *const u8,
+    length: usize,
+) -> OwnedSettingValue {
+    Box::new(Image::new(slice::from_raw_parts(data, length)).into())
+}
+
+/// Creates a new setting value from the image mode with the name provided. If
+/// it doesn't match a known image mode, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "StaticImage" => ImageMode::StaticImage,
+        "SegmentIcon" => ImageMode::SegmentIcon,
+        "GameIcon" => ImageMode::GameIcon,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the aspect mode with the name provided. If
+/// it doesn't match a known aspect mode, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_aspect_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Fit" => AspectMode::Fit,
+        "Stretch" => AspectMode::Stretch,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the image alignment with the name
+/// provided. If it doesn't match a known image alignment, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image_alignment(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Left" => ImageAlignment::Left,
+        "Center" => ImageAlignment::Center,
+        "Right" => ImageAlignment::Right,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
@@ -1,7 +1,7 @@
 //! The state object that describes a single segment's information to visualize.
 
 use super::{output_str, output_vec};
-use livesplit_core::component::splits::State as SplitsComponentState;
+use livesplit_core::component::splits::{ColumnChart, State as SplitsComponentState};
 use std::io::Write;
 use std::os::raw::c_char;
 
@@ -119,6 +119,58 @@ pub extern "C" fn SplitsComponentState_column_semantic_color(
     })
 }
 
+/// The kind of chart to show instead of the column's value for the split and
+/// column with the specified index. It is either `Sparkline` or `Histogram`.
+/// If the column's value is supposed to be shown as text, an empty string is
+/// returned. The columns are specified from right to left. You may not provide
+/// an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_column_chart_kind(
+    this: &SplitsComponentState,
+    index: usize,
+    column_index: usize,
+) -> *const c_char {
+    output_str(match &this.splits[index].columns[column_index].chart {
+        Some(ColumnChart::Sparkline(_)) => "Sparkline",
+        Some(ColumnChart::Histogram(_)) => "Histogram",
+        None => "",
+    })
+}
+
+/// The amount of values of the chart of the split and column with the
+/// specified index. The columns are specified from right to left. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_column_chart_len(
+    this: &SplitsComponentState,
+    index: usize,
+    column_index: usize,
+) -> usize {
+    match &this.splits[index].columns[column_index].chart {
+        Some(ColumnChart::Sparkline(values) | ColumnChart::Histogram(values)) => values.len(),
+        None => 0,
+    }
+}
+
+/// The value of the chart of the split and column with the specified index.
+/// For sparklines, this is the height of a point and for histograms, the
+/// height of a bin, both in the range 0..1. The columns are specified from
+/// right to left. You may not provide out of bounds indices.
+#[no_mangle]
+pub extern "C" fn SplitsComponentState_column_chart_value(
+    this: &SplitsComponentState,
+    index: usize,
+    column_index: usize,
+    value_index: usize,
+) -> f32 {
+    match &this.splits[index].columns[column_index].chart {
+        Some(ColumnChart::Sparkline(values) | ColumnChart::Histogram(values)) => {
+            values[value_index]
+        }
+        None => panic!("The column doesn't show a chart"),
+    }
+}
+
 /// Describes if the segment with the specified index is the segment the active
 /// attempt is currently on.
 #[no_mangle]
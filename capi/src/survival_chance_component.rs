@@ -0,0 +1,54 @@
+//! The Survival Chance Component is a component that shows how likely it is
+//! for the current attempt to reach the end of the run, based on how often
+//! attempts were reset from the current segment onward. If there is no active
+//! attempt, it shows the general chance of an attempt reaching the end.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::survival_chance::Component as SurvivalChanceComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedSurvivalChanceComponent = Box<SurvivalChanceComponent>;
+
+/// Creates a new Survival Chance Component.
+#[no_mangle]
+pub extern "C" fn SurvivalChanceComponent_new() -> OwnedSurvivalChanceComponent {
+    Box::new(SurvivalChanceComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SurvivalChanceComponent_drop(this: OwnedSurvivalChanceComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SurvivalChanceComponent_into_generic(
+    this: OwnedSurvivalChanceComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SurvivalChanceComponent_state_as_json(
+    this: &SurvivalChanceComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot()).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn SurvivalChanceComponent_state(
+    this: &SurvivalChanceComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(&timer.snapshot()))
+}
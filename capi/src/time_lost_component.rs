@@ -0,0 +1,48 @@
+//! The Time Lost Component is a component that shows how much time has been
+//! lost in the current attempt, either compared to the chosen comparison or
+//! compared to the Best Segments.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::key_value_component_state::OwnedKeyValueComponentState;
+use livesplit_core::component::time_lost::Component as TimeLostComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedTimeLostComponent = Box<TimeLostComponent>;
+
+/// Creates a new Time Lost Component.
+#[no_mangle]
+pub extern "C" fn TimeLostComponent_new() -> OwnedTimeLostComponent {
+    Box::new(TimeLostComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn TimeLostComponent_drop(this: OwnedTimeLostComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn TimeLostComponent_into_generic(this: OwnedTimeLostComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn TimeLostComponent_state_as_json(this: &TimeLostComponent, timer: &Timer) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot()).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn TimeLostComponent_state(
+    this: &TimeLostComponent,
+    timer: &Timer,
+) -> OwnedKeyValueComponentState {
+    Box::new(this.state(&timer.snapshot()))
+}
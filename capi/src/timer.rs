@@ -135,6 +135,21 @@ pub extern "C" fn Timer_current_attempt_has_new_best_times(this: &Timer) -> bool
     this.current_attempt_has_new_best_times()
 }
 
+/// Starts a new play session. If there is a session that is still active, it
+/// gets ended. All the attempts that are started from now on belong to the new
+/// session until it gets ended.
+#[no_mangle]
+pub extern "C" fn Timer_start_session(this: &mut Timer) {
+    this.start_session();
+}
+
+/// Ends the play session that is still active. If there is no active session,
+/// nothing happens.
+#[no_mangle]
+pub extern "C" fn Timer_end_session(this: &mut Timer) {
+    this.end_session();
+}
+
 /// Resets the current attempt if there is one in progress. If the splits
 /// are to be updated, all the information of the current attempt is stored
 /// in the Run's history. Otherwise the current attempt's information is
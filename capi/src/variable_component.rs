@@ -0,0 +1,48 @@
+//! The Variable Component shows the current value of a custom variable, such
+//! as the number of deaths or the current area provided by an auto splitter.
+//! Numeric variables can additionally be visualized as a graph of their values
+//! at each split of the current attempt.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::variable_component_state::OwnedVariableComponentState;
+use livesplit_core::{component::variable::Component as VariableComponent, Timer};
+
+/// type
+pub type OwnedVariableComponent = Box<VariableComponent>;
+
+/// Creates a new Variable Component.
+#[no_mangle]
+pub extern "C" fn VariableComponent_new() -> OwnedVariableComponent {
+    Box::new(VariableComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn VariableComponent_drop(this: OwnedVariableComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn VariableComponent_into_generic(this: OwnedVariableComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn VariableComponent_state_as_json(this: &VariableComponent, timer: &Timer) -> Json {
+    output_vec(|o| {
+        this.state(&timer.snapshot()).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn VariableComponent_state(
+    this: &VariableComponent,
+    timer: &Timer,
+) -> OwnedVariableComponentState {
+    Box::new(this.state(&timer.snapshot()))
+}
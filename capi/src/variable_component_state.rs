@@ -0,0 +1,69 @@
+//! The state object describes the information to visualize for this component.
+//! All the coordinates of the graph are in the range 0..1.
+
+use super::output_str;
+use livesplit_core::component::variable::State as VariableComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedVariableComponentState = Box<VariableComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn VariableComponentState_drop(this: OwnedVariableComponentState) {
+    drop(this);
+}
+
+/// The label to visualize.
+#[no_mangle]
+pub extern "C" fn VariableComponentState_label(this: &VariableComponentState) -> *const c_char {
+    output_str(&this.label)
+}
+
+/// The value to visualize, including the prefix and the suffix.
+#[no_mangle]
+pub extern "C" fn VariableComponentState_value(this: &VariableComponentState) -> *const c_char {
+    output_str(&this.value)
+}
+
+/// Specifies whether a graph of the variable's values is supposed to be shown.
+#[no_mangle]
+pub extern "C" fn VariableComponentState_has_graph(this: &VariableComponentState) -> bool {
+    this.graph.is_some()
+}
+
+/// Returns the amount of points of the graph to visualize. Connect all of them
+/// to visualize the graph. If there is no graph, 0 is returned.
+#[no_mangle]
+pub extern "C" fn VariableComponentState_graph_points_len(this: &VariableComponentState) -> usize {
+    this.graph.as_ref().map_or(0, |g| g.points.len())
+}
+
+/// Returns the x coordinate of the point specified. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn VariableComponentState_graph_point_x(
+    this: &VariableComponentState,
+    index: usize,
+) -> f32 {
+    this.graph.as_ref().unwrap().points[index].x
+}
+
+/// Returns the y coordinate of the point specified. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn VariableComponentState_graph_point_y(
+    this: &VariableComponentState,
+    index: usize,
+) -> f32 {
+    this.graph.as_ref().unwrap().points[index].y
+}
+
+/// Specifies whether the last point of the graph is the current value of the
+/// variable, rather than its value at a split.
+#[no_mangle]
+pub extern "C" fn VariableComponentState_graph_is_live_value_active(
+    this: &VariableComponentState,
+) -> bool {
+    this.graph.as_ref().is_some_and(|g| g.is_live_value_active)
+}
@@ -0,0 +1,12 @@
+/// A change in the state of a single key, as reported to the listeners
+/// registered via [`Hook::register_key_listener`](crate::Hook::register_key_listener).
+/// Unlike hotkeys, key listeners are notified about both presses and releases
+/// and are not affected by the modifiers that are currently held down.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KeyEvent {
+    /// The key got pressed. Repeated key presses caused by holding the key
+    /// down are not reported.
+    Pressed,
+    /// The key got released.
+    Released,
+}
@@ -40,8 +40,9 @@ cfg_if::cfg_if! {
 
 mod hotkey;
 mod key_code;
+mod key_event;
 mod modifiers;
-pub use self::{hotkey::*, key_code::*, modifiers::*, platform::*};
+pub use self::{hotkey::*, key_code::*, key_event::*, modifiers::*, platform::*};
 
 #[cfg(not(all(target_family = "wasm", target_os = "unknown", feature = "wasm-web")))]
 const _: () = {
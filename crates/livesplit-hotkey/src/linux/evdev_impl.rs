@@ -1,11 +1,15 @@
-use std::{collections::hash_map::HashMap, os::unix::prelude::AsRawFd, ptr, thread};
+use std::{
+    collections::hash_map::{Entry, HashMap},
+    os::unix::prelude::AsRawFd,
+    ptr, thread,
+};
 
 use evdev::{Device, EventType, InputEventKind, Key};
 use mio::{unix::SourceFd, Events, Interest, Poll, Token, Waker};
 use x11_dl::xlib::{Xlib, _XDisplay};
 
 use super::{x11_impl, Message};
-use crate::{Error, Hook, KeyCode, Modifiers, Result};
+use crate::{Error, Hook, KeyCode, KeyEvent, Modifiers, Result};
 
 // Low numbered tokens are allocated to devices.
 const PING_TOKEN: Token = Token(usize::MAX);
@@ -255,6 +259,7 @@ pub fn new() -> Result<Hook> {
         let mut result = Ok(());
         let mut events = Events::with_capacity(1024);
         let mut hotkeys: HashMap<(Key, Modifiers), Box<dyn FnMut() + Send>> = HashMap::new();
+        let mut key_listeners: HashMap<Key, Box<dyn FnMut(KeyEvent) + Send>> = HashMap::new();
         let mut modifiers = Modifiers::empty();
 
         let (mut xlib, mut display) = (None, None);
@@ -274,6 +279,9 @@ pub fn new() -> Result<Hook> {
                             const PRESSED: i32 = 1;
                             match ev.value() {
                                 PRESSED => {
+                                    if let Some(callback) = key_listeners.get_mut(&k) {
+                                        callback(KeyEvent::Pressed);
+                                    }
                                     if let Some(callback) = hotkeys.get_mut(&(k, modifiers)) {
                                         callback();
                                     }
@@ -293,21 +301,26 @@ pub fn new() -> Result<Hook> {
                                         _ => {}
                                     }
                                 }
-                                RELEASED => match k {
-                                    Key::KEY_LEFTALT | Key::KEY_RIGHTALT => {
-                                        modifiers.remove(Modifiers::ALT);
-                                    }
-                                    Key::KEY_LEFTCTRL | Key::KEY_RIGHTCTRL => {
-                                        modifiers.remove(Modifiers::CONTROL);
-                                    }
-                                    Key::KEY_LEFTMETA | Key::KEY_RIGHTMETA => {
-                                        modifiers.remove(Modifiers::META);
+                                RELEASED => {
+                                    if let Some(callback) = key_listeners.get_mut(&k) {
+                                        callback(KeyEvent::Released);
                                     }
-                                    Key::KEY_LEFTSHIFT | Key::KEY_RIGHTSHIFT => {
-                                        modifiers.remove(Modifiers::SHIFT);
+                                    match k {
+                                        Key::KEY_LEFTALT | Key::KEY_RIGHTALT => {
+                                            modifiers.remove(Modifiers::ALT);
+                                        }
+                                        Key::KEY_LEFTCTRL | Key::KEY_RIGHTCTRL => {
+                                            modifiers.remove(Modifiers::CONTROL);
+                                        }
+                                        Key::KEY_LEFTMETA | Key::KEY_RIGHTMETA => {
+                                            modifiers.remove(Modifiers::META);
+                                        }
+                                        Key::KEY_LEFTSHIFT | Key::KEY_RIGHTSHIFT => {
+                                            modifiers.remove(Modifiers::SHIFT);
+                                        }
+                                        _ => {}
                                     }
-                                    _ => {}
-                                },
+                                }
                                 _ => {} // Ignore repeating
                             }
                         }
@@ -332,6 +345,29 @@ pub fn new() -> Result<Hook> {
                                     .and_then(|k| hotkeys.remove(&(k, key.modifiers)).map(drop))
                                     .ok_or(Error::NotRegistered),
                             ),
+                            Message::RegisterKeyListener(key_code, callback, promise) => {
+                                promise.set(match code_for(key_code) {
+                                    Some(k) => match key_listeners.entry(k) {
+                                        Entry::Vacant(vacant) => {
+                                            vacant.insert(callback);
+                                            Ok(())
+                                        }
+                                        Entry::Occupied(_) => Err(Error::AlreadyRegistered),
+                                    },
+                                    None => Ok(()),
+                                });
+                            }
+                            Message::UnregisterKeyListener(key_code, promise) => {
+                                promise.set(match code_for(key_code) {
+                                    Some(k) => key_listeners
+                                        .remove(&k)
+                                        .map(drop)
+                                        .ok_or(Error::NotRegistered),
+                                    // Registering a key without a code does nothing, so
+                                    // there is nothing to unregister either.
+                                    None => Ok(()),
+                                });
+                            }
                             Message::Resolve(key_code, promise) => {
                                 promise.set(resolve(&mut xlib, &mut display, key_code))
                             }
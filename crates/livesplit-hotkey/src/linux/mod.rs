@@ -1,6 +1,6 @@
 use std::thread::JoinHandle;
 
-use crate::{Hotkey, KeyCode};
+use crate::{Hotkey, KeyCode, KeyEvent};
 use crossbeam_channel::Sender;
 use mio::Waker;
 use nix::unistd::{getgroups, Group};
@@ -25,6 +25,9 @@ pub enum Error {
     NoXLib,
     /// Failed opening a connection to the X11 server.
     OpenXServerConnection,
+    /// Failed dynamically linking to the X Input extension, which is needed
+    /// for listening to keys with X11.
+    NoXInput,
     /// The background thread stopped unexpectedly.
     ThreadStopped,
 }
@@ -39,6 +42,12 @@ enum Message {
         Promise<Result<()>>,
     ),
     Unregister(Hotkey, Promise<Result<()>>),
+    RegisterKeyListener(
+        KeyCode,
+        Box<dyn FnMut(KeyEvent) + Send + 'static>,
+        Promise<Result<()>>,
+    ),
+    UnregisterKeyListener(KeyCode, Promise<Result<()>>),
     Resolve(KeyCode, Promise<Option<char>>),
     End,
 }
@@ -105,6 +114,40 @@ impl Hook {
         future.value().ok_or(Error::ThreadStopped)?
     }
 
+    /// Registers a listener that gets notified whenever the given key gets
+    /// pressed or released.
+    pub fn register_key_listener<F>(&self, key_code: KeyCode, callback: F) -> Result<()>
+    where
+        F: FnMut(KeyEvent) + Send + 'static,
+    {
+        let (future, promise) = future_promise();
+
+        self.sender
+            .send(Message::RegisterKeyListener(
+                key_code,
+                Box::new(callback),
+                promise,
+            ))
+            .map_err(|_| Error::ThreadStopped)?;
+
+        self.waker.wake().map_err(|_| Error::ThreadStopped)?;
+
+        future.value().ok_or(Error::ThreadStopped)?
+    }
+
+    /// Unregisters a previously registered key listener.
+    pub fn unregister_key_listener(&self, key_code: KeyCode) -> Result<()> {
+        let (future, promise) = future_promise();
+
+        self.sender
+            .send(Message::UnregisterKeyListener(key_code, promise))
+            .map_err(|_| Error::ThreadStopped)?;
+
+        self.waker.wake().map_err(|_| Error::ThreadStopped)?;
+
+        future.value().ok_or(Error::ThreadStopped)?
+    }
+
     pub(crate) fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         let (future, promise) = future_promise();
 
@@ -1,18 +1,25 @@
 use std::{
-    collections::HashMap,
+    collections::{hash_map::Entry, HashMap},
     mem::MaybeUninit,
     os::raw::{c_int, c_uint},
     ptr, thread,
 };
 
 use mio::{unix::SourceFd, Events, Interest, Poll, Token, Waker};
-use x11_dl::xlib::{
-    AnyKey, AnyModifier, ControlMask, Display, GrabModeAsync, KeyPress, Mod1Mask, Mod4Mask,
-    ShiftMask, XErrorEvent, XKeyEvent, Xlib, _XDisplay,
+use x11_dl::{
+    xinput2::{
+        XIAllMasterDevices, XIEventMask, XIRawEvent, XISetMask, XI_LASTEVENT, XI_RawKeyPress,
+        XI_RawKeyRelease, XInput2,
+    },
+    xlib::{
+        AnyKey, AnyModifier, ControlMask, Display, GenericEvent, GrabModeAsync, KeyPress,
+        Mod1Mask, Mod4Mask, ShiftMask, XErrorEvent, XGenericEventCookie, XKeyEvent, Xlib,
+        _XDisplay,
+    },
 };
 
 use super::Message;
-use crate::{Error, Hook, KeyCode, Modifiers, Result};
+use crate::{Error, Hook, KeyCode, KeyEvent, Modifiers, Result};
 
 unsafe fn ungrab_all(xlib: &Xlib, display: *mut Display) {
     let screencount = (xlib.XScreenCount)(display);
@@ -72,6 +79,108 @@ const fn code_for(key: KeyCode) -> Option<c_uint> {
 const X_TOKEN: Token = Token(0);
 const PING_TOKEN: Token = Token(1);
 
+// X11 only reports the keys that are grabbed, and grabbing a key prevents
+// other applications from receiving it. So instead the raw key events of the X
+// Input extension are used for the key listeners, as they are reported for the
+// whole keyboard without grabbing any keys.
+struct RawKeyEvents {
+    xinput: XInput2,
+    opcode: c_int,
+}
+
+const XINPUT_EXTENSION_NAME: &[u8] = b"XInputExtension\0";
+
+type KeyListener = (Box<dyn FnMut(KeyEvent) + Send + 'static>, bool);
+
+unsafe fn open_raw_key_events(xlib: &Xlib, display: *mut Display) -> Option<RawKeyEvents> {
+    let xinput = XInput2::open().ok()?;
+
+    let (mut opcode, mut event, mut error) = (0, 0, 0);
+    if (xlib.XQueryExtension)(
+        display,
+        XINPUT_EXTENSION_NAME.as_ptr().cast(),
+        &mut opcode,
+        &mut event,
+        &mut error,
+    ) == 0
+    {
+        return None;
+    }
+
+    // Raw events were introduced with version 2.0 of the extension.
+    let (mut major, mut minor) = (2, 0);
+    if (xinput.XIQueryVersion)(display, &mut major, &mut minor) != 0 {
+        return None;
+    }
+
+    Some(RawKeyEvents { xinput, opcode })
+}
+
+// The raw key events are only selected while there are key listeners, so that
+// the key presses don't need to be processed otherwise.
+unsafe fn select_raw_key_events(
+    xlib: &Xlib,
+    display: *mut Display,
+    raw_key_events: &RawKeyEvents,
+    enabled: bool,
+) {
+    let mut mask = [0; (XI_LASTEVENT as usize >> 3) + 1];
+    if enabled {
+        XISetMask(&mut mask, XI_RawKeyPress);
+        XISetMask(&mut mask, XI_RawKeyRelease);
+    }
+    let mut event_mask = XIEventMask {
+        deviceid: XIAllMasterDevices,
+        mask_len: mask.len() as c_int,
+        mask: mask.as_mut_ptr(),
+    };
+    (raw_key_events.xinput.XISelectEvents)(
+        display,
+        (xlib.XDefaultRootWindow)(display),
+        &mut event_mask,
+        1,
+    );
+    (xlib.XFlush)(display);
+}
+
+unsafe fn handle_raw_key_event(
+    xlib: &Xlib,
+    display: *mut Display,
+    raw_key_events: &RawKeyEvents,
+    cookie: &mut XGenericEventCookie,
+    key_listeners: &mut HashMap<c_uint, KeyListener>,
+) {
+    if cookie.extension != raw_key_events.opcode || (xlib.XGetEventData)(display, cookie) == 0 {
+        return;
+    }
+
+    let is_pressed = if cookie.evtype == XI_RawKeyPress {
+        Some(true)
+    } else if cookie.evtype == XI_RawKeyRelease {
+        Some(false)
+    } else {
+        None
+    };
+    let event = &*(cookie.data as *const XIRawEvent);
+
+    if let (Some(is_pressed), Some((callback, was_pressed))) =
+        (is_pressed, key_listeners.get_mut(&(event.detail as c_uint)))
+    {
+        // Holding a key repeats its press events, but the listeners only get
+        // notified when the state of the key changes.
+        if is_pressed != *was_pressed {
+            *was_pressed = is_pressed;
+            callback(if is_pressed {
+                KeyEvent::Pressed
+            } else {
+                KeyEvent::Released
+            });
+        }
+    }
+
+    (xlib.XFreeEventData)(display, cookie);
+}
+
 pub fn new() -> Result<Hook> {
     unsafe {
         let (sender, receiver) = crossbeam_channel::unbounded();
@@ -97,18 +206,21 @@ pub fn new() -> Result<Hook> {
             )
             .map_err(|_| Error::EPoll)?;
 
-        struct XData(Xlib, *mut Display);
+        let raw_key_events = open_raw_key_events(&xlib, display);
+
+        struct XData(Xlib, *mut Display, Option<RawKeyEvents>);
         unsafe impl Send for XData {}
-        let xdata = XData(xlib, display);
+        let xdata = XData(xlib, display, raw_key_events);
 
         let join_handle = thread::spawn(move || -> Result<()> {
             // Force the whole XData to be moved.
             let xdata = xdata;
-            let XData(xlib, display) = xdata;
+            let XData(xlib, display, raw_key_events) = xdata;
 
             let mut result = Ok(());
             let mut events = Events::with_capacity(1024);
             let mut hotkeys = HashMap::new();
+            let mut key_listeners = HashMap::new();
 
             // For some reason we need to call this once for any KeyGrabs to
             // actually do anything.
@@ -150,6 +262,51 @@ pub fn new() -> Result<Hook> {
                                     }
                                     promise.set(res);
                                 }
+                                Message::RegisterKeyListener(key_code, callback, promise) => {
+                                    promise.set(match (&raw_key_events, code_for(key_code)) {
+                                        (None, _) => Err(Error::NoXInput),
+                                        (Some(raw_key_events), Some(code)) => {
+                                            match key_listeners.entry(code) {
+                                                Entry::Vacant(vacant) => {
+                                                    vacant.insert((callback, false));
+                                                    if key_listeners.len() == 1 {
+                                                        select_raw_key_events(
+                                                            &xlib,
+                                                            display,
+                                                            raw_key_events,
+                                                            true,
+                                                        );
+                                                    }
+                                                    Ok(())
+                                                }
+                                                Entry::Occupied(_) => Err(Error::AlreadyRegistered),
+                                            }
+                                        }
+                                        (Some(_), None) => Ok(()),
+                                    });
+                                }
+                                Message::UnregisterKeyListener(key_code, promise) => {
+                                    promise.set(match code_for(key_code) {
+                                        Some(code) => key_listeners
+                                            .remove(&code)
+                                            .map(|_| {
+                                                if let (true, Some(raw_key_events)) =
+                                                    (key_listeners.is_empty(), &raw_key_events)
+                                                {
+                                                    select_raw_key_events(
+                                                        &xlib,
+                                                        display,
+                                                        raw_key_events,
+                                                        false,
+                                                    );
+                                                }
+                                            })
+                                            .ok_or(Error::NotRegistered),
+                                        // Registering a key without a code does nothing,
+                                        // so there is nothing to unregister either.
+                                        None => Ok(()),
+                                    });
+                                }
                                 Message::Resolve(key_code, promise) => {
                                     promise.set(resolve(&xlib, display, key_code))
                                 }
@@ -163,8 +320,18 @@ pub fn new() -> Result<Hook> {
                             let mut event = MaybeUninit::uninit();
                             let err_code = (xlib.XNextEvent)(display, event.as_mut_ptr());
                             if err_code == 0 {
-                                let event = event.assume_init();
-                                if event.get_type() == KeyPress {
+                                let mut event = event.assume_init();
+                                if event.get_type() == GenericEvent {
+                                    if let Some(raw_key_events) = &raw_key_events {
+                                        handle_raw_key_event(
+                                            &xlib,
+                                            display,
+                                            raw_key_events,
+                                            &mut event.generic_event_cookie,
+                                            &mut key_listeners,
+                                        );
+                                    }
+                                } else if event.get_type() == KeyPress {
                                     let event: &XKeyEvent = event.as_ref();
 
                                     let mut modifiers = Modifiers::empty();
@@ -193,6 +360,7 @@ pub fn new() -> Result<Hook> {
                         }
                     }
                 }
+
             }
 
             ungrab_all(&xlib, display);
@@ -196,6 +196,16 @@ bitflags::bitflags! {
     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
     #[repr(transparent)]
     pub struct EventFlags: u64 {
+        // The device dependent flags tell the left and right modifier keys
+        // apart. They are defined in IOKit's IOLLEvent.h.
+        const DEVICE_LEFT_CONTROL = 1 << 0;
+        const DEVICE_LEFT_SHIFT = 1 << 1;
+        const DEVICE_RIGHT_SHIFT = 1 << 2;
+        const DEVICE_LEFT_COMMAND = 1 << 3;
+        const DEVICE_RIGHT_COMMAND = 1 << 4;
+        const DEVICE_LEFT_OPTION = 1 << 5;
+        const DEVICE_RIGHT_OPTION = 1 << 6;
+        const DEVICE_RIGHT_CONTROL = 1 << 13;
         const CAPS_LOCK = 1 << 16;
         const SHIFT = 1 << 17;
         const CONTROL = 1 << 18;
@@ -20,7 +20,7 @@ use self::{
         EventTapLocation, EventTapOptions, EventTapPlacement, EventTapProxy, EventType,
     },
 };
-use crate::{Hotkey, KeyCode, Modifiers};
+use crate::{Hotkey, KeyCode, KeyEvent, Modifiers};
 use std::{
     collections::{hash_map::Entry, HashMap},
     ffi::c_void,
@@ -69,6 +69,7 @@ unsafe impl Sync for RunLoop {}
 
 struct State {
     hotkeys: Mutex<HashMap<Hotkey, Box<dyn FnMut() + Send + 'static>>>,
+    key_listeners: Mutex<HashMap<KeyCode, Box<dyn FnMut(KeyEvent) + Send + 'static>>>,
 }
 
 /// A hook allows you to listen to hotkeys.
@@ -94,6 +95,7 @@ impl Hook {
     pub fn new() -> Result<Self> {
         let state = Arc::new(State {
             hotkeys: Mutex::new(HashMap::new()),
+            key_listeners: Mutex::new(HashMap::new()),
         });
         let thread_state = state.clone();
 
@@ -116,7 +118,7 @@ impl Hook {
                 EventTapLocation::SESSION,
                 EventTapPlacement::HEAD_INSERT_EVENT_TAP,
                 EventTapOptions::DEFAULT_TAP,
-                EventMask::KEY_DOWN,
+                EventMask::KEY_DOWN | EventMask::KEY_UP | EventMask::FLAGS_CHANGED,
                 Some(callback),
                 state_ptr as *mut c_void,
             );
@@ -182,6 +184,32 @@ impl Hook {
         Ok(())
     }
 
+    /// Registers a listener that gets notified whenever the given key gets
+    /// pressed or released.
+    pub fn register_key_listener<F>(&self, key_code: KeyCode, callback: F) -> Result<()>
+    where
+        F: FnMut(KeyEvent) + Send + 'static,
+    {
+        if let Entry::Vacant(vacant) = self.state.key_listeners.lock().unwrap().entry(key_code) {
+            vacant.insert(Box::new(callback));
+            Ok(())
+        } else {
+            Err(Error::AlreadyRegistered)
+        }
+    }
+
+    /// Unregisters a previously registered key listener.
+    pub fn unregister_key_listener(&self, key_code: KeyCode) -> Result<()> {
+        let _ = self
+            .state
+            .key_listeners
+            .lock()
+            .unwrap()
+            .remove(&key_code)
+            .ok_or(Error::NotRegistered)?;
+        Ok(())
+    }
+
     pub(crate) fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         unsafe {
             let current_keyboard_raw = TISCopyCurrentKeyboardInputSource();
@@ -290,7 +318,7 @@ impl Hook {
 
 unsafe extern "C" fn callback(
     _: EventTapProxy,
-    _: EventType,
+    event_type: EventType,
     event: EventRef,
     user_info: *mut c_void,
 ) -> EventRef {
@@ -438,6 +466,43 @@ unsafe extern "C" fn callback(
     let state = user_info as *const State;
     let state = &*state;
 
+    let key_event = match event_type {
+        EventType::KEY_UP => KeyEvent::Released,
+        EventType::FLAGS_CHANGED => {
+            // Modifier keys don't cause key down and key up events. Instead the
+            // flags tell whether the modifier key is held down now. Caps Lock
+            // is skipped, as its flag only tells whether it is locked.
+            let flag = match key_code {
+                KeyCode::ShiftLeft => EventFlags::DEVICE_LEFT_SHIFT,
+                KeyCode::ShiftRight => EventFlags::DEVICE_RIGHT_SHIFT,
+                KeyCode::ControlLeft => EventFlags::DEVICE_LEFT_CONTROL,
+                KeyCode::ControlRight => EventFlags::DEVICE_RIGHT_CONTROL,
+                KeyCode::AltLeft => EventFlags::DEVICE_LEFT_OPTION,
+                KeyCode::AltRight => EventFlags::DEVICE_RIGHT_OPTION,
+                KeyCode::MetaLeft => EventFlags::DEVICE_LEFT_COMMAND,
+                KeyCode::MetaRight => EventFlags::DEVICE_RIGHT_COMMAND,
+                KeyCode::Fn => EventFlags::FUNCTION,
+                _ => return event,
+            };
+            if CGEventGetFlags(event).contains(flag) {
+                KeyEvent::Pressed
+            } else {
+                KeyEvent::Released
+            }
+        }
+        _ => KeyEvent::Pressed,
+    };
+
+    if let Some(callback) = state.key_listeners.lock().unwrap().get_mut(&key_code) {
+        callback(key_event);
+    }
+
+    // Hotkeys are only triggered by key down events, so pressing a modifier
+    // key on its own doesn't trigger them.
+    if event_type != EventType::KEY_DOWN {
+        return event;
+    }
+
     let modifier_flags = CGEventGetFlags(event);
     let mut modifiers = Modifiers::empty();
 
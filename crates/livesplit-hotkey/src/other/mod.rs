@@ -1,4 +1,4 @@
-use crate::{Hotkey, KeyCode};
+use crate::{Hotkey, KeyCode, KeyEvent};
 use alloc::string::String;
 
 /// The error type for this crate.
@@ -31,6 +31,20 @@ impl Hook {
         Ok(())
     }
 
+    /// Registers a listener that gets notified whenever the given key gets
+    /// pressed or released.
+    pub fn register_key_listener<F>(&self, _: KeyCode, _: F) -> Result<()>
+    where
+        F: FnMut(KeyEvent) + Send + 'static,
+    {
+        Ok(())
+    }
+
+    /// Unregisters a previously registered key listener.
+    pub fn unregister_key_listener(&self, _: KeyCode) -> Result<()> {
+        Ok(())
+    }
+
     pub(crate) fn try_resolve(&self, _key_code: KeyCode) -> Option<String> {
         None
     }
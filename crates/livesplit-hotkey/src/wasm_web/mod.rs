@@ -1,4 +1,4 @@
-use crate::{Hotkey, KeyCode, Modifiers};
+use crate::{Hotkey, KeyCode, KeyEvent, Modifiers};
 use js_sys::{Function, Promise, Reflect};
 use wasm_bindgen::{prelude::*, JsCast};
 use web_sys::{window, Event, Gamepad, GamepadButton, KeyboardEvent};
@@ -28,7 +28,9 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// A hook allows you to listen to hotkeys.
 pub struct Hook {
     hotkeys: Arc<Mutex<HashMap<Hotkey, Box<dyn FnMut() + Send + 'static>>>>,
+    key_listeners: Arc<Mutex<HashMap<KeyCode, Box<dyn FnMut(KeyEvent) + Send + 'static>>>>,
     keyboard_callback: Closure<dyn FnMut(Event)>,
+    key_up_callback: Closure<dyn FnMut(Event)>,
     gamepad_callback: Closure<dyn FnMut()>,
     interval_id: Cell<Option<i32>>,
     keyboard_layout_resolver: Rc<RefCell<Option<(JsValue, Function)>>>,
@@ -42,6 +44,10 @@ impl Drop for Hook {
                 "keydown",
                 self.keyboard_callback.as_ref().unchecked_ref(),
             );
+            let _ = window.remove_event_listener_with_callback(
+                "keyup",
+                self.key_up_callback.as_ref().unchecked_ref(),
+            );
             if let Some(interval_id) = self.interval_id.get() {
                 window.clear_interval_with_handle(interval_id);
             }
@@ -81,9 +87,15 @@ impl Hook {
             Box<dyn FnMut() + Send + 'static>,
         >::new()));
 
+        let key_listeners = Arc::new(Mutex::new(HashMap::<
+            KeyCode,
+            Box<dyn FnMut(KeyEvent) + Send + 'static>,
+        >::new()));
+
         let window = window().ok_or(Error::FailedToCreateHook)?;
 
         let hotkey_map = hotkeys.clone();
+        let listener_map = key_listeners.clone();
         let keyboard_callback = Closure::wrap(Box::new(move |event: Event| {
             // Despite all sorts of documentation claiming that `keydown` events
             // pass you a `KeyboardEvent`, this is not actually always the case
@@ -92,6 +104,10 @@ impl Hook {
             if let Ok(event) = event.dyn_into::<KeyboardEvent>() {
                 if !event.repeat() {
                     if let Ok(code) = event.code().parse::<KeyCode>() {
+                        if let Some(callback) = listener_map.lock().unwrap().get_mut(&code) {
+                            callback(KeyEvent::Pressed);
+                        }
+
                         let mut modifiers = Modifiers::empty();
                         if event.shift_key()
                             && !matches!(code, KeyCode::ShiftLeft | KeyCode::ShiftRight)
@@ -129,7 +145,23 @@ impl Hook {
             .add_event_listener_with_callback("keydown", keyboard_callback.as_ref().unchecked_ref())
             .map_err(|_| Error::FailedToCreateHook)?;
 
+        let listener_map = key_listeners.clone();
+        let key_up_callback = Closure::wrap(Box::new(move |event: Event| {
+            if let Ok(event) = event.dyn_into::<KeyboardEvent>() {
+                if let Ok(code) = event.code().parse::<KeyCode>() {
+                    if let Some(callback) = listener_map.lock().unwrap().get_mut(&code) {
+                        callback(KeyEvent::Released);
+                    }
+                }
+            }
+        }) as Box<dyn FnMut(Event)>);
+
+        window
+            .add_event_listener_with_callback("keyup", key_up_callback.as_ref().unchecked_ref())
+            .map_err(|_| Error::FailedToCreateHook)?;
+
         let hotkey_map = hotkeys.clone();
+        let listener_map = key_listeners.clone();
 
         let mut states = Vec::new();
         let navigator = window.navigator();
@@ -180,6 +212,17 @@ impl Hook {
                         {
                             if let Ok(button) = button.dyn_into::<GamepadButton>() {
                                 let pressed = button.pressed();
+                                if pressed != *state {
+                                    if let Some(callback) =
+                                        listener_map.lock().unwrap().get_mut(&code)
+                                    {
+                                        callback(if pressed {
+                                            KeyEvent::Pressed
+                                        } else {
+                                            KeyEvent::Released
+                                        });
+                                    }
+                                }
                                 if pressed && !*state {
                                     if let Some(callback) =
                                         hotkey_map.lock().unwrap().get_mut(&code.into())
@@ -197,7 +240,9 @@ impl Hook {
 
         Ok(Hook {
             hotkeys,
+            key_listeners,
             keyboard_callback,
+            key_up_callback,
             gamepad_callback,
             interval_id: Cell::new(None),
             keyboard_layout_resolver,
@@ -211,16 +256,7 @@ impl Hook {
         F: FnMut() + Send + 'static,
     {
         if let Entry::Vacant(vacant) = self.hotkeys.lock().unwrap().entry(hotkey) {
-            if GAMEPAD_BUTTONS.contains(&hotkey.key_code) && self.interval_id.get().is_none() {
-                let interval_id = window()
-                    .ok_or(Error::FailedToCreateHook)?
-                    .set_interval_with_callback_and_timeout_and_arguments_0(
-                        self.gamepad_callback.as_ref().unchecked_ref(),
-                        1000 / 60,
-                    )
-                    .map_err(|_| Error::FailedToCreateHook)?;
-                self.interval_id.set(Some(interval_id));
-            }
+            self.poll_gamepads_for(hotkey.key_code)?;
             vacant.insert(Box::new(callback));
             Ok(())
         } else {
@@ -237,6 +273,50 @@ impl Hook {
         }
     }
 
+    /// Registers a listener that gets notified whenever the given key gets
+    /// pressed or released.
+    pub fn register_key_listener<F>(&self, key_code: KeyCode, callback: F) -> Result<()>
+    where
+        F: FnMut(KeyEvent) + Send + 'static,
+    {
+        if let Entry::Vacant(vacant) = self.key_listeners.lock().unwrap().entry(key_code) {
+            self.poll_gamepads_for(key_code)?;
+            vacant.insert(Box::new(callback));
+            Ok(())
+        } else {
+            Err(Error::AlreadyRegistered)
+        }
+    }
+
+    /// Unregisters a previously registered key listener.
+    pub fn unregister_key_listener(&self, key_code: KeyCode) -> Result<()> {
+        if self
+            .key_listeners
+            .lock()
+            .unwrap()
+            .remove(&key_code)
+            .is_some()
+        {
+            Ok(())
+        } else {
+            Err(Error::NotRegistered)
+        }
+    }
+
+    fn poll_gamepads_for(&self, key_code: KeyCode) -> Result<()> {
+        if GAMEPAD_BUTTONS.contains(&key_code) && self.interval_id.get().is_none() {
+            let interval_id = window()
+                .ok_or(Error::FailedToCreateHook)?
+                .set_interval_with_callback_and_timeout_and_arguments_0(
+                    self.gamepad_callback.as_ref().unchecked_ref(),
+                    1000 / 60,
+                )
+                .map_err(|_| Error::FailedToCreateHook)?;
+            self.interval_id.set(Some(interval_id));
+        }
+        Ok(())
+    }
+
     pub(crate) fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         let keyboard_layout_resolver = self.keyboard_layout_resolver.borrow();
         let (layout, resolve_fn) = keyboard_layout_resolver.as_ref()?;
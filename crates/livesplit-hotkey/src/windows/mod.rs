@@ -1,4 +1,4 @@
-use crate::{Hotkey, KeyCode, Modifiers};
+use crate::{Hotkey, KeyCode, KeyEvent, Modifiers};
 use std::{
     cell::RefCell,
     collections::hash_map::{Entry, HashMap},
@@ -52,6 +52,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub struct Hook {
     thread_id: DWORD,
     hotkeys: Arc<Mutex<HashMap<Hotkey, Box<dyn FnMut() + Send + 'static>>>>,
+    key_listeners: Arc<Mutex<HashMap<KeyCode, Box<dyn FnMut(KeyEvent) + Send + 'static>>>>,
 }
 
 impl Drop for Hook {
@@ -64,7 +65,7 @@ impl Drop for Hook {
 
 struct State {
     hook: HHOOK,
-    events: Sender<Hotkey>,
+    events: Sender<(Hotkey, KeyEvent)>,
     modifiers: Modifiers,
     // FIXME: Use variant count when it's stable.
     // https://github.com/rust-lang/rust/issues/73662
@@ -294,10 +295,13 @@ unsafe extern "system" fn callback_proc(code: c_int, wparam: WPARAM, lparam: LPA
 
                         state
                             .events
-                            .send(Hotkey {
-                                key_code,
-                                modifiers: state.modifiers,
-                            })
+                            .send((
+                                Hotkey {
+                                    key_code,
+                                    modifiers: state.modifiers,
+                                },
+                                KeyEvent::Pressed,
+                            ))
                             .expect("Callback Thread disconnected");
 
                         match key_code {
@@ -344,7 +348,14 @@ unsafe extern "system" fn callback_proc(code: c_int, wparam: WPARAM, lparam: LPA
 
                 if let Some(key_code) = parse_scan_code(scan_code) {
                     let (idx, bit) = key_idx(key_code);
-                    state.key_state[idx as usize] &= !bit;
+                    if state.key_state[idx as usize] & bit != 0 {
+                        state.key_state[idx as usize] &= !bit;
+
+                        state
+                            .events
+                            .send((key_code.into(), KeyEvent::Released))
+                            .expect("Callback Thread disconnected");
+                    }
 
                     match key_code {
                         KeyCode::AltLeft | KeyCode::AltRight => {
@@ -437,19 +448,34 @@ impl Hook {
             Ok(())
         });
 
+        let key_listeners = Arc::new(Mutex::new(HashMap::<
+            KeyCode,
+            Box<dyn FnMut(KeyEvent) + Send + 'static>,
+        >::new()));
+
         let hotkey_map = hotkeys.clone();
+        let listener_map = key_listeners.clone();
 
         thread::spawn(move || {
-            while let Ok(key) = events_rx.recv() {
-                if let Some(callback) = hotkey_map.lock().unwrap().get_mut(&key) {
-                    callback();
+            while let Ok((key, event)) = events_rx.recv() {
+                if let Some(callback) = listener_map.lock().unwrap().get_mut(&key.key_code) {
+                    callback(event);
+                }
+                if event == KeyEvent::Pressed {
+                    if let Some(callback) = hotkey_map.lock().unwrap().get_mut(&key) {
+                        callback();
+                    }
                 }
             }
         });
 
         let thread_id = initialized_rx.recv().map_err(|_| Error::ThreadStopped)??;
 
-        Ok(Hook { thread_id, hotkeys })
+        Ok(Hook {
+            thread_id,
+            hotkeys,
+            key_listeners,
+        })
     }
 
     /// Registers a hotkey to listen to.
@@ -474,6 +500,35 @@ impl Hook {
         }
     }
 
+    /// Registers a listener that gets notified whenever the given key gets
+    /// pressed or released.
+    pub fn register_key_listener<F>(&self, key_code: KeyCode, callback: F) -> Result<()>
+    where
+        F: FnMut(KeyEvent) + Send + 'static,
+    {
+        if let Entry::Vacant(vacant) = self.key_listeners.lock().unwrap().entry(key_code) {
+            vacant.insert(Box::new(callback));
+            Ok(())
+        } else {
+            Err(Error::AlreadyRegistered)
+        }
+    }
+
+    /// Unregisters a previously registered key listener.
+    pub fn unregister_key_listener(&self, key_code: KeyCode) -> Result<()> {
+        if self
+            .key_listeners
+            .lock()
+            .unwrap()
+            .remove(&key_code)
+            .is_some()
+        {
+            Ok(())
+        } else {
+            Err(Error::NotRegistered)
+        }
+    }
+
     pub(crate) fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         use self::KeyCode::*;
         let scan_code = match key_code {
@@ -0,0 +1,230 @@
+//! Provides functionality to calculate the distributions of the segment times
+//! and split times of each segment of a [`Run`]. The distributions consist of
+//! the times binned into a histogram, as well as some percentiles, the mean and
+//! the median. The results are meant to be visualized as histograms.
+//!
+//! The segment times are collected the same way as for the
+//! [`SkillCurve`](super::SkillCurve). Only actual attempts are considered,
+//! skipped segments don't have a segment time and combined segment times are
+//! not considered. The split times on the other hand are not affected by
+//! skipped segments, so the split time of the segment after a skipped segment
+//! is still considered.
+
+use crate::{
+    platform::{math::f64::floor, prelude::*},
+    Run, Segment, TimeSpan, TimingMethod,
+};
+use serde::{Deserialize, Serialize};
+
+/// The options for calculating the distributions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Options {
+    /// The number of bins each histogram is split into. The bins are evenly
+    /// distributed between the fastest and the slowest time.
+    pub bins: u32,
+    /// The percentiles to calculate. Each percentile is a value between 0 and
+    /// 1, where 0 is the fastest time and 1 is the slowest time.
+    pub percentiles: Vec<f64>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            bins: 20,
+            percentiles: vec![0.1, 0.25, 0.5, 0.75, 0.9],
+        }
+    }
+}
+
+/// The distributions of all the segments of a [`Run`].
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Distributions {
+    /// The distributions of each segment.
+    pub segments: Vec<SegmentDistributions>,
+}
+
+/// The distributions of a single segment.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentDistributions {
+    /// The distribution of the segment times of the segment.
+    pub segment_times: Distribution,
+    /// The distribution of the split times of the segment.
+    pub split_times: Distribution,
+}
+
+/// The distribution of a set of times. All the times are in seconds.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Distribution {
+    /// The number of times the distribution is based on.
+    pub samples: u32,
+    /// The fastest time. This is `None` if there are no times.
+    pub min: Option<f64>,
+    /// The slowest time. This is `None` if there are no times.
+    pub max: Option<f64>,
+    /// The mean of the times. This is `None` if there are no times.
+    pub mean: Option<f64>,
+    /// The median of the times. This is `None` if there are no times.
+    pub median: Option<f64>,
+    /// The requested percentiles. This is empty if there are no times.
+    pub percentiles: Vec<Percentile>,
+    /// The bins of the histogram, ordered from the fastest to the slowest
+    /// times. This is empty if there are no times.
+    pub bins: Vec<Bin>,
+}
+
+/// A percentile of a distribution.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Percentile {
+    /// The percentile as a value between 0 and 1.
+    pub percentile: f64,
+    /// The time at the percentile in seconds. Times between two samples are
+    /// linearly interpolated.
+    pub time: f64,
+}
+
+/// A single bin of a histogram.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bin {
+    /// The start of the bin in seconds. The start is inclusive.
+    pub start: f64,
+    /// The end of the bin in seconds. The end is exclusive, unless this is the
+    /// last bin.
+    pub end: f64,
+    /// The number of times that fall into this bin.
+    pub count: u32,
+}
+
+#[cfg(feature = "std")]
+impl Distributions {
+    /// Encodes the distributions as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+/// Calculates the distributions of the segment times and split times of each
+/// segment of the run provided for the timing method specified.
+pub fn calculate(run: &Run, method: TimingMethod, options: &Options) -> Distributions {
+    let segments = run.segments();
+    let mut split_times = vec![Vec::new(); segments.len()];
+
+    for attempt in run.attempt_history() {
+        let id = attempt.index();
+        if id <= 0 {
+            continue;
+        }
+        let mut split_time = TimeSpan::zero();
+        for (segment, split_times) in segments.iter().zip(&mut split_times) {
+            // If there is no segment time, the attempt was reset.
+            let Some(time) = segment.segment_history().get(id) else {
+                break;
+            };
+            if let Some(time) = time[method] {
+                split_time += time;
+                split_times.push(split_time.total_seconds());
+            }
+        }
+    }
+
+    let mut times = Vec::new();
+
+    Distributions {
+        segments: split_times
+            .into_iter()
+            .enumerate()
+            .map(|(segment_index, mut split_times)| {
+                times.clear();
+                times.extend(segment_times(segments, segment_index, method));
+                SegmentDistributions {
+                    segment_times: distribution(&mut times, options),
+                    split_times: distribution(&mut split_times, options),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Iterates over the segment times of the segment in seconds, from the oldest
+/// to the most recent. Combined segment times are skipped.
+pub(crate) fn segment_times(
+    segments: &[Segment],
+    segment_index: usize,
+    method: TimingMethod,
+) -> impl DoubleEndedIterator<Item = f64> + '_ {
+    segments[segment_index]
+        .segment_history()
+        .iter_actual_runs()
+        .filter_map(move |&(id, time)| {
+            let time = time[method]?;
+            // Skip all the combined segments
+            let skip = catch! {
+                segments[segment_index.checked_sub(1)?].segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+            if skip {
+                None
+            } else {
+                Some(time.total_seconds())
+            }
+        })
+}
+
+fn distribution(times: &mut [f64], options: &Options) -> Distribution {
+    times.sort_unstable_by(|a, b| a.total_cmp(b));
+    let (Some(&min), Some(&max)) = (times.first(), times.last()) else {
+        return Distribution::default();
+    };
+
+    let bin_count = options.bins.max(1);
+    let bin_width = (max - min) / bin_count as f64;
+    let mut bins: Vec<_> = (0..bin_count)
+        .map(|i| Bin {
+            start: min + i as f64 * bin_width,
+            end: if i + 1 == bin_count {
+                max
+            } else {
+                min + (i + 1) as f64 * bin_width
+            },
+            count: 0,
+        })
+        .collect();
+    for &time in &*times {
+        let index = if bin_width > 0.0 {
+            (floor((time - min) / bin_width) as usize).min(bins.len() - 1)
+        } else {
+            0
+        };
+        bins[index].count += 1;
+    }
+
+    Distribution {
+        samples: times.len() as u32,
+        min: Some(min),
+        max: Some(max),
+        mean: Some(times.iter().sum::<f64>() / times.len() as f64),
+        median: Some(percentile(times, 0.5)),
+        percentiles: options
+            .percentiles
+            .iter()
+            .map(|&p| Percentile {
+                percentile: p,
+                time: percentile(times, p),
+            })
+            .collect(),
+        bins,
+    }
+}
+
+/// Determines the time at the percentile of the sorted times provided, which
+/// must not be empty.
+fn percentile(times: &[f64], percentile: f64) -> f64 {
+    let position = percentile.clamp(0.0, 1.0) * (times.len() - 1) as f64;
+    let left = floor(position) as usize;
+    let right = (left + 1).min(times.len() - 1);
+    let fraction = position - left as f64;
+    times[left] + fraction * (times[right] - times[left])
+}
@@ -3,12 +3,20 @@
 
 pub mod current_pace;
 pub mod delta;
+pub mod distributions;
 pub mod pb_chance;
 pub mod possible_time_save;
+pub mod prediction;
+pub mod reset_points;
+pub mod segment_outlook;
+pub mod sessions;
 mod skill_curve;
 pub mod state_helper;
 pub mod sum_of_segments;
+pub mod suspicious_golds;
+pub mod time_lost;
 pub mod total_playtime;
+pub mod trends;
 
 pub use self::skill_curve::SkillCurve;
 pub use self::state_helper::*;
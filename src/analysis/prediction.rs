@@ -0,0 +1,293 @@
+//! Provides functionality to predict the final time of an attempt through a
+//! Monte Carlo simulation. For each of the remaining segments, a segment time
+//! is randomly sampled from the segment's history. Summing them up many times
+//! results in a distribution of possible final times, which allows calculating
+//! confidence intervals and the probability of beating certain times, such as
+//! the Personal Best or a goal time.
+//!
+//! The simulation is cached by the [`Predictor`] and only rerun when the
+//! current split or the history changes, so it is fast enough to be used for
+//! every frame.
+
+use crate::{platform::prelude::*, timing::Snapshot, Segment, TimeSpan, TimerPhase, TimingMethod};
+
+/// The number of attempts a [`Predictor`] simulates by default.
+pub const DEFAULT_SAMPLES: usize = 1000;
+const RECENCY_WEIGHT: f64 = 0.9;
+const SEED: u64 = 0x2545_F491_4F6C_DD1D;
+
+/// A predictor predicts the final time of an attempt through a Monte Carlo
+/// simulation. It caches the simulation, so that it only needs to be rerun
+/// when the current split or the history changes.
+#[derive(Clone, Debug)]
+pub struct Predictor {
+    samples: usize,
+    weight_recent: bool,
+    key: Option<CacheKey>,
+    until_split: Vec<TimeSpan>,
+    remaining: Vec<TimeSpan>,
+    weighted_times: Vec<(f64, TimeSpan)>,
+    final_times: Vec<TimeSpan>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct CacheKey {
+    start: usize,
+    current: usize,
+    method: TimingMethod,
+    segments: usize,
+    attempts: usize,
+    attempt_count: u32,
+    history: usize,
+}
+
+/// The distribution of the final times that a [`Predictor`] predicted. The
+/// final times are sorted from the fastest to the slowest.
+#[derive(Copy, Clone, Debug)]
+pub struct Prediction<'a> {
+    final_times: &'a [TimeSpan],
+}
+
+impl Default for Predictor {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLES, false)
+    }
+}
+
+impl Predictor {
+    /// Creates a new predictor that simulates the number of attempts
+    /// specified. If `weight_recent` is `true`, more recent segment times are
+    /// more likely to be sampled than older ones.
+    pub const fn new(samples: usize, weight_recent: bool) -> Self {
+        Self {
+            samples: if samples > 0 { samples } else { 1 },
+            weight_recent,
+            key: None,
+            until_split: Vec::new(),
+            remaining: Vec::new(),
+            weighted_times: Vec::new(),
+            final_times: Vec::new(),
+        }
+    }
+
+    /// Returns whether more recent segment times are more likely to be
+    /// sampled than older ones.
+    pub const fn weight_recent(&self) -> bool {
+        self.weight_recent
+    }
+
+    /// Sets whether more recent segment times are more likely to be sampled
+    /// than older ones.
+    pub fn set_weight_recent(&mut self, weight_recent: bool) {
+        if self.weight_recent != weight_recent {
+            self.weight_recent = weight_recent;
+            self.invalidate();
+        }
+    }
+
+    /// Discards the cached simulation, so it gets rerun the next time a
+    /// prediction is requested. The cache is automatically discarded when the
+    /// current split, the number of attempts or any of the segment histories
+    /// change, so this usually doesn't need to be called.
+    pub fn invalidate(&mut self) {
+        self.key = None;
+    }
+
+    /// Predicts the final time of the timer's current attempt. If there is no
+    /// active attempt, the final time of a new attempt is predicted. Returns
+    /// `None` if any of the remaining segments doesn't have any history to
+    /// sample from.
+    pub fn predict(&mut self, timer: &Snapshot<'_>) -> Option<Prediction<'_>> {
+        let method = timer.current_timing_method();
+        let run = timer.run();
+        let segments = run.segments();
+
+        let (current, elapsed_time) = match timer.current_phase() {
+            TimerPhase::Ended => {
+                let final_time = segments.last()?.split_time()[method]?;
+                self.key = None;
+                self.final_times.clear();
+                self.final_times.push(final_time);
+                return Some(Prediction {
+                    final_times: &self.final_times,
+                });
+            }
+            TimerPhase::NotRunning => (0, TimeSpan::zero()),
+            TimerPhase::Running | TimerPhase::Paused => (
+                timer.current_split_index()?,
+                timer.current_time()[method].unwrap_or_default(),
+            ),
+        };
+
+        let (start, offset) = segments[..current]
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(i, s)| Some((i + 1, s.split_time()[method]?)))
+            .unwrap_or_default();
+
+        let key = CacheKey {
+            start,
+            current,
+            method,
+            segments: segments.len(),
+            attempts: run.attempt_history().len(),
+            attempt_count: run.attempt_count(),
+            // Every modification increases the revision of one of the segment
+            // histories, so their sum changes with every modification.
+            history: segments
+                .iter()
+                .map(|segment| segment.segment_history().revision())
+                .fold(0, usize::wrapping_add),
+        };
+
+        if self.key != Some(key) {
+            self.key = None;
+            self.simulate(segments, start, current, method)?;
+            self.key = Some(key);
+        }
+
+        let elapsed_in_segment = elapsed_time - offset;
+        self.final_times.clear();
+        self.final_times
+            .extend(self.until_split.iter().zip(&self.remaining).map(
+                |(&until_split, &remaining)| {
+                    offset + until_split.max(elapsed_in_segment) + remaining
+                },
+            ));
+        self.final_times.sort_unstable();
+
+        Some(Prediction {
+            final_times: &self.final_times,
+        })
+    }
+
+    fn simulate(
+        &mut self,
+        segments: &[Segment],
+        start: usize,
+        current: usize,
+        method: TimingMethod,
+    ) -> Option<()> {
+        self.until_split.clear();
+        self.until_split.resize(self.samples, TimeSpan::zero());
+        self.remaining.clear();
+        self.remaining.resize(self.samples, TimeSpan::zero());
+
+        let mut rng = SplitMix64(SEED);
+
+        for (index, segment) in segments.iter().enumerate().skip(start) {
+            let previous_segment = index.checked_sub(1).map(|i| &segments[i]);
+            self.collect_weighted_times(segment, previous_segment, method);
+            let total_weight = self.weighted_times.last()?.0;
+
+            let sums = if index <= current {
+                &mut self.until_split
+            } else {
+                &mut self.remaining
+            };
+
+            for sum in sums {
+                let target = rng.next_f64() * total_weight;
+                let found_index = self
+                    .weighted_times
+                    .partition_point(|&(weight, _)| weight <= target)
+                    .min(self.weighted_times.len() - 1);
+                *sum += self.weighted_times[found_index].1;
+            }
+        }
+
+        Some(())
+    }
+
+    /// Collects the segment times of the segment with their accumulated
+    /// weights, so that they can be sampled via a binary search.
+    fn collect_weighted_times(
+        &mut self,
+        segment: &Segment,
+        previous_segment: Option<&Segment>,
+        method: TimingMethod,
+    ) {
+        self.weighted_times.clear();
+
+        let mut current_weight = 1.0;
+        let mut total_weight = 0.0;
+
+        for &(id, time) in segment.segment_history().iter_actual_runs().rev() {
+            if let Some(time) = time[method] {
+                // Skip all the combined segments
+                let skip = catch! {
+                    previous_segment?.segment_history().get(id)?[method].is_none()
+                }
+                .unwrap_or(false);
+
+                if !skip {
+                    total_weight += current_weight;
+                    self.weighted_times.push((total_weight, time));
+                    if self.weight_recent {
+                        current_weight *= RECENCY_WEIGHT;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Prediction<'_> {
+    /// Accesses all the predicted final times, sorted from the fastest to the
+    /// slowest.
+    pub const fn final_times(&self) -> &[TimeSpan] {
+        self.final_times
+    }
+
+    /// Returns the predicted final time at the percentile specified. A
+    /// percentile of 0 yields the fastest predicted time, while a percentile
+    /// of 1 yields the slowest predicted time.
+    pub fn percentile(&self, percentile: f64) -> TimeSpan {
+        let last_index = self.final_times.len() - 1;
+        let index = (percentile.clamp(0.0, 1.0) * last_index as f64 + 0.5) as usize;
+        self.final_times[index.min(last_index)]
+    }
+
+    /// Returns the median of the predicted final times.
+    pub fn median(&self) -> TimeSpan {
+        self.percentile(0.5)
+    }
+
+    /// Returns the interval that contains the specified share of the
+    /// predicted final times, centered around the median. A confidence of 0.9
+    /// for example yields the interval from the 5th to the 95th percentile.
+    pub fn confidence_interval(&self, confidence: f64) -> (TimeSpan, TimeSpan) {
+        let tail = 0.5 * (1.0 - confidence.clamp(0.0, 1.0));
+        (self.percentile(tail), self.percentile(1.0 - tail))
+    }
+
+    /// Returns the probability of the final time being faster than the time
+    /// provided. This can be used for determining the chance of beating a
+    /// comparison or of reaching a goal time. The value is being reported as
+    /// a floating point number in the range from 0 (0%) to 1 (100%).
+    pub fn probability_of_beating(&self, time: TimeSpan) -> f64 {
+        let faster = self.final_times.partition_point(|&t| t < time);
+        faster as f64 / self.final_times.len() as f64
+    }
+}
+
+/// A small and fast pseudo random number generator. The simulation doesn't
+/// need cryptographic randomness, but it should be deterministic, so the
+/// predictions don't jitter whenever they get recalculated.
+#[derive(Copy, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
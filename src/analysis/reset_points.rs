@@ -0,0 +1,77 @@
+//! Provides functionality to analyze where attempts end. For each segment, the
+//! number of attempts reaching it and the number of attempts that were reset
+//! during it are determined from the attempt history. Based on that, the
+//! probability of surviving from a segment to the end of the run and the time
+//! that is expected to be wasted by resetting during a segment are calculated.
+
+use crate::{platform::prelude::*, Run, TimeSpan};
+
+/// Describes where attempts end in relation to a single segment.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ResetPoint {
+    /// The number of attempts that reached this segment.
+    pub reached: u32,
+    /// The number of attempts that were reset during this segment.
+    pub resets: u32,
+    /// The probability of an attempt that reaches this segment to also reach
+    /// the end of the run. This is a value between 0 and 1. If no attempt ever
+    /// reached a segment, it is assumed that the segment is always survived.
+    pub survival_probability: f64,
+    /// The total amount of time that was spent in the attempts that were
+    /// reset during this segment.
+    pub time_wasted: TimeSpan,
+    /// The amount of time an attempt that reaches this segment is expected to
+    /// waste by being reset during this segment. This is the time wasted
+    /// divided by the number of attempts reaching the segment.
+    pub expected_time_wasted: TimeSpan,
+}
+
+/// Calculates the reset points of all the segments of the run provided. The
+/// durations of the attempts are used for determining the time wasted, so
+/// attempts without information about their duration don't contribute to it.
+pub fn calculate(run: &Run) -> Vec<ResetPoint> {
+    let segments = run.segments();
+    let mut reset_points = vec![ResetPoint::default(); segments.len()];
+
+    for attempt in run.attempt_history() {
+        let id = attempt.index();
+        let passed = segments
+            .iter()
+            .take_while(|s| s.segment_history().get(id).is_some())
+            .count();
+
+        for reset_point in &mut reset_points[..(passed + 1).min(segments.len())] {
+            reset_point.reached += 1;
+        }
+
+        if let Some(reset_point) = reset_points.get_mut(passed) {
+            reset_point.resets += 1;
+            if let Some(duration) = attempt.duration() {
+                reset_point.time_wasted += duration - attempt.pause_time().unwrap_or_default();
+            }
+        }
+    }
+
+    let mut survival_probability = 1.0;
+    for reset_point in reset_points.iter_mut().rev() {
+        if reset_point.reached != 0 {
+            let reached = reset_point.reached as f64;
+            survival_probability *= 1.0 - reset_point.resets as f64 / reached;
+            reset_point.expected_time_wasted =
+                TimeSpan::from_seconds(reset_point.time_wasted.total_seconds() / reached);
+        }
+        reset_point.survival_probability = survival_probability;
+    }
+
+    reset_points
+}
+
+/// Calculates the probability of an attempt that reaches the segment with the
+/// index provided to also reach the end of the run. This is a value between 0
+/// and 1. If the index is past the last segment, the run is considered
+/// survived.
+pub fn survival_probability(run: &Run, segment_index: usize) -> f64 {
+    calculate(run)
+        .get(segment_index)
+        .map_or(1.0, |reset_point| reset_point.survival_probability)
+}
@@ -0,0 +1,257 @@
+//! Provides functions for estimating the outlook of individual segments based
+//! on the distribution of their recent segment times. This includes the
+//! probability of getting a new best segment and the amount of time that is
+//! expected to be saved compared to a comparison. Unlike the
+//! [`possible_time_save`](super::possible_time_save), which is the maximum
+//! amount of time that could be saved, this is the amount of time that is
+//! realistically going to be saved on average.
+//!
+//! More recent segment times are weighted higher than older ones, so that the
+//! estimations represent the current performance of the runner.
+
+use crate::{
+    analysis,
+    platform::{
+        math::f64::{erf, sqrt},
+        prelude::*,
+    },
+    timing::Snapshot,
+    Run, Segment, TimeSpan, TimingMethod,
+};
+
+const WEIGHT: f64 = 0.9;
+
+/// Iterates over the segment times of the segment in seconds, from the most
+/// recent to the oldest, along with their weight. Combined segment times are
+/// skipped.
+fn weighted_times(
+    segments: &[Segment],
+    segment_index: usize,
+    method: TimingMethod,
+) -> impl Iterator<Item = (f64, f64)> + '_ {
+    let previous_segment = segment_index.checked_sub(1).map(|i| &segments[i]);
+    let mut current_weight = 1.0 / WEIGHT;
+
+    segments[segment_index]
+        .segment_history()
+        .iter_actual_runs()
+        .rev()
+        .filter_map(move |&(id, time)| {
+            let time = time[method]?;
+            // Skip all the combined segments
+            let skip = catch! {
+                previous_segment?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+
+            if skip {
+                return None;
+            }
+
+            current_weight *= WEIGHT;
+            Some((current_weight, time.total_seconds()))
+        })
+}
+
+fn normal_cdf(x: f64, mean: f64, standard_deviation: f64) -> f64 {
+    if standard_deviation <= 0.0 {
+        return if x > mean { 1.0 } else { 0.0 };
+    }
+    0.5 * (1.0 + erf((x - mean) / (standard_deviation * core::f64::consts::SQRT_2)))
+}
+
+fn elapsed(timer: &Snapshot<'_>, segment_index: usize, method: TimingMethod) -> Option<TimeSpan> {
+    if timer.current_split_index() == Some(segment_index) {
+        analysis::live_segment_time(timer, segment_index, method)
+    } else {
+        None
+    }
+}
+
+/// The weighted distribution of the recent segment times of a segment. All the
+/// estimations of a segment's outlook are based on it. Collecting the segment
+/// times requires iterating over the segment's history, so the outlook can be
+/// kept around for as long as the history doesn't change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outlook {
+    segment_index: usize,
+    method: TimingMethod,
+    times: Vec<(f64, f64)>,
+    total_weight: f64,
+    mean: f64,
+    standard_deviation: f64,
+}
+
+impl Outlook {
+    /// Collects the recent segment times of the segment specified.
+    pub fn new(run: &Run, segment_index: usize, method: TimingMethod) -> Self {
+        let times: Vec<_> = weighted_times(run.segments(), segment_index, method).collect();
+
+        let (mut total_weight, mut total) = (0.0, 0.0);
+        for &(weight, time) in &times {
+            total_weight += weight;
+            total += weight * time;
+        }
+
+        let (mean, standard_deviation) = if total_weight != 0.0 {
+            let mean = total / total_weight;
+            let variance = times
+                .iter()
+                .map(|&(weight, time)| weight * (time - mean) * (time - mean))
+                .sum::<f64>()
+                / total_weight;
+            (mean, sqrt(variance))
+        } else {
+            (0.0, 0.0)
+        };
+
+        Self {
+            segment_index,
+            method,
+            times,
+            total_weight,
+            mean,
+            standard_deviation,
+        }
+    }
+
+    fn calculate_gold_probability(&self, run: &Run, elapsed: Option<TimeSpan>) -> Option<f64> {
+        let best_segment =
+            run.segment(self.segment_index).best_segment_time()[self.method]?.total_seconds();
+
+        if self.total_weight == 0.0 {
+            return None;
+        }
+
+        let below_best = normal_cdf(best_segment, self.mean, self.standard_deviation);
+
+        let Some(elapsed) = elapsed else {
+            return Some(below_best);
+        };
+
+        let elapsed = elapsed.total_seconds();
+        if elapsed >= best_segment {
+            return Some(0.0);
+        }
+
+        // The segment is known to take at least as long as the time that
+        // already elapsed, so the distribution gets conditioned on that.
+        let below_elapsed = normal_cdf(elapsed, self.mean, self.standard_deviation);
+        let remaining = 1.0 - below_elapsed;
+        Some(if remaining > 0.0 {
+            ((below_best - below_elapsed) / remaining).clamp(0.0, 1.0)
+        } else {
+            0.0
+        })
+    }
+
+    fn calculate_expected_time_save(
+        &self,
+        run: &Run,
+        comparison: &str,
+        elapsed: Option<TimeSpan>,
+    ) -> Option<TimeSpan> {
+        let comparison_time = analysis::comparison_combined_segment_time(
+            run,
+            self.segment_index,
+            comparison,
+            self.method,
+        )?
+        .total_seconds();
+        let elapsed = elapsed.map_or(0.0, |t| t.total_seconds());
+
+        if self.total_weight == 0.0 {
+            return None;
+        }
+
+        let total_save = self
+            .times
+            .iter()
+            .map(|&(weight, time)| weight * (comparison_time - time.max(elapsed)).max(0.0))
+            .sum::<f64>();
+
+        Some(TimeSpan::from_seconds(total_save / self.total_weight))
+    }
+
+    /// Calculates the probability of getting a new best segment on the
+    /// segment. See [`gold_probability`] for details.
+    pub fn gold_probability(&self, run: &Run) -> Option<f64> {
+        self.calculate_gold_probability(run, None)
+    }
+
+    /// Calculates the probability of getting a new best segment on the
+    /// segment, taking the current attempt into account. See
+    /// [`live_gold_probability`] for details.
+    pub fn live_gold_probability(&self, timer: &Snapshot<'_>) -> Option<f64> {
+        let elapsed = elapsed(timer, self.segment_index, self.method);
+        self.calculate_gold_probability(timer.run(), elapsed)
+    }
+
+    /// Calculates the amount of time that is expected to be saved on the
+    /// segment compared to the comparison's segment time. See
+    /// [`expected_time_save`] for details.
+    pub fn expected_time_save(&self, run: &Run, comparison: &str) -> Option<TimeSpan> {
+        self.calculate_expected_time_save(run, comparison, None)
+    }
+
+    /// Calculates the amount of time that is expected to be saved on the
+    /// segment compared to the comparison's segment time, taking the current
+    /// attempt into account. See [`live_expected_time_save`] for details.
+    pub fn live_expected_time_save(
+        &self,
+        timer: &Snapshot<'_>,
+        comparison: &str,
+    ) -> Option<TimeSpan> {
+        let elapsed = elapsed(timer, self.segment_index, self.method);
+        self.calculate_expected_time_save(timer.run(), comparison, elapsed)
+    }
+}
+
+/// Calculates the probability of getting a new best segment on the segment
+/// specified. The segment times are assumed to be normally distributed
+/// according to the recent segment times. The value is being reported as a
+/// floating point number in the range from 0 (0%) to 1 (100%). Returns `None`
+/// if there is no best segment or no history for the segment.
+pub fn gold_probability(run: &Run, segment_index: usize, method: TimingMethod) -> Option<f64> {
+    Outlook::new(run, segment_index, method).gold_probability(run)
+}
+
+/// Calculates the probability of getting a new best segment on the segment
+/// specified, taking the current attempt into account. If the segment is the
+/// current segment, the time that already elapsed in it is considered, so the
+/// probability shrinks towards zero as time goes on.
+pub fn live_gold_probability(
+    timer: &Snapshot<'_>,
+    segment_index: usize,
+    method: TimingMethod,
+) -> Option<f64> {
+    Outlook::new(timer.run(), segment_index, method).live_gold_probability(timer)
+}
+
+/// Calculates the amount of time that is expected to be saved on the segment
+/// specified compared to the comparison's segment time. Segment times that are
+/// slower than the comparison don't save any time, so the time returned can
+/// never be below zero. Returns `None` if there is no comparison time or no
+/// history for the segment.
+pub fn expected_time_save(
+    run: &Run,
+    segment_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    Outlook::new(run, segment_index, method).expected_time_save(run, comparison)
+}
+
+/// Calculates the amount of time that is expected to be saved on the segment
+/// specified compared to the comparison's segment time, taking the current
+/// attempt into account. If the segment is the current segment, the time that
+/// already elapsed in it is considered, so the expected time save shrinks
+/// towards zero as time goes on.
+pub fn live_expected_time_save(
+    timer: &Snapshot<'_>,
+    segment_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    Outlook::new(timer.run(), segment_index, method).live_expected_time_save(timer, comparison)
+}
@@ -0,0 +1,275 @@
+//! Provides functionality to calculate statistics about the play sessions of a
+//! [`Run`]. If the runner explicitly declared sessions through the
+//! [`Timer`], these are used. Otherwise the attempts are grouped into sessions
+//! heuristically, where an attempt belongs to the same session as the attempt
+//! before it, if it was started no later than a certain amount of time after
+//! the previous attempt ended.
+
+use super::total_playtime;
+use crate::{
+    analysis::check_best_segment, platform::prelude::*, AtomicDateTime, Attempt, Run, TimeSpan,
+    Timer, TimerPhase, TimingMethod,
+};
+use hashbrown::HashMap;
+
+/// The statistics of a single play session.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionStatistics {
+    /// The point in time the session started at.
+    pub started: AtomicDateTime,
+    /// The point in time the session ended at. This is `None` if the session
+    /// is still active.
+    pub ended: Option<AtomicDateTime>,
+    /// The number of attempts that were started during the session.
+    pub attempts: u32,
+    /// The number of attempts of the session that were reset before reaching
+    /// the end of the run.
+    pub resets: u32,
+    /// The number of new best segments that were achieved during the session.
+    pub best_segments: u32,
+    /// The index of the attempt with the fastest final time in the session.
+    /// This is `None` if no attempt of the session was completed.
+    pub best_attempt: Option<i32>,
+    /// The fastest final time of the session. This is `None` if no attempt of
+    /// the session was completed.
+    pub best_time: Option<TimeSpan>,
+    /// The playtime of all the attempts of the session.
+    pub playtime: TimeSpan,
+}
+
+impl SessionStatistics {
+    const fn new(started: AtomicDateTime, ended: Option<AtomicDateTime>) -> Self {
+        Self {
+            started,
+            ended,
+            attempts: 0,
+            resets: 0,
+            best_segments: 0,
+            best_attempt: None,
+            best_time: None,
+            playtime: TimeSpan::zero(),
+        }
+    }
+
+    fn add_attempt(&mut self, attempt: &Attempt, best_segments: u32, method: TimingMethod) {
+        self.attempts += 1;
+        self.best_segments += best_segments;
+        self.add_final_time(attempt.index(), attempt.time()[method]);
+        if attempt.time().real_time.is_none() {
+            self.resets += 1;
+        }
+    }
+
+    fn add_final_time(&mut self, index: i32, final_time: Option<TimeSpan>) {
+        if let Some(final_time) = final_time {
+            if self.best_time.map_or(true, |best| final_time < best) {
+                self.best_time = Some(final_time);
+                self.best_attempt = Some(index);
+            }
+        }
+    }
+}
+
+/// Counts the new best segments of each attempt. A segment time is considered
+/// a new best segment if it is faster than all the segment times of the
+/// attempts before it, or if it is the first segment time of the segment.
+/// Combined segment times are not considered.
+fn count_best_segments(run: &Run, method: TimingMethod) -> HashMap<i32, u32> {
+    let mut best_segments = HashMap::new();
+    let segments = run.segments();
+
+    for (segment_index, segment) in segments.iter().enumerate() {
+        let previous_segment = segment_index.checked_sub(1).map(|i| &segments[i]);
+        let mut best = None;
+
+        for &(id, time) in segment.segment_history() {
+            let Some(time) = time[method] else {
+                continue;
+            };
+            // Skip all the combined segments
+            let skip = catch! {
+                previous_segment?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+
+            if skip {
+                continue;
+            }
+
+            if best.map_or(true, |best| time < best) {
+                if id > 0 {
+                    *best_segments.entry(id).or_insert(0) += 1;
+                }
+                best = Some(time);
+            }
+        }
+    }
+
+    best_segments
+}
+
+/// Calculates the statistics of all the sessions of the run provided. If the
+/// run has explicitly declared sessions, these are used and attempts that were
+/// started outside of them are not considered. Otherwise the attempts are
+/// grouped into sessions, where each attempt is started no later than
+/// `max_gap` after the previous attempt ended. In both cases, attempts without
+/// a known starting time are not considered. The sessions are ordered from the
+/// oldest to the most recent.
+pub fn calculate(run: &Run, method: TimingMethod, max_gap: TimeSpan) -> Vec<SessionStatistics> {
+    let best_segments = count_best_segments(run, method);
+    let best_segments_of = |attempt: &Attempt| {
+        best_segments
+            .get(&attempt.index())
+            .copied()
+            .unwrap_or_default()
+    };
+
+    if !run.sessions().is_empty() {
+        return run
+            .sessions()
+            .iter()
+            .map(|session| {
+                let mut statistics = SessionStatistics::new(session.started(), session.ended());
+                for attempt in run.attempt_history() {
+                    if attempt.started().is_some_and(|s| session.contains(s)) {
+                        statistics.add_attempt(attempt, best_segments_of(attempt), method);
+                        statistics.playtime += total_playtime::attempt_playtime(run, attempt);
+                    }
+                }
+                statistics
+            })
+            .collect();
+    }
+
+    total_playtime::by_session(run, max_gap)
+        .into_iter()
+        .map(|session| {
+            let mut statistics = SessionStatistics::new(session.started, Some(session.ended));
+            statistics.playtime = session.playtime;
+            for attempt in run.attempt_history() {
+                let index = attempt.index();
+                if attempt.started().is_some()
+                    && index >= session.first_attempt
+                    && index <= session.last_attempt
+                {
+                    statistics.add_attempt(attempt, best_segments_of(attempt), method);
+                }
+            }
+            statistics
+        })
+        .collect()
+}
+
+/// Calculates the statistics of the current session of the timer provided,
+/// including the attempt that is currently in progress. If the run has
+/// explicitly declared sessions, the session that is still active is the
+/// current session. Otherwise the most recent session of the attempt history
+/// is continued, if it ended no longer than `max_gap` before the current
+/// attempt was started. Returns `None` if there is no current session. If the
+/// current session needs to be determined repeatedly, use a
+/// [`CurrentSession`] instead, which caches the analysis of the attempt
+/// history.
+pub fn current(timer: &Timer, max_gap: TimeSpan) -> Option<SessionStatistics> {
+    CurrentSession::new().calculate(timer, max_gap)
+}
+
+/// Determines the current session of a timer. It caches the statistics of the
+/// most recent session of the attempt history, so that they only need to be
+/// calculated again when the attempt history changes. This makes it fast
+/// enough to be used for every frame.
+#[derive(Clone, Debug, Default)]
+pub struct CurrentSession {
+    key: Option<CacheKey>,
+    last_session: Option<SessionStatistics>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct CacheKey {
+    attempt_count: u32,
+    attempts: usize,
+    phase: TimerPhase,
+    sessions: usize,
+    is_session_active: bool,
+    method: TimingMethod,
+    max_gap: TimeSpan,
+}
+
+impl CurrentSession {
+    /// Creates a new empty cache for determining the current session.
+    pub const fn new() -> Self {
+        Self {
+            key: None,
+            last_session: None,
+        }
+    }
+
+    /// Calculates the statistics of the current session of the timer
+    /// provided, including the attempt that is currently in progress. See
+    /// [`current`] for how the current session is determined.
+    pub fn calculate(&mut self, timer: &Timer, max_gap: TimeSpan) -> Option<SessionStatistics> {
+        let run = timer.run();
+        let method = timer.current_timing_method();
+        let phase = timer.current_phase();
+
+        let key = CacheKey {
+            attempt_count: run.attempt_count(),
+            attempts: run.attempt_history().len(),
+            phase,
+            sessions: run.sessions().len(),
+            is_session_active: run.sessions().last().is_some_and(|s| s.is_active()),
+            method,
+            max_gap,
+        };
+
+        if self.key != Some(key) {
+            self.last_session = calculate(run, method, max_gap).pop();
+            self.key = Some(key);
+        }
+
+        let mut statistics = if !run.sessions().is_empty() {
+            if !key.is_session_active {
+                return None;
+            }
+            self.last_session.clone()?
+        } else {
+            let now = AtomicDateTime::now();
+            let current_started = now.time - timer.current_attempt_duration().to_duration();
+            let continues_at = AtomicDateTime::new(current_started, false);
+            match &self.last_session {
+                Some(session)
+                    if session
+                        .ended
+                        .is_some_and(|ended| continues_at - ended <= max_gap) =>
+                {
+                    SessionStatistics {
+                        ended: None,
+                        ..session.clone()
+                    }
+                }
+                _ if phase != TimerPhase::NotRunning => SessionStatistics::new(continues_at, None),
+                _ => return None,
+            }
+        };
+
+        if phase != TimerPhase::NotRunning {
+            statistics.attempts += 1;
+            statistics.playtime +=
+                timer.current_attempt_duration() - timer.get_pause_time().unwrap_or_default();
+            statistics.best_segments += (0..run.len())
+                .filter(|&i| check_best_segment(timer, i, method))
+                .count() as u32;
+            if phase == TimerPhase::Ended {
+                statistics.add_final_time(
+                    run.attempt_history()
+                        .iter()
+                        .map(Attempt::index)
+                        .max()
+                        .map_or(1, |index| index.max(0) + 1),
+                    run.segments().last().and_then(|s| s.split_time()[method]),
+                );
+            }
+        }
+
+        Some(statistics)
+    }
+}
@@ -1,8 +1,8 @@
 //! Provides different helper functions.
 
 use crate::{
-    comparison::best_segments, settings::SemanticColor, timing::Snapshot, Run, Segment, TimeSpan,
-    Timer, TimerPhase, TimingMethod,
+    comparison::best_segments, run::Section, settings::SemanticColor, timing::Snapshot, Run,
+    Segment, TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 
 /// Gets the last non-live delta in the [`Run`](crate::Run) starting
@@ -92,6 +92,40 @@ pub fn comparison_combined_segment_time(
     Some(current_comparison_time - previous_comparison_time)
 }
 
+/// Calculates the comparison's combined segment time of all the segments of
+/// the section with the timing method specified, combining it with the
+/// segments before the section if they are empty. This is not calculating the
+/// current attempt's segment times.
+///
+/// # Panics
+///
+/// Panics if the end of the provided `section` is greater than or equal to
+/// `run.len()`.
+pub fn comparison_section_time(
+    run: &Run,
+    section: Section,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    if comparison == best_segments::NAME {
+        return run.segments()[section.start()..=section.end()]
+            .iter()
+            .map(|segment| segment.best_segment_time()[method])
+            .sum();
+    }
+
+    let current_comparison_time = run.segment(section.end()).comparison(comparison)[method]?;
+
+    let previous_comparison_time = find_previous_non_empty_comparison_time(
+        &run.segments()[..section.start()],
+        comparison,
+        method,
+    )
+    .unwrap_or_default();
+
+    Some(current_comparison_time - previous_comparison_time)
+}
+
 /// Calculates the comparison's segment time of the segment with the timing
 /// method specified. This is not calculating the current attempt's segment
 /// times.
@@ -124,16 +158,16 @@ pub fn comparison_single_segment_time(
 
 fn segment_delta(
     run: &Run,
-    segment_index: usize,
+    section: Section,
     current_time: TimeSpan,
     comparison: &str,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    let segment_index_comparison = run.segment(segment_index).comparison(comparison)[method]?;
+    let segment_index_comparison = run.segment(section.end()).comparison(comparison)[method]?;
 
     Some(
         find_previous_non_empty_split_and_comparison_time(
-            &run.segments()[..segment_index],
+            &run.segments()[..section.start()],
             comparison,
             method,
         )
@@ -146,11 +180,11 @@ fn segment_delta(
 
 fn segment_time(
     run: &Run,
-    segment_index: usize,
+    section: Section,
     current_time: TimeSpan,
     method: TimingMethod,
 ) -> TimeSpan {
-    find_previous_non_empty_split_time(&run.segments()[..segment_index], method)
+    find_previous_non_empty_split_time(&run.segments()[..section.start()], method)
         .map(|split_time| current_time - split_time)
         .unwrap_or(current_time)
 }
@@ -168,11 +202,27 @@ pub fn previous_segment_time(
     timer: &Timer,
     segment_index: usize,
     method: TimingMethod,
+) -> Option<TimeSpan> {
+    previous_section_time(timer, Section::new(segment_index, segment_index), method)
+}
+
+/// Gets the combined length of all the segments of a section.
+///
+/// - `timer`: The current [`Timer`](crate::timing::Timer).
+/// - `section`: The section to combine the segments of.
+/// - `method`: The [`TimingMethod`](crate::timing::TimingMethod) that you are using.
+///
+/// Returns the combined length of the segments of the section, returning None
+/// if the split ending the section is not completed yet.
+pub fn previous_section_time(
+    timer: &Timer,
+    section: Section,
+    method: TimingMethod,
 ) -> Option<TimeSpan> {
     segment_time(
         timer.run(),
-        segment_index,
-        timer.run().segment(segment_index).split_time()[method]?,
+        section,
+        timer.run().segment(section.end()).split_time()[method]?,
         method,
     )
     .into()
@@ -193,13 +243,24 @@ pub fn live_segment_time(
     segment_index: usize,
     method: TimingMethod,
 ) -> Option<TimeSpan> {
-    segment_time(
-        timer.run(),
-        segment_index,
-        timer.current_time()[method]?,
-        method,
-    )
-    .into()
+    live_section_time(timer, Section::new(segment_index, segment_index), method)
+}
+
+/// Gets the combined length of all the segments of a section, using the live
+/// segment time if the split ending the section is not completed yet.
+///
+/// - `timer`: The current [`Timer`](crate::timing::Timer).
+/// - `section`: The section to combine the segments of.
+/// - `method`: The [`TimingMethod`](crate::timing::TimingMethod) that you are using.
+///
+/// Returns the combined length of the segments of the section, up to the
+/// current time.
+pub fn live_section_time(
+    timer: &Snapshot<'_>,
+    section: Section,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    segment_time(timer.run(), section, timer.current_time()[method]?, method).into()
 }
 
 /// Gets the amount of time lost or gained on a certain split.
@@ -216,11 +277,35 @@ pub fn previous_segment_delta(
     segment_index: usize,
     comparison: &str,
     method: TimingMethod,
+) -> Option<TimeSpan> {
+    previous_section_delta(
+        timer,
+        Section::new(segment_index, segment_index),
+        comparison,
+        method,
+    )
+}
+
+/// Gets the combined amount of time lost or gained on all the segments of a
+/// section.
+///
+/// - `timer`: The current [`Timer`](crate::timing::Timer).
+/// - `section`: The section for which the delta is calculated.
+/// - `comparison`: The comparison that you are comparing with.
+/// - `method`: The [`TimingMethod`](crate::timing::TimingMethod) that you are using.
+///
+/// Returns the delta of the section, returning None if the split ending the
+/// section is not completed yet.
+pub fn previous_section_delta(
+    timer: &Timer,
+    section: Section,
+    comparison: &str,
+    method: TimingMethod,
 ) -> Option<TimeSpan> {
     segment_delta(
         timer.run(),
-        segment_index,
-        timer.run().segment(segment_index).split_time()[method]?,
+        section,
+        timer.run().segment(section.end()).split_time()[method]?,
         comparison,
         method,
     )
@@ -241,10 +326,34 @@ pub fn live_segment_delta(
     segment_index: usize,
     comparison: &str,
     method: TimingMethod,
+) -> Option<TimeSpan> {
+    live_section_delta(
+        timer,
+        Section::new(segment_index, segment_index),
+        comparison,
+        method,
+    )
+}
+
+/// Gets the combined amount of time lost or gained on all the segments of a
+/// section, using the live segment delta if the split ending the section is
+/// not completed yet.
+///
+/// - `timer`: The current [`Timer`](crate::timing::Timer).
+/// - `section`: The section for which the delta is calculated.
+/// - `comparison`: The comparison that you are comparing with.
+/// - `method`: The [`TimingMethod`](crate::timing::TimingMethod) that you are using.
+///
+/// Returns the delta of the section, up to the current time.
+pub fn live_section_delta(
+    timer: &Snapshot<'_>,
+    section: Section,
+    comparison: &str,
+    method: TimingMethod,
 ) -> Option<TimeSpan> {
     segment_delta(
         timer.run(),
-        segment_index,
+        section,
         timer.current_time()[method]?,
         comparison,
         method,
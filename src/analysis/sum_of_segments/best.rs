@@ -8,7 +8,40 @@
 //! misleading, but sticks around for historical reasons.
 
 use super::{track_branch, track_current_run, track_personal_best_run, Prediction};
-use crate::{Segment, TimeSpan, TimingMethod};
+use crate::{platform::prelude::*, Segment, TimeSpan, TimingMethod};
+
+/// A single step of the route that forms the Sum of Best Segments. A step
+/// either covers a single segment, in which case its time is the best segment
+/// time, or multiple segments, in which case its time is a combined segment
+/// time of an attempt that skipped the splits in between.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RouteSegment {
+    /// The index of the first segment covered by this step.
+    pub start: usize,
+    /// The index of the last segment covered by this step. This is the same as
+    /// the start if the step covers a single segment.
+    pub end: usize,
+    /// The time it takes to complete all the segments covered by this step.
+    pub time: TimeSpan,
+    /// Where the time of this step comes from.
+    pub source: RouteSource,
+}
+
+/// Describes where the time of a [`RouteSegment`] comes from.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RouteSource {
+    /// The time was achieved in the attempt with the index provided. If
+    /// multiple attempts achieved the same time, this is the earliest one.
+    Attempt(i32),
+    /// The time was achieved in the attempt that is currently in progress.
+    CurrentRun,
+    /// The time comes from the Personal Best, but there is no attempt in the
+    /// history that it can be attributed to.
+    PersonalBest,
+    /// The time can't be attributed to any attempt. This is usually the case
+    /// if the best segment time was imported or manually edited.
+    Unknown,
+}
 
 fn populate_prediction(
     predecessor: usize,
@@ -125,3 +158,89 @@ pub fn calculate(
     }
     Some(predictions[end_index]?.time)
 }
+
+/// Follows the predecessors of the predictions calculated by [`calculate`]
+/// backwards and determines the route that forms the Sum of Best Segments.
+/// Each step of the route is attributed to the attempt it was achieved in. The
+/// steps are ordered from the first to the last segment. If the Sum of Best
+/// Segments can't be calculated, the route is empty. The flag about taking the
+/// active attempt into account needs to match the one used for calculating
+/// the predictions.
+pub fn route(
+    segments: &[Segment],
+    predictions: &[Option<Prediction>],
+    use_current_run: bool,
+    method: TimingMethod,
+) -> Vec<RouteSegment> {
+    let mut route = Vec::new();
+    let mut index = segments.len();
+
+    while index > 0 {
+        let Some(prediction) = predictions[index] else {
+            return Vec::new();
+        };
+        let start = prediction.predecessor;
+        let Some(predecessor) = predictions[start] else {
+            return Vec::new();
+        };
+        let time = prediction.time - predecessor.time;
+        route.push(RouteSegment {
+            start,
+            end: index - 1,
+            time,
+            source: find_source(segments, start, index, time, use_current_run, method),
+        });
+        index = start;
+    }
+
+    route.reverse();
+    route
+}
+
+/// Determines where the time it takes to get from the start of the segment
+/// `start` to the end of the segment before `end` comes from.
+fn find_source(
+    segments: &[Segment],
+    start: usize,
+    end: usize,
+    time: TimeSpan,
+    use_current_run: bool,
+    method: TimingMethod,
+) -> RouteSource {
+    let attempt =
+        segments[end - 1]
+            .segment_history()
+            .iter_actual_runs()
+            .find(|&&(id, segment_time)| {
+                segment_time[method] == Some(time)
+                && segments[start..end - 1].iter().all(|segment| {
+                    segment
+                        .segment_history()
+                        .get(id)
+                        .is_some_and(|t| t[method].is_none())
+                })
+                // The attempt must not have skipped the split before either
+                && catch! {
+                    segments[start.checked_sub(1)?].segment_history().get(id)?[method].is_some()
+                }
+                .unwrap_or(true)
+            });
+    if let Some(&(id, _)) = attempt {
+        return RouteSource::Attempt(id);
+    }
+
+    if use_current_run {
+        let (index, current_time) =
+            track_current_run(segments, Some(TimeSpan::zero()), start, method);
+        if index == end && current_time[method] == Some(time) {
+            return RouteSource::CurrentRun;
+        }
+    }
+
+    let (index, pb_time) = track_personal_best_run(segments, Some(TimeSpan::zero()), start, method);
+    if index == end && pb_time[method] == Some(time) {
+        return RouteSource::PersonalBest;
+    }
+
+    RouteSource::Unknown
+}
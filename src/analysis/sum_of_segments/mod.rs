@@ -55,6 +55,29 @@ pub fn calculate_best(
     )
 }
 
+/// Calculates the route that forms the Sum of Best Segments for the timing
+/// method provided. The route consists of the best segment times and the
+/// combined segment times of attempts that skipped splits, which together
+/// make up the Sum of Best Segments. Each of them is attributed to the attempt
+/// it was achieved in. The route is empty if the Sum of Best Segments can't be
+/// calculated. The options are the same as for [`calculate_best`].
+pub fn calculate_best_route(
+    segments: &[Segment],
+    simple_calculation: bool,
+    use_current_run: bool,
+    method: TimingMethod,
+) -> Vec<best::RouteSegment> {
+    let mut predictions = vec![None; segments.len() + 1];
+    best::calculate(
+        segments,
+        &mut predictions,
+        simple_calculation,
+        use_current_run,
+        method,
+    );
+    best::route(segments, &predictions, use_current_run, method)
+}
+
 /// Calculates the Sum of Worst Segments for the timing method provided. This is
 /// the slowest time possible to complete a run of a category, based on
 /// information collected from all the previous attempts. This obviously isn't
@@ -1,7 +1,12 @@
-use super::{best, Prediction};
+use super::{
+    best::{self, RouteSegment, RouteSource},
+    calculate_best_route, Prediction,
+};
 use crate::{
     comparison::best_segments,
-    util::tests_helper::{create_timer, run_with_splits_opt, span},
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits_opt, span, start_run,
+    },
     Timer, TimingMethod,
 };
 
@@ -126,3 +131,70 @@ pub fn sum_of_best() {
         [(5.0, 0, true), (8.0, 1, true), (13.0, 2, true)],
     );
 }
+
+#[test]
+pub fn best_route_with_combined_segment() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), Some(20.0), Some(60.0)]);
+    run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(20.0)]);
+    run_with_splits_opt(&mut timer, &[Some(4.0), Some(25.0), Some(80.0)]);
+
+    let route = calculate_best_route(timer.run().segments(), false, false, TimingMethod::GameTime);
+    assert_eq!(
+        route,
+        [
+            RouteSegment {
+                start: 0,
+                end: 0,
+                time: span(4.0),
+                source: RouteSource::Attempt(3),
+            },
+            RouteSegment {
+                start: 1,
+                end: 2,
+                time: span(10.0),
+                source: RouteSource::Attempt(2),
+            },
+        ]
+    );
+}
+
+#[test]
+pub fn best_route_of_simple_calculation() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), Some(20.0), Some(60.0)]);
+    // The combined segment time is not considered by the simple calculation,
+    // but it is part of the Personal Best, which is always considered.
+    run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(20.0)]);
+    run_with_splits_opt(&mut timer, &[Some(4.0), Some(25.0), Some(50.0)]);
+
+    let route = calculate_best_route(timer.run().segments(), true, false, TimingMethod::GameTime);
+    let sources = route
+        .iter()
+        .map(|s| (s.start, s.end, s.source))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sources,
+        [
+            (0, 0, RouteSource::Attempt(3)),
+            (1, 2, RouteSource::Attempt(2)),
+        ]
+    );
+}
+
+#[test]
+pub fn best_route_with_current_run() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    run_with_splits_opt(&mut timer, &[Some(5.0), Some(20.0)]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(3.0)]);
+
+    let route = calculate_best_route(timer.run().segments(), false, true, TimingMethod::GameTime);
+    let sources = route.iter().map(|s| s.source).collect::<Vec<_>>();
+    assert_eq!(sources, [RouteSource::CurrentRun, RouteSource::Attempt(1)]);
+
+    assert!(calculate_best_route(&[], false, false, TimingMethod::GameTime).is_empty());
+}
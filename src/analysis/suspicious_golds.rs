@@ -0,0 +1,208 @@
+//! Provides functionality to identify best segments that are likely to be the
+//! result of a mistake, such as splitting too early. A single best segment
+//! like that makes the Sum of Best unreachable forever. Each best segment is
+//! scored based on how far it lies outside of the distribution of the
+//! segment's other segment times and on whether the neighbouring segments of
+//! the same attempt were unusually slow, which is what happens if a split was
+//! done too early or too late.
+//!
+//! The suspicious best segments can be removed through the
+//! [`SumOfBestCleaner`](crate::run::editor::cleaning::SumOfBestCleaner).
+
+use crate::{platform::prelude::*, Segment, TimeSpan, TimingMethod};
+
+/// The score a best segment needs to reach by default to be considered
+/// suspicious.
+pub const DEFAULT_THRESHOLD: f64 = 5.0;
+
+/// The minimum number of other segment times a segment needs to have for its
+/// best segment to be judged. Fewer segment times don't allow for any
+/// meaningful conclusions about the distribution of the segment times.
+const MIN_SAMPLES: usize = 5;
+
+/// Scales the median absolute deviation such that it estimates the standard
+/// deviation of normally distributed segment times.
+const MAD_SCALE: f64 = 1.4826;
+
+/// A best segment that is likely to be the result of a mistake.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuspiciousGold {
+    /// The index of the segment the best segment belongs to.
+    pub segment_index: usize,
+    /// The index of the attempt the best segment was achieved in.
+    pub attempt_index: i32,
+    /// The timing method of the best segment.
+    pub method: TimingMethod,
+    /// The best segment time.
+    pub time: TimeSpan,
+    /// The median of the segment's other segment times.
+    pub typical_time: TimeSpan,
+    /// How many (estimated) standard deviations the best segment is faster
+    /// than the median of the segment's other segment times.
+    pub deviation: f64,
+    /// How many (estimated) standard deviations the slowest neighbouring
+    /// segment of the same attempt is slower than its median. This is 0 if
+    /// the neighbouring segments were not slower than usual.
+    pub neighbour_deviation: f64,
+    /// The overall score of how suspicious the best segment is. This is the
+    /// sum of the deviation and the neighbour deviation.
+    pub score: f64,
+}
+
+struct Distribution {
+    median: f64,
+    scale: f64,
+}
+
+impl Distribution {
+    fn new(times: &mut [f64]) -> Option<Self> {
+        let center = median(times)?;
+        for time in &mut *times {
+            *time = (*time - center).abs();
+        }
+        let deviation = median(times)?;
+        // If most of the segment times are identical, the deviation would be
+        // zero, so a tiny fraction of the median is used as the minimum.
+        let scale = (MAD_SCALE * deviation).max(0.01 * center.abs()).max(1e-3);
+        Some(Self {
+            median: center,
+            scale,
+        })
+    }
+
+    fn deviation(&self, time: f64) -> f64 {
+        (time - self.median) / self.scale
+    }
+}
+
+fn median(times: &mut [f64]) -> Option<f64> {
+    if times.is_empty() {
+        return None;
+    }
+    times.sort_unstable_by(|a, b| a.total_cmp(b));
+    let mid = times.len() / 2;
+    Some(if times.len() % 2 == 0 {
+        0.5 * (times[mid - 1] + times[mid])
+    } else {
+        times[mid]
+    })
+}
+
+/// Collects all the segment times of the segment, skipping the combined
+/// segment times.
+fn segment_times(
+    segments: &[Segment],
+    segment_index: usize,
+    method: TimingMethod,
+) -> impl Iterator<Item = (i32, TimeSpan)> + '_ {
+    let previous_segment = segment_index.checked_sub(1).map(|i| &segments[i]);
+    segments[segment_index]
+        .segment_history()
+        .iter()
+        .filter_map(move |&(id, time)| {
+            let time = time[method]?;
+            // Skip all the combined segments
+            let skip = catch! {
+                previous_segment?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+            if skip {
+                None
+            } else {
+                Some((id, time))
+            }
+        })
+}
+
+fn non_combined_time(
+    segments: &[Segment],
+    segment_index: usize,
+    attempt_index: i32,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    segment_times(segments, segment_index, method)
+        .find(|&(id, _)| id == attempt_index)
+        .map(|(_, time)| time)
+}
+
+/// Calculates the best segments of the segments provided that are likely to be
+/// the result of a mistake. Only best segments with a score of at least the
+/// threshold provided are considered suspicious. The best segments are ranked
+/// from the most suspicious to the least suspicious. Best segments that don't
+/// have a matching segment time in the segment history, such as ones that
+/// were imported, are not considered, as there is nothing that could be
+/// removed. Segments with too few segment times are not judged at all.
+pub fn calculate(
+    segments: &[Segment],
+    method: TimingMethod,
+    threshold: f64,
+) -> Vec<SuspiciousGold> {
+    let mut distributions = Vec::with_capacity(segments.len());
+    let mut buf = Vec::new();
+
+    for segment_index in 0..segments.len() {
+        buf.clear();
+        buf.extend(segment_times(segments, segment_index, method).map(|(_, t)| t.total_seconds()));
+        distributions.push(Distribution::new(&mut buf));
+    }
+
+    let mut suspicious_golds = Vec::new();
+
+    for (segment_index, segment) in segments.iter().enumerate() {
+        let Some(best_segment) = segment.best_segment_time()[method] else {
+            continue;
+        };
+
+        let Some((attempt_index, _)) = segment_times(segments, segment_index, method)
+            .find(|&(id, time)| id > 0 && time == best_segment)
+        else {
+            continue;
+        };
+
+        buf.clear();
+        buf.extend(
+            segment_times(segments, segment_index, method)
+                .filter(|&(id, _)| id != attempt_index)
+                .map(|(_, t)| t.total_seconds()),
+        );
+        if buf.len() < MIN_SAMPLES {
+            continue;
+        }
+        let Some(distribution) = Distribution::new(&mut buf) else {
+            continue;
+        };
+
+        let deviation = -distribution.deviation(best_segment.total_seconds());
+        if deviation <= 0.0 {
+            continue;
+        }
+
+        let neighbour_deviation = [segment_index.checked_sub(1), Some(segment_index + 1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|neighbour| {
+                let distribution = distributions.get(neighbour)?.as_ref()?;
+                let time = non_combined_time(segments, neighbour, attempt_index, method)?;
+                Some(distribution.deviation(time.total_seconds()))
+            })
+            .fold(0.0, f64::max);
+
+        let score = deviation + neighbour_deviation;
+        if score >= threshold {
+            suspicious_golds.push(SuspiciousGold {
+                segment_index,
+                attempt_index,
+                method,
+                time: best_segment,
+                typical_time: TimeSpan::from_seconds(distribution.median),
+                deviation,
+                neighbour_deviation,
+                score,
+            });
+        }
+    }
+
+    suspicious_golds.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+    suspicious_golds
+}
@@ -0,0 +1,83 @@
+use super::super::distributions::{calculate, Options};
+use crate::util::tests_helper::{create_run, run_with_splits, run_with_splits_opt};
+use crate::{Timer, TimingMethod};
+
+fn options(bins: u32) -> Options {
+    Options {
+        bins,
+        percentiles: vec![0.0, 0.25, 1.0],
+    }
+}
+
+#[test]
+fn bins_percentiles_and_averages() {
+    let mut timer = Timer::new(create_run(&["First", "Second"])).unwrap();
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[12.0, 26.0]);
+    run_with_splits(&mut timer, &[14.0, 24.0]);
+    run_with_splits(&mut timer, &[20.0]);
+
+    let distributions = calculate(timer.run(), TimingMethod::GameTime, &options(2));
+    assert_eq!(distributions.segments.len(), 2);
+
+    let first = &distributions.segments[0].segment_times;
+    assert_eq!(first.samples, 4);
+    assert_eq!((first.min, first.max), (Some(10.0), Some(20.0)));
+    assert_eq!(first.mean, Some(14.0));
+    assert_eq!(first.median, Some(13.0));
+    assert_eq!(first.percentiles[0].time, 10.0);
+    assert_eq!(first.percentiles[1].time, 11.5);
+    assert_eq!(first.percentiles[2].time, 20.0);
+    assert_eq!(first.bins.len(), 2);
+    assert_eq!((first.bins[0].start, first.bins[0].end), (10.0, 15.0));
+    assert_eq!(first.bins[0].count, 3);
+    assert_eq!((first.bins[1].start, first.bins[1].end), (15.0, 20.0));
+    assert_eq!(first.bins[1].count, 1);
+
+    let second = &distributions.segments[1];
+    assert_eq!(second.segment_times.samples, 3);
+    assert_eq!(second.segment_times.min, Some(10.0));
+    assert_eq!(second.split_times.min, Some(20.0));
+    assert_eq!(second.split_times.max, Some(26.0));
+}
+
+#[test]
+fn skipped_segments() {
+    let mut timer = Timer::new(create_run(&["First", "Second", "Third"])).unwrap();
+    run_with_splits_opt(&mut timer, &[Some(10.0), Some(20.0), Some(30.0)]);
+    run_with_splits_opt(&mut timer, &[Some(9.0), None, Some(25.0)]);
+
+    let distributions = calculate(timer.run(), TimingMethod::GameTime, &options(4));
+
+    // The skipped segment has no times at all.
+    let second = &distributions.segments[1];
+    assert_eq!(second.segment_times.samples, 1);
+    assert_eq!(second.split_times.samples, 1);
+
+    // The combined segment time is not considered, but the split time is.
+    let third = &distributions.segments[2];
+    assert_eq!(third.segment_times.samples, 1);
+    assert_eq!(third.segment_times.median, Some(10.0));
+    assert_eq!(third.split_times.samples, 2);
+    assert_eq!(third.split_times.min, Some(25.0));
+}
+
+#[test]
+fn identical_times_end_up_in_the_first_bin() {
+    let mut timer = Timer::new(create_run(&["First"])).unwrap();
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[10.0]);
+
+    let distributions = calculate(timer.run(), TimingMethod::GameTime, &options(3));
+    let first = &distributions.segments[0].segment_times;
+    assert_eq!(first.bins.len(), 3);
+    assert_eq!(first.bins[0].count, 2);
+}
+
+#[test]
+fn empty_run() {
+    let timer = Timer::new(create_run(&["First"])).unwrap();
+    let distributions = calculate(timer.run(), TimingMethod::GameTime, &Options::default());
+    assert_eq!(distributions.segments[0].segment_times.samples, 0);
+    assert!(distributions.segments[0].split_times.bins.is_empty());
+}
@@ -1 +1,10 @@
+mod distributions;
 mod empty_run;
+mod prediction;
+mod reset_points;
+mod segment_outlook;
+mod sessions;
+mod suspicious_golds;
+mod time_lost;
+mod total_playtime;
+mod trends;
@@ -0,0 +1,82 @@
+use super::super::prediction::Predictor;
+use crate::util::tests_helper::{run_with_splits, start_run};
+use crate::{Run, Segment, TimeSpan, Timer, TimingMethod};
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    let mut timer = Timer::new(run).unwrap();
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[12.0, 30.0]);
+    timer
+}
+
+#[test]
+fn predicts_all_combinations() {
+    let timer = timer();
+    let mut predictor = Predictor::default();
+    let prediction = predictor.predict(&timer.snapshot()).unwrap();
+
+    let s = TimeSpan::from_seconds;
+    assert_eq!(prediction.percentile(0.0), s(20.0));
+    assert_eq!(prediction.percentile(1.0), s(30.0));
+    assert!(prediction
+        .final_times()
+        .iter()
+        .all(|&t| t == s(20.0) || t == s(22.0) || t == s(28.0) || t == s(30.0)));
+
+    let (fastest, slowest) = prediction.confidence_interval(0.8);
+    assert!(fastest <= slowest);
+    assert_eq!(prediction.probability_of_beating(s(20.0)), 0.0);
+    assert_eq!(prediction.probability_of_beating(s(31.0)), 1.0);
+    let chance = prediction.probability_of_beating(s(25.0));
+    assert!(chance > 0.3 && chance < 0.7);
+}
+
+#[test]
+fn elapsed_time_is_considered() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(15.0));
+
+    let mut predictor = Predictor::default();
+    let prediction = predictor.predict(&timer.snapshot()).unwrap();
+    assert!(prediction.percentile(0.0) >= TimeSpan::from_seconds(23.0));
+}
+
+#[test]
+fn missing_history_has_no_prediction() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    let timer = Timer::new(run).unwrap();
+    assert!(Predictor::default().predict(&timer.snapshot()).is_none());
+}
+
+#[test]
+fn modified_history_invalidates_the_simulation() {
+    let timer = timer();
+    let mut predictor = Predictor::default();
+    let s = TimeSpan::from_seconds;
+    assert_eq!(
+        predictor
+            .predict(&timer.snapshot())
+            .unwrap()
+            .percentile(1.0),
+        s(30.0)
+    );
+
+    let mut run = timer.into_run(true);
+    for (_, time) in run.segment_mut(1).segment_history_mut().iter_mut() {
+        time.game_time = Some(s(15.0));
+    }
+    let mut timer = Timer::new(run).unwrap();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+    assert_eq!(
+        predictor
+            .predict(&timer.snapshot())
+            .unwrap()
+            .percentile(1.0),
+        s(27.0)
+    );
+}
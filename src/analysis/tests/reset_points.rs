@@ -0,0 +1,29 @@
+use super::super::reset_points::{calculate, survival_probability};
+use crate::util::tests_helper::{run_with_splits, start_run};
+use crate::{Run, Segment, Timer};
+
+#[test]
+fn reset_points() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    let mut timer = Timer::new(run).unwrap();
+
+    run_with_splits(&mut timer, &[1.0, 2.0]);
+    run_with_splits(&mut timer, &[1.0]);
+    run_with_splits(&mut timer, &[1.0]);
+    start_run(&mut timer);
+    timer.reset(true);
+
+    let reset_points = calculate(timer.run());
+    assert_eq!(reset_points.len(), 2);
+
+    assert_eq!(reset_points[0].reached, 4);
+    assert_eq!(reset_points[0].resets, 1);
+    assert_eq!(reset_points[1].reached, 3);
+    assert_eq!(reset_points[1].resets, 2);
+
+    assert!((reset_points[1].survival_probability - 1.0 / 3.0).abs() < 1e-9);
+    assert!((reset_points[0].survival_probability - 0.25).abs() < 1e-9);
+    assert_eq!(survival_probability(timer.run(), 2), 1.0);
+}
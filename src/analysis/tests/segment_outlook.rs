@@ -0,0 +1,82 @@
+use super::super::segment_outlook;
+use crate::comparison::personal_best;
+use crate::util::tests_helper::{run_with_splits, start_run};
+use crate::{Run, Segment, TimeSpan, Timer, TimingMethod};
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    let mut timer = Timer::new(run).unwrap();
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[12.0, 26.0]);
+    run_with_splits(&mut timer, &[8.0, 24.0]);
+    timer
+}
+
+#[test]
+fn gold_probability_is_a_probability() {
+    let timer = timer();
+    let run = timer.run();
+    for segment_index in 0..2 {
+        let probability =
+            segment_outlook::gold_probability(run, segment_index, TimingMethod::GameTime).unwrap();
+        assert!(probability > 0.0 && probability < 1.0);
+    }
+}
+
+#[test]
+fn gold_probability_shrinks_while_the_segment_goes_on() {
+    let mut timer = timer();
+    start_run(&mut timer);
+
+    timer.set_game_time(TimeSpan::from_seconds(5.0));
+    let early =
+        segment_outlook::live_gold_probability(&timer.snapshot(), 0, TimingMethod::GameTime)
+            .unwrap();
+
+    timer.set_game_time(TimeSpan::from_seconds(7.5));
+    let late = segment_outlook::live_gold_probability(&timer.snapshot(), 0, TimingMethod::GameTime)
+        .unwrap();
+
+    timer.set_game_time(TimeSpan::from_seconds(8.5));
+    let too_late =
+        segment_outlook::live_gold_probability(&timer.snapshot(), 0, TimingMethod::GameTime)
+            .unwrap();
+
+    assert!(early > late);
+    assert_eq!(too_late, 0.0);
+}
+
+#[test]
+fn expected_time_save_is_never_negative() {
+    let timer = timer();
+    let run = timer.run();
+
+    // The Personal Best only took 10 seconds for each segment. Only the most
+    // recent attempt was faster on the first segment, by 2 seconds. Nothing
+    // can be saved on the second segment.
+    let s = TimeSpan::from_seconds;
+    let first =
+        segment_outlook::expected_time_save(run, 0, personal_best::NAME, TimingMethod::GameTime)
+            .unwrap();
+    assert!(first > s(0.0) && first < s(2.0));
+
+    let second =
+        segment_outlook::expected_time_save(run, 1, personal_best::NAME, TimingMethod::GameTime);
+    assert_eq!(second, Some(s(0.0)));
+}
+
+#[test]
+fn missing_history_has_no_outlook() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    assert!(segment_outlook::gold_probability(&run, 0, TimingMethod::GameTime).is_none());
+    assert!(segment_outlook::expected_time_save(
+        &run,
+        0,
+        personal_best::NAME,
+        TimingMethod::GameTime
+    )
+    .is_none());
+}
@@ -0,0 +1,114 @@
+use super::super::sessions;
+use crate::platform::DateTime;
+use crate::util::tests_helper::run_with_splits;
+use crate::{AtomicDateTime, Run, Segment, Time, TimeSpan, Timer, TimingMethod};
+
+const HOUR: i64 = 60 * 60;
+const START: i64 = 1_672_833_600;
+
+fn at(seconds: i64) -> Option<AtomicDateTime> {
+    Some(AtomicDateTime::new(
+        DateTime::from_unix_timestamp(seconds).unwrap(),
+        false,
+    ))
+}
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+
+    let s = |seconds| Some(TimeSpan::from_seconds(seconds));
+    // (started, first segment, second segment)
+    let attempts = [
+        (START, s(10.0), s(20.0)),
+        (START + 100, s(9.0), None),
+        (START + 200, s(11.0), s(18.0)),
+        (START + 3 * HOUR, s(8.0), s(19.0)),
+        (START + 3 * HOUR + 100, None, None),
+    ];
+
+    for (started, first, second) in attempts {
+        let final_time = catch! { first? + second? };
+        let duration = final_time.map_or(30, |t| t.total_seconds() as i64);
+        run.add_attempt(
+            Time::new().with_real_time(final_time),
+            at(started),
+            at(started + duration),
+            None,
+        );
+        let index = run.attempt_history().last().unwrap().index();
+        if let Some(first) = first {
+            run.segment_mut(0)
+                .segment_history_mut()
+                .insert(index, Time::new().with_real_time(Some(first)));
+        }
+        if let Some(second) = second {
+            run.segment_mut(1)
+                .segment_history_mut()
+                .insert(index, Time::new().with_real_time(Some(second)));
+        }
+    }
+
+    run
+}
+
+#[test]
+fn sessions_are_split_by_gaps() {
+    let sessions = sessions::calculate(
+        &run(),
+        TimingMethod::RealTime,
+        TimeSpan::from_seconds(HOUR as f64),
+    );
+    assert_eq!(sessions.len(), 2);
+
+    let first = &sessions[0];
+    assert_eq!(first.attempts, 3);
+    assert_eq!(first.resets, 1);
+    // The first attempt gets both segments, the second one improves the first
+    // segment and the third one improves the second segment.
+    assert_eq!(first.best_segments, 4);
+    assert_eq!(first.best_attempt, Some(3));
+    assert_eq!(first.best_time, Some(TimeSpan::from_seconds(29.0)));
+
+    let second = &sessions[1];
+    assert_eq!(second.attempts, 2);
+    assert_eq!(second.resets, 1);
+    assert_eq!(second.best_segments, 1);
+    assert_eq!(second.best_attempt, Some(4));
+}
+
+#[test]
+fn explicit_sessions_take_precedence() {
+    let mut run = run();
+    run.start_session(at(START + 150).unwrap());
+    run.end_session(at(START + 4 * HOUR).unwrap());
+
+    let sessions = sessions::calculate(
+        &run,
+        TimingMethod::RealTime,
+        TimeSpan::from_seconds(HOUR as f64),
+    );
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].attempts, 3);
+    assert_eq!(sessions[0].best_segments, 2);
+    assert_eq!(sessions[0].best_time, Some(TimeSpan::from_seconds(27.0)));
+}
+
+#[test]
+fn current_session_gets_updated_with_new_attempts() {
+    let mut run = run();
+    run.start_session(at(START + 150).unwrap());
+    let mut timer = Timer::new(run).unwrap();
+    let max_gap = TimeSpan::from_seconds(HOUR as f64);
+
+    let mut current_session = sessions::CurrentSession::new();
+    let session = current_session.calculate(&timer, max_gap).unwrap();
+    assert_eq!(session.attempts, 3);
+    assert_eq!(session.ended, None);
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    let session = current_session.calculate(&timer, max_gap).unwrap();
+    assert_eq!(session.attempts, 4);
+    assert_eq!(session, sessions::current(&timer, max_gap).unwrap());
+}
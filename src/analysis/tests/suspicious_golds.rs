@@ -0,0 +1,74 @@
+use super::super::suspicious_golds::{self, DEFAULT_THRESHOLD};
+use crate::run::editor::cleaning::SumOfBestCleaner;
+use crate::util::tests_helper::{create_timer, run_with_splits, span};
+use crate::{Run, TimingMethod};
+
+fn run() -> Run {
+    let mut timer = create_timer(&["First", "Second", "Third"]);
+    for offset in [0.0, 0.5, -0.3, 0.2, -0.4, 0.3, 0.1] {
+        run_with_splits(&mut timer, &[10.0 + offset, 20.0 + offset, 30.0 + offset]);
+    }
+    // The second split was done way too early, so the third segment is a lot
+    // slower than usual.
+    run_with_splits(&mut timer, &[10.0, 12.0, 30.0]);
+    timer.into_run(true)
+}
+
+#[test]
+fn early_split_is_ranked_first() {
+    let run = run();
+    let golds =
+        suspicious_golds::calculate(run.segments(), TimingMethod::GameTime, DEFAULT_THRESHOLD);
+
+    let first = golds.first().unwrap();
+    assert_eq!(first.segment_index, 1);
+    assert_eq!(first.attempt_index, 8);
+    assert_eq!(first.time, span(2.0));
+    assert_eq!(first.typical_time, span(10.0));
+    assert!(first.neighbour_deviation > 0.0);
+    assert!(golds.iter().all(|gold| gold.segment_index != 0));
+}
+
+#[test]
+fn consistent_golds_are_not_suspicious() {
+    let mut timer = create_timer(&["First", "Second"]);
+    for offset in [0.0, 0.5, -0.3, 0.2, -0.4, 0.3, 0.1] {
+        run_with_splits(&mut timer, &[10.0 + offset, 20.0 + 2.0 * offset]);
+    }
+    let run = timer.into_run(true);
+    let golds =
+        suspicious_golds::calculate(run.segments(), TimingMethod::GameTime, DEFAULT_THRESHOLD);
+    assert!(golds.is_empty());
+}
+
+#[test]
+fn cleaner_removes_suspicious_gold() {
+    let mut run = run();
+    let mut cleaner = SumOfBestCleaner::with_suspicious_golds(&mut run, DEFAULT_THRESHOLD);
+
+    let clean_up = cleaner.next_potential_clean_up().unwrap();
+    assert!(clean_up
+        .to_string()
+        .contains("typical segment time of 10.00"));
+    let clean_up = clean_up.into();
+    cleaner.apply(clean_up);
+
+    // The same best segment is never pointed out twice.
+    while let Some(clean_up) = cleaner.next_potential_clean_up() {
+        assert!(!clean_up
+            .to_string()
+            .contains("typical segment time of 10.00"));
+    }
+    drop(cleaner);
+
+    let second = run.segment(1);
+    assert!(second.segment_history().get(8).is_none());
+    assert_eq!(second.best_segment_time().game_time, Some(span(10.0)));
+}
+
+#[test]
+fn plain_cleaner_ignores_suspicious_golds() {
+    let mut run = run();
+    let mut cleaner = SumOfBestCleaner::new(&mut run);
+    assert!(cleaner.next_potential_clean_up().is_none());
+}
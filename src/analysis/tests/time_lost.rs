@@ -0,0 +1,106 @@
+use super::super::time_lost::{calculate, SegmentTimeLost};
+use crate::comparison::personal_best;
+use crate::util::tests_helper::{
+    create_timer, make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, span,
+    start_run,
+};
+use crate::{TimeSpan, TimingMethod};
+
+#[test]
+fn gained_time_does_not_reduce_time_lost() {
+    let mut timer = create_timer(&["First", "Second", "Third"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[11.0, 18.0, 31.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.0), Some(20.0)]);
+    timer.set_game_time(span(25.0));
+
+    let time_lost = calculate(
+        &timer.snapshot(),
+        personal_best::NAME,
+        TimingMethod::GameTime,
+        false,
+    );
+    assert_eq!(
+        time_lost.segments,
+        [
+            SegmentTimeLost {
+                segment_index: 0,
+                best_segments: Some(span(2.0)),
+                comparison: Some(span(2.0)),
+            },
+            SegmentTimeLost {
+                segment_index: 1,
+                best_segments: Some(span(1.0)),
+                comparison: Some(TimeSpan::zero()),
+            },
+        ]
+    );
+    assert_eq!(time_lost.best_segments, span(3.0));
+    assert_eq!(time_lost.comparison, span(2.0));
+    assert!(!time_lost.updates_frequently);
+}
+
+#[test]
+fn live_segment_counts_once_it_is_behind() {
+    let mut timer = create_timer(&["First", "Second"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(10.0)]);
+
+    timer.set_game_time(span(15.0));
+    let time_lost = calculate(
+        &timer.snapshot(),
+        personal_best::NAME,
+        TimingMethod::GameTime,
+        true,
+    );
+    assert_eq!(time_lost.segments.len(), 1);
+    assert!(!time_lost.updates_frequently);
+
+    timer.set_game_time(span(23.0));
+    let time_lost = calculate(
+        &timer.snapshot(),
+        personal_best::NAME,
+        TimingMethod::GameTime,
+        true,
+    );
+    assert_eq!(time_lost.segments.len(), 2);
+    assert_eq!(time_lost.best_segments, span(3.0));
+    assert_eq!(time_lost.comparison, span(3.0));
+    assert!(time_lost.updates_frequently);
+}
+
+#[test]
+fn skipped_splits_are_combined() {
+    let mut timer = create_timer(&["First", "Second", "Third"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(35.0)]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(34.0)]);
+
+    let time_lost = calculate(
+        &timer.snapshot(),
+        personal_best::NAME,
+        TimingMethod::GameTime,
+        false,
+    );
+    assert_eq!(
+        time_lost.segments,
+        [
+            SegmentTimeLost {
+                segment_index: 0,
+                best_segments: Some(TimeSpan::zero()),
+                comparison: Some(TimeSpan::zero()),
+            },
+            SegmentTimeLost {
+                segment_index: 2,
+                best_segments: Some(span(4.0)),
+                comparison: Some(span(4.0)),
+            },
+        ]
+    );
+}
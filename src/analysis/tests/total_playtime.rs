@@ -0,0 +1,94 @@
+use super::super::total_playtime::{self, Period};
+use crate::platform::DateTime;
+use crate::util::tests_helper::run_with_splits;
+use crate::{AtomicDateTime, Run, Segment, Time, TimeSpan, Timer};
+
+const DAY: i64 = 24 * 60 * 60;
+// 2023-01-04 12:00:00 UTC, which is a Wednesday.
+const WEDNESDAY: i64 = 1_672_833_600;
+
+fn at(seconds: i64) -> Option<AtomicDateTime> {
+    Some(AtomicDateTime::new(
+        DateTime::from_unix_timestamp(seconds).unwrap(),
+        false,
+    ))
+}
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    for (started, duration) in [
+        (WEDNESDAY, 100),
+        (WEDNESDAY + 200, 50),
+        (WEDNESDAY + 4000, 30),
+        (WEDNESDAY + DAY, 10),
+        (WEDNESDAY + 7 * DAY, 20),
+    ] {
+        run.add_attempt(Time::default(), at(started), at(started + duration), None);
+    }
+    run
+}
+
+#[test]
+fn by_day() {
+    let periods = total_playtime::by_period(&run(), Period::Day);
+    let s = TimeSpan::from_seconds;
+    assert_eq!(periods.len(), 3);
+    assert_eq!(periods[0].attempts, 3);
+    assert_eq!(periods[0].playtime, s(180.0));
+    assert_eq!(periods[1].playtime, s(10.0));
+    assert_eq!(periods[2].playtime, s(20.0));
+    assert!(periods[0].start < periods[1].start && periods[1].start < periods[2].start);
+}
+
+#[test]
+fn on_date() {
+    let run = run();
+    let periods = total_playtime::by_period(&run, Period::Day);
+    for period in &periods {
+        assert_eq!(total_playtime::on_date(&run, period.start), period.playtime);
+    }
+    let day_after = periods[2].start.next_day().unwrap();
+    assert_eq!(total_playtime::on_date(&run, day_after), TimeSpan::zero());
+}
+
+#[test]
+fn by_week() {
+    let periods = total_playtime::by_period(&run(), Period::Week);
+    assert_eq!(periods.len(), 2);
+    assert_eq!(periods[0].attempts, 4);
+    assert_eq!(periods[0].playtime, TimeSpan::from_seconds(190.0));
+    assert_eq!(periods[1].start - periods[0].start, time::Duration::days(7));
+}
+
+#[test]
+fn by_session() {
+    let sessions = total_playtime::by_session(&run(), TimeSpan::from_seconds(30.0 * 60.0));
+    let s = TimeSpan::from_seconds;
+    assert_eq!(sessions.len(), 4);
+    assert_eq!(sessions[0].first_attempt, 1);
+    assert_eq!(sessions[0].last_attempt, 2);
+    assert_eq!(sessions[0].attempts, 2);
+    assert_eq!(sessions[0].playtime, s(150.0));
+    assert_eq!(sessions[1].playtime, s(30.0));
+
+    let sessions = total_playtime::by_session(&run(), TimeSpan::from_seconds(2.0 * 60.0 * 60.0));
+    assert_eq!(sessions.len(), 3);
+    assert_eq!(sessions[0].attempts, 3);
+    assert_eq!(sessions[0].ended.time, at(WEDNESDAY + 4030).unwrap().time);
+}
+
+#[test]
+fn by_segment() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    let mut timer = Timer::new(run).unwrap();
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+    run_with_splits(&mut timer, &[12.0, 30.0]);
+
+    let segments = total_playtime::by_segment(timer.run());
+    assert_eq!(segments.len(), 2);
+    assert!(segments[0] > TimeSpan::zero());
+    assert!(segments[1] > TimeSpan::zero());
+}
@@ -0,0 +1,75 @@
+use super::super::trends::{calculate, Bucketing};
+use crate::util::tests_helper::{run_with_splits, start_run};
+use crate::{Run, Segment, Time, Timer, TimingMethod};
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    Timer::new(run).unwrap()
+}
+
+fn reset_in_first_segment(timer: &mut Timer) {
+    start_run(timer);
+    timer.reset(true);
+}
+
+#[test]
+fn buckets_by_attempts() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    reset_in_first_segment(&mut timer);
+    run_with_splits(&mut timer, &[8.0]);
+    run_with_splits(&mut timer, &[6.0, 15.0]);
+    run_with_splits(&mut timer, &[5.0, 12.0]);
+
+    let trends = calculate(timer.run(), TimingMethod::GameTime, Bucketing::Attempts(3));
+    assert_eq!(trends.buckets.len(), 2);
+
+    let first = &trends.buckets[0];
+    assert_eq!((first.first_attempt, first.last_attempt), (1, 3));
+    assert_eq!((first.attempts, first.completed), (3, 1));
+    assert_eq!(first.segments[0].reached, 3);
+    assert_eq!(first.segments[0].resets, 1);
+    assert_eq!(first.segments[0].median, Some(9.0));
+    assert_eq!(first.segments[0].standard_deviation, Some(1.0));
+    assert_eq!(first.segments[0].improvement_rate, None);
+    assert_eq!(first.segments[1].reached, 2);
+    assert_eq!(first.segments[1].reset_rate, 0.5);
+
+    let second = &trends.buckets[1];
+    assert_eq!((second.first_attempt, second.last_attempt), (4, 5));
+    assert_eq!(second.completion_rate, 1.0);
+    assert_eq!(second.segments[0].median, Some(5.5));
+    let improvement = second.segments[0].improvement_rate.unwrap();
+    assert!((improvement - (9.0 - 5.5) / 9.0).abs() < 1e-9);
+}
+
+#[test]
+fn buckets_by_days() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[8.0, 16.0]);
+
+    let trends = calculate(timer.run(), TimingMethod::GameTime, Bucketing::Days(1));
+    assert_eq!(trends.buckets.len(), 1);
+    assert_eq!(trends.buckets[0].attempts, 2);
+    assert!(trends.buckets[0].started.is_some());
+}
+
+#[test]
+fn attempts_without_a_starting_time_are_skipped_when_bucketing_by_days() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    let mut run = timer.run().clone();
+    run.add_attempt_with_index(Time::default(), 2, None, None, None);
+    let mut timer = Timer::new(run).unwrap();
+    run_with_splits(&mut timer, &[8.0, 16.0]);
+
+    let trends = calculate(timer.run(), TimingMethod::GameTime, Bucketing::Days(1));
+    assert_eq!(trends.buckets.len(), 1);
+    let bucket = &trends.buckets[0];
+    assert_eq!((bucket.first_attempt, bucket.last_attempt), (1, 3));
+    assert_eq!((bucket.attempts, bucket.completed), (2, 2));
+    assert_eq!(bucket.segments[0].reached, 2);
+}
@@ -0,0 +1,114 @@
+//! Provides functions for calculating how much time has been lost in the
+//! current attempt, both compared to the best segments and compared to a
+//! comparison. Unlike the deltas, segments where time was gained don't reduce
+//! the time lost, so this is the amount of time that could've been saved if
+//! every segment that lost time had been at least as fast as the best segments
+//! or the comparison. If splits were skipped, the segment time of the next
+//! split that isn't skipped is compared against the combined segment time.
+
+use crate::{analysis, platform::prelude::*, timing::Snapshot, TimeSpan, TimingMethod};
+
+/// The time lost on a single segment of the current attempt.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SegmentTimeLost {
+    /// The index of the segment. If splits were skipped, this is the index of
+    /// the segment that ends the combined segment.
+    pub segment_index: usize,
+    /// The time lost compared to the best segments. This is `None` if there is
+    /// no best segment to compare against.
+    pub best_segments: Option<TimeSpan>,
+    /// The time lost compared to the comparison. This is `None` if there is no
+    /// comparison time to compare against.
+    pub comparison: Option<TimeSpan>,
+}
+
+/// The time lost in the current attempt.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct TimeLost {
+    /// The time lost on each of the segments that have been completed. If the
+    /// live segment is taken into account, it is the last segment in here.
+    pub segments: Vec<SegmentTimeLost>,
+    /// The total time lost compared to the best segments.
+    pub best_segments: TimeSpan,
+    /// The total time lost compared to the comparison.
+    pub comparison: TimeSpan,
+    /// Specifies whether the time lost is currently actively changing, as time
+    /// is being lost on the live segment.
+    pub updates_frequently: bool,
+}
+
+impl TimeLost {
+    fn push(&mut self, segment: SegmentTimeLost) {
+        self.best_segments += segment.best_segments.unwrap_or_default();
+        self.comparison += segment.comparison.unwrap_or_default();
+        self.segments.push(segment);
+    }
+}
+
+fn lost(time: TimeSpan) -> TimeSpan {
+    time.max(TimeSpan::zero())
+}
+
+/// Calculates how much time has been lost in the current attempt compared to
+/// the best segments and the comparison provided. If the parameter `live` is
+/// set to `true`, the time that is being lost on the current segment is taken
+/// into account as well, as soon as the current segment takes longer than the
+/// best segment or the comparison.
+pub fn calculate(
+    timer: &Snapshot<'_>,
+    comparison: &str,
+    method: TimingMethod,
+    live: bool,
+) -> TimeLost {
+    let mut time_lost = TimeLost::default();
+    let Some(current_split_index) = timer.current_split_index() else {
+        return time_lost;
+    };
+    let segments = timer.run().segments();
+    let mut combined_best_segments = Some(TimeSpan::zero());
+
+    for (segment_index, segment) in segments.iter().enumerate() {
+        combined_best_segments = catch! {
+            combined_best_segments? + segment.best_segment_time()[method]?
+        };
+
+        if segment_index == current_split_index {
+            if live && timer.current_phase().is_running() {
+                let best_segments = catch! {
+                    analysis::live_segment_time(timer, segment_index, method)?
+                        - combined_best_segments?
+                }
+                .filter(|&t| t > TimeSpan::zero());
+                let comparison =
+                    analysis::live_segment_delta(timer, segment_index, comparison, method)
+                        .filter(|&t| t > TimeSpan::zero());
+                if best_segments.is_some() || comparison.is_some() {
+                    time_lost.updates_frequently = true;
+                    time_lost.push(SegmentTimeLost {
+                        segment_index,
+                        best_segments,
+                        comparison,
+                    });
+                }
+            }
+            break;
+        }
+
+        let Some(segment_time) = analysis::previous_segment_time(timer, segment_index, method)
+        else {
+            // The split got skipped, so the segment gets combined with the
+            // next one.
+            continue;
+        };
+
+        time_lost.push(SegmentTimeLost {
+            segment_index,
+            best_segments: combined_best_segments.map(|best| lost(segment_time - best)),
+            comparison: analysis::previous_segment_delta(timer, segment_index, comparison, method)
+                .map(lost),
+        });
+        combined_best_segments = Some(TimeSpan::zero());
+    }
+
+    time_lost
+}
@@ -2,41 +2,94 @@
 //! [`Run`] or a [`Timer`]. For a [`Run`], all the durations stored in the attempt
 //! history are summed together. For a [`Timer`], the current attempt's duration
 //! is also factored in.
+//!
+//! The playtime can also be broken down by the days or weeks the attempts were
+//! started at, by segment and by session. A session is a cluster of attempts
+//! that are separated by gaps no longer than a certain amount of time.
 
-use crate::{Run, TimeSpan, Timer, TimingMethod};
+use crate::{
+    platform::{prelude::*, to_local, Duration},
+    AtomicDateTime, Attempt, Run, TimeSpan, Timer, TimingMethod,
+};
+use time::Date;
+
+/// Describes the period of time that the playtime gets grouped by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Period {
+    /// The playtime gets grouped by the local day the attempts were started at.
+    Day,
+    /// The playtime gets grouped by the local week the attempts were started
+    /// at. Weeks start on Monday.
+    Week,
+}
+
+/// The playtime of all the attempts started within a single day or week.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeriodPlaytime {
+    /// The local date the period starts at. For weeks this is the Monday of
+    /// the week.
+    pub start: Date,
+    /// The number of attempts that were started within the period.
+    pub attempts: u32,
+    /// The playtime of all the attempts that were started within the period.
+    pub playtime: TimeSpan,
+}
+
+/// The playtime of a single session, which is a cluster of attempts that are
+/// separated by gaps no longer than a certain amount of time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionPlaytime {
+    /// The index of the first attempt of the session.
+    pub first_attempt: i32,
+    /// The index of the last attempt of the session.
+    pub last_attempt: i32,
+    /// The point in time the first attempt of the session was started at.
+    pub started: AtomicDateTime,
+    /// The point in time the last attempt of the session ended at.
+    pub ended: AtomicDateTime,
+    /// The number of attempts in the session.
+    pub attempts: u32,
+    /// The playtime of all the attempts of the session.
+    pub playtime: TimeSpan,
+}
 /// Allows calculating the total playtime.
 pub trait TotalPlaytime {
     /// Calculates the total playtime.
     fn total_playtime(&self) -> TimeSpan;
 }
 
-impl TotalPlaytime for Run {
-    fn total_playtime(&self) -> TimeSpan {
-        let mut total_playtime = TimeSpan::zero();
-
-        for attempt in self.attempt_history() {
-            if let Some(duration) = attempt.duration() {
-                // Either >= 1.6.0 or a finished run
-                total_playtime += duration;
-                if let Some(pause_time) = attempt.pause_time() {
-                    total_playtime -= pause_time;
-                }
-            } else {
-                // Must be < 1.6.0 and a reset
-                // Calculate the sum of the segments for that run
-                for segment in self.segments() {
-                    if let Some(segment_time) = segment
-                        .segment_history()
-                        .get(attempt.index())
-                        .and_then(|s| s[TimingMethod::RealTime])
-                    {
-                        total_playtime += segment_time;
-                    }
-                }
+pub(crate) fn attempt_playtime(run: &Run, attempt: &Attempt) -> TimeSpan {
+    let mut playtime = TimeSpan::zero();
+
+    if let Some(duration) = attempt.duration() {
+        // Either >= 1.6.0 or a finished run
+        playtime += duration;
+        if let Some(pause_time) = attempt.pause_time() {
+            playtime -= pause_time;
+        }
+    } else {
+        // Must be < 1.6.0 and a reset
+        // Calculate the sum of the segments for that run
+        for segment in run.segments() {
+            if let Some(segment_time) = segment
+                .segment_history()
+                .get(attempt.index())
+                .and_then(|s| s[TimingMethod::RealTime])
+            {
+                playtime += segment_time;
             }
         }
+    }
 
-        total_playtime
+    playtime
+}
+
+impl TotalPlaytime for Run {
+    fn total_playtime(&self) -> TimeSpan {
+        self.attempt_history()
+            .iter()
+            .map(|attempt| attempt_playtime(self, attempt))
+            .sum()
     }
 }
 
@@ -61,3 +114,134 @@ impl<'a, T: 'a + TotalPlaytime> TotalPlaytime for &'a T {
 pub fn calculate<T: TotalPlaytime>(source: T) -> TimeSpan {
     source.total_playtime()
 }
+
+fn period_start(date: Date, period: Period) -> Date {
+    match period {
+        Period::Day => date,
+        Period::Week => date - Duration::days(date.weekday().number_days_from_monday() as i64),
+    }
+}
+
+/// Breaks down the playtime of the run provided by the local days or weeks
+/// the attempts were started at. Attempts are fully attributed to the period
+/// they were started in, even if they continued into the next period. Attempts
+/// without a known starting time are not considered. The periods are ordered
+/// chronologically and periods without any attempts are omitted.
+pub fn by_period(run: &Run, period: Period) -> Vec<PeriodPlaytime> {
+    let mut periods = Vec::<PeriodPlaytime>::new();
+
+    for attempt in run.attempt_history() {
+        let Some(started) = attempt.started() else {
+            continue;
+        };
+        let start = period_start(to_local(started.time).date(), period);
+        let playtime = attempt_playtime(run, attempt);
+
+        let index = match periods.binary_search_by_key(&start, |p| p.start) {
+            Ok(index) => index,
+            Err(index) => {
+                periods.insert(
+                    index,
+                    PeriodPlaytime {
+                        start,
+                        attempts: 0,
+                        playtime: TimeSpan::zero(),
+                    },
+                );
+                index
+            }
+        };
+        let entry = &mut periods[index];
+        entry.attempts += 1;
+        entry.playtime += playtime;
+    }
+
+    periods
+}
+
+/// Breaks down the playtime of the run provided by segment, based on the
+/// Real Time segment times stored in the segment history. The time spent in a
+/// segment during which an attempt got reset is not stored in the segment
+/// history, so it is not considered. The playtime of a segment time that
+/// combines multiple segments, because the segments before it got skipped, is
+/// attributed to the segment that was split on.
+pub fn by_segment(run: &Run) -> Vec<TimeSpan> {
+    run.segments()
+        .iter()
+        .map(|segment| {
+            segment
+                .segment_history()
+                .iter_actual_runs()
+                .filter_map(|(_, time)| time.real_time)
+                .sum()
+        })
+        .collect()
+}
+
+/// Breaks down the playtime of the run provided by session. A session is a
+/// cluster of attempts where each attempt is started no later than `max_gap`
+/// after the previous attempt ended. Attempts without a known starting time
+/// are not considered. The sessions are ordered chronologically.
+pub fn by_session(run: &Run, max_gap: TimeSpan) -> Vec<SessionPlaytime> {
+    let mut sessions = Vec::<SessionPlaytime>::new();
+
+    for attempt in run.attempt_history() {
+        let Some(started) = attempt.started() else {
+            continue;
+        };
+        let playtime = attempt_playtime(run, attempt);
+        let ended = attempt.ended().unwrap_or_else(|| {
+            AtomicDateTime::new(
+                started.time + attempt.duration().unwrap_or(playtime).to_duration(),
+                started.synced_with_atomic_clock,
+            )
+        });
+
+        match sessions.last_mut() {
+            Some(session) if started - session.ended <= max_gap => {
+                session.last_attempt = attempt.index();
+                session.ended = ended;
+                session.attempts += 1;
+                session.playtime += playtime;
+            }
+            _ => sessions.push(SessionPlaytime {
+                first_attempt: attempt.index(),
+                last_attempt: attempt.index(),
+                started,
+                ended,
+                attempts: 1,
+                playtime,
+            }),
+        }
+    }
+
+    sessions
+}
+
+/// Calculates the playtime of the timer's current attempt. If there is no
+/// active attempt, the playtime is zero.
+pub fn current_attempt_playtime(timer: &Timer) -> TimeSpan {
+    timer.current_attempt_duration() - timer.get_pause_time().unwrap_or_default()
+}
+
+/// Calculates the playtime of all the attempts in the run's history that were
+/// started on the local date provided.
+pub fn on_date(run: &Run, date: Date) -> TimeSpan {
+    run.attempt_history()
+        .iter()
+        .filter(|attempt| {
+            attempt
+                .started()
+                .is_some_and(|started| to_local(started.time).date() == date)
+        })
+        .map(|attempt| attempt_playtime(run, attempt))
+        .sum()
+}
+
+/// Calculates the playtime of today for the timer provided. This is the
+/// playtime of all the attempts that were started today in local time,
+/// including the current attempt.
+pub fn today(timer: &Timer) -> TimeSpan {
+    let today = to_local(AtomicDateTime::now().time).date();
+    on_date(timer.run(), today) + current_attempt_playtime(timer)
+}
@@ -0,0 +1,273 @@
+//! Provides functionality to analyze how the consistency of a runner develops
+//! over time. The attempt history of a [`Run`] is split into buckets, either by
+//! the number of attempts or by the dates the attempts were started at. For
+//! each of the buckets, statistics about each segment are calculated, such as
+//! the median segment time, its standard deviation and how often the runner
+//! reset during the segment. The results are meant to be visualized as charts.
+
+use crate::{
+    platform::{
+        math::f64::{floor, sqrt},
+        prelude::*,
+    },
+    Attempt, Run, Segment, TimeSpan, TimingMethod,
+};
+use serde::{Deserialize, Serialize};
+
+/// Describes how the attempt history is split into buckets.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Bucketing {
+    /// Each bucket consists of the specified number of consecutive attempts.
+    Attempts(u32),
+    /// Each bucket consists of the attempts that were started within the
+    /// specified number of days. The first bucket starts with the first
+    /// attempt that has a known starting time. Attempts without a known
+    /// starting time are not considered.
+    Days(u32),
+}
+
+/// The trends of a [`Run`], consisting of the statistics of all the buckets
+/// the attempt history got split into. The buckets are ordered from the oldest
+/// to the most recent.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trends {
+    /// The statistics of all the buckets.
+    pub buckets: Vec<Bucket>,
+}
+
+/// The statistics of a single bucket of attempts.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bucket {
+    /// The index of the first attempt in this bucket.
+    pub first_attempt: i32,
+    /// The index of the last attempt in this bucket.
+    pub last_attempt: i32,
+    /// The point in time the first attempt of this bucket was started at, as
+    /// seconds since the Unix epoch. This is `None` if it is not known.
+    pub started: Option<i64>,
+    /// The number of attempts in this bucket.
+    pub attempts: u32,
+    /// The number of attempts in this bucket that were completed.
+    pub completed: u32,
+    /// The share of the attempts in this bucket that were completed. This is a
+    /// value between 0 and 1.
+    pub completion_rate: f64,
+    /// The statistics of each segment within this bucket.
+    pub segments: Vec<SegmentStatistics>,
+}
+
+/// The statistics of a single segment within a bucket of attempts.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SegmentStatistics {
+    /// The number of attempts that reached this segment.
+    pub reached: u32,
+    /// The number of attempts that were reset during this segment.
+    pub resets: u32,
+    /// The share of the attempts reaching this segment that were reset during
+    /// it. This is a value between 0 and 1.
+    pub reset_rate: f64,
+    /// The number of segment times that the times are based on. Skipped
+    /// segments and combined segment times are not considered.
+    pub samples: u32,
+    /// The median of the segment times in seconds. This is `None` if there are
+    /// no segment times.
+    pub median: Option<f64>,
+    /// The standard deviation of the segment times in seconds. This is `None`
+    /// if there are no segment times.
+    pub standard_deviation: Option<f64>,
+    /// How much faster the median segment time got compared to the median of
+    /// the most recent earlier bucket that has one. A value of 0.1 means that
+    /// the segment got 10% faster, while negative values mean that it got
+    /// slower. This is `None` if there is no earlier median to compare to.
+    pub improvement_rate: Option<f64>,
+}
+
+#[cfg(feature = "std")]
+impl Trends {
+    /// Encodes the trends as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+/// Calculates the trends of the run provided for the timing method specified.
+/// The attempt history is split into buckets as specified.
+pub fn calculate(run: &Run, method: TimingMethod, bucketing: Bucketing) -> Trends {
+    let mut trends = Trends::default();
+    let mut times = Vec::new();
+    let mut attempts = Vec::new();
+
+    for bucket_attempts in split_into_buckets(run.attempt_history(), bucketing) {
+        // When bucketing by days, attempts without a known starting time may
+        // be interspersed with the bucket's attempts, but they don't belong to
+        // any bucket.
+        attempts.clear();
+        attempts.extend(
+            bucket_attempts
+                .iter()
+                .filter(|attempt| bucketing.considers(attempt)),
+        );
+
+        let (Some(first), Some(last)) = (attempts.first(), attempts.last()) else {
+            continue;
+        };
+
+        let mut bucket = Bucket {
+            first_attempt: first.index(),
+            last_attempt: last.index(),
+            started: first.started().map(|s| s.time.unix_timestamp()),
+            attempts: attempts.len() as u32,
+            ..Default::default()
+        };
+
+        let mut previous_segment: Option<&Segment> = None;
+        for (segment_index, segment) in run.segments().iter().enumerate() {
+            let history = segment.segment_history();
+            let mut statistics = SegmentStatistics::default();
+            times.clear();
+
+            for attempt in &attempts {
+                let id = attempt.index();
+                let reached = segment_index == 0
+                    || catch! { previous_segment?.segment_history().get(id)? }.is_some();
+                if !reached {
+                    continue;
+                }
+                statistics.reached += 1;
+
+                let Some(time) = history.get(id) else {
+                    statistics.resets += 1;
+                    continue;
+                };
+
+                if let Some(time) = time[method] {
+                    // Skip all the combined segments
+                    let skip = catch! {
+                        previous_segment?.segment_history().get(id)?[method].is_none()
+                    }
+                    .unwrap_or(false);
+
+                    if !skip {
+                        times.push(time);
+                    }
+                }
+            }
+
+            if statistics.reached != 0 {
+                statistics.reset_rate = statistics.resets as f64 / statistics.reached as f64;
+            }
+            statistics.samples = times.len() as u32;
+            statistics.median = median(&mut times);
+            statistics.standard_deviation = standard_deviation(&times);
+            statistics.improvement_rate = catch! {
+                let current = statistics.median?;
+                let previous = trends
+                    .buckets
+                    .iter()
+                    .rev()
+                    .find_map(|b| b.segments.get(segment_index)?.median)?;
+                (previous - current) / previous
+            }
+            .filter(|rate| rate.is_finite());
+
+            bucket.segments.push(statistics);
+            previous_segment = Some(segment);
+        }
+
+        if let Some(last_segment) = bucket.segments.last() {
+            bucket.completed = last_segment.reached - last_segment.resets;
+        }
+        bucket.completion_rate = bucket.completed as f64 / bucket.attempts as f64;
+
+        trends.buckets.push(bucket);
+    }
+
+    trends
+}
+
+impl Bucketing {
+    const fn considers(self, attempt: &Attempt) -> bool {
+        match self {
+            Bucketing::Attempts(_) => true,
+            Bucketing::Days(_) => attempt.started().is_some(),
+        }
+    }
+}
+
+fn split_into_buckets(
+    attempts: &[Attempt],
+    bucketing: Bucketing,
+) -> impl Iterator<Item = &[Attempt]> + '_ {
+    let (by_days, size) = match bucketing {
+        Bucketing::Attempts(size) => (false, size.max(1)),
+        Bucketing::Days(size) => (true, size.max(1)),
+    };
+    let bucket_duration = TimeSpan::from_days(size as f64);
+
+    let mut remaining = attempts;
+    let mut first_started = None;
+
+    core::iter::from_fn(move || {
+        if by_days {
+            let skip = remaining.iter().position(|a| a.started().is_some())?;
+            remaining = &remaining[skip..];
+            let started = remaining[0].started()?;
+            let first_started = *first_started.get_or_insert(started);
+            let index = bucket_index(started - first_started, bucket_duration);
+            let len = remaining
+                .iter()
+                .position(|a| {
+                    a.started()
+                        .is_some_and(|s| bucket_index(s - first_started, bucket_duration) != index)
+                })
+                .unwrap_or(remaining.len());
+            let (bucket, rest) = remaining.split_at(len);
+            remaining = rest;
+            Some(bucket)
+        } else {
+            if remaining.is_empty() {
+                return None;
+            }
+            let (bucket, rest) = remaining.split_at((size as usize).min(remaining.len()));
+            remaining = rest;
+            Some(bucket)
+        }
+    })
+}
+
+fn bucket_index(offset: TimeSpan, bucket_duration: TimeSpan) -> i64 {
+    floor(offset.total_seconds() / bucket_duration.total_seconds()) as i64
+}
+
+fn median(times: &mut [TimeSpan]) -> Option<f64> {
+    if times.is_empty() {
+        return None;
+    }
+    times.sort_unstable();
+    let mid = times.len() / 2;
+    Some(if times.len() % 2 == 0 {
+        0.5 * (times[mid - 1].total_seconds() + times[mid].total_seconds())
+    } else {
+        times[mid].total_seconds()
+    })
+}
+
+fn standard_deviation(times: &[TimeSpan]) -> Option<f64> {
+    if times.is_empty() {
+        return None;
+    }
+    let len = times.len() as f64;
+    let mean = times.iter().map(TimeSpan::total_seconds).sum::<f64>() / len;
+    let variance = times
+        .iter()
+        .map(|t| {
+            let diff = t.total_seconds() - mean;
+            diff * diff
+        })
+        .sum::<f64>()
+        / len;
+    Some(sqrt(variance))
+}
@@ -0,0 +1,159 @@
+//! Defines the Comparison Generator for comparing against the splits of
+//! another run, such as a rival's splits or the World Record. Unlike importing
+//! a comparison, the splits stay linked to the file they come from, so they can
+//! be refreshed whenever that file changes. The other run doesn't need to be
+//! split the same way. Its splits are aligned with the segments of this run by
+//! their names.
+
+use super::ComparisonGenerator;
+use crate::{
+    platform::prelude::*,
+    settings::{Field, SettingsDescription, Value},
+    Attempt, Run, Segment, Time,
+};
+
+/// The kind of this Comparison Generator.
+pub const KIND: &str = "Linked Comparison";
+
+/// The Comparison Generator for comparing against the splits of another run.
+/// The splits are stored together with the names of their segments and get
+/// aligned with the segments of this run by their names. All the splits that
+/// don't have a segment with the same name are skipped, while the final split
+/// is always aligned with the final segment. Segments that don't have a split
+/// with the same name don't get a comparison time.
+///
+/// The splits themselves are not stored in the splits file. Only the path of
+/// the file they come from is. Use [`Run::refresh_linked_comparisons`] after
+/// loading a [`Run`] to load the splits again.
+#[derive(Clone, Debug)]
+pub struct LinkedComparison {
+    name: String,
+    path: String,
+    splits: Vec<(String, Time)>,
+}
+
+impl Default for LinkedComparison {
+    fn default() -> Self {
+        Self::new(String::from("Linked"), String::new())
+    }
+}
+
+impl LinkedComparison {
+    /// Creates a new Linked Comparison with the name of the comparison and the
+    /// path of the file the splits come from. It doesn't have any splits until
+    /// the source is provided via [`set_source`](Self::set_source) or
+    /// [`set_splits`](Self::set_splits).
+    pub const fn new(name: String, path: String) -> Self {
+        Self {
+            name,
+            path,
+            splits: Vec::new(),
+        }
+    }
+
+    /// Accesses the path of the file the splits come from.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Sets the path of the file the splits come from.
+    pub fn set_path(&mut self, path: String) {
+        self.path = path;
+    }
+
+    /// Accesses the splits, consisting of the name of each segment and its
+    /// split time.
+    pub fn splits(&self) -> &[(String, Time)] {
+        &self.splits
+    }
+
+    /// Replaces the splits with the split list provided. Each split consists of
+    /// the name of the segment and its split time.
+    pub fn set_splits<I>(&mut self, splits: I)
+    where
+        I: IntoIterator<Item = (String, Time)>,
+    {
+        self.splits.clear();
+        self.splits.extend(splits);
+    }
+
+    /// Replaces the splits with the Personal Best split times of the run
+    /// provided.
+    pub fn set_source(&mut self, run: &Run) {
+        self.set_splits(
+            run.segments()
+                .iter()
+                .map(|s| (s.name().to_owned(), s.personal_best_split_time())),
+        );
+    }
+}
+
+/// Creates a Linked Comparison without any splits from the settings of the
+/// Comparison Generator provided. Returns `None` if the Comparison Generator is
+/// not a Linked Comparison.
+pub(crate) fn from_settings_of(generator: &dyn ComparisonGenerator) -> Option<LinkedComparison> {
+    if generator.kind() != KIND {
+        return None;
+    }
+    let mut linked = LinkedComparison::default();
+    super::registry::apply_settings(
+        &mut linked,
+        generator
+            .settings_description()
+            .fields
+            .into_iter()
+            .map(|field| field.value),
+    );
+    Some(linked)
+}
+
+impl ComparisonGenerator for LinkedComparison {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        KIND
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        for segment in &mut *segments {
+            *segment.comparison_mut(&self.name) = Time::default();
+        }
+
+        let Some(((_, last_time), splits)) = self.splits.split_last() else {
+            return;
+        };
+
+        let Some((last_segment, mut remaining_segments)) = segments.split_last_mut() else {
+            return;
+        };
+
+        for (name, time) in splits {
+            if let Some(segment_index) = remaining_segments
+                .iter()
+                .position(|s| unicase::eq(name.as_str(), s.name()))
+            {
+                *remaining_segments[segment_index].comparison_mut(&self.name) = *time;
+                remaining_segments = &mut remaining_segments[segment_index + 1..];
+            }
+        }
+
+        *last_segment.comparison_mut(&self.name) = *last_time;
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Name".into(), self.name.clone().into()),
+            Field::new("Path".into(), self.path.clone().into()),
+        ])
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.name = value.into(),
+            1 => self.path = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
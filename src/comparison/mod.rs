@@ -11,17 +11,26 @@ pub mod best_segments;
 pub mod best_split_times;
 pub mod goal;
 pub mod latest_run;
+pub mod linked;
 pub mod median_segments;
 pub mod none;
+pub mod percentile;
+pub mod recent_segments;
+pub mod registry;
 pub mod worst_segments;
 
 pub use self::{
     average_segments::AverageSegments, balanced_pb::BalancedPB, best_segments::BestSegments,
-    best_split_times::BestSplitTimes, latest_run::LatestRun, median_segments::MedianSegments,
-    none::None, worst_segments::WorstSegments,
+    best_split_times::BestSplitTimes, latest_run::LatestRun, linked::LinkedComparison,
+    median_segments::MedianSegments, none::None, percentile::Percentile,
+    recent_segments::RecentSegments, registry::Registry, worst_segments::WorstSegments,
 };
 
-use crate::{platform::prelude::*, Attempt, Segment, Timer};
+use crate::{
+    platform::prelude::*,
+    settings::{SettingsDescription, Value},
+    Attempt, Segment, Timer,
+};
 use core::fmt::Debug;
 
 /// Race comparisons are prefixed by `[Race]`.
@@ -52,14 +61,45 @@ pub mod world_record {
 /// A Comparison Generator automatically generates a comparison based on what
 /// kind of generator it is. Comparison Generators stored in a Run automatically
 /// get called between all attempts to refresh the comparison's information.
+///
+/// Comparison Generators can optionally be configured. Similar to components,
+/// they provide a generic description of their settings that can be modified by
+/// the index of the setting. The settings are stored alongside the kind of the
+/// generator when saving a [`Run`](crate::Run), so that a [`Registry`] can
+/// recreate the generator when loading the [`Run`](crate::Run) again.
 pub trait ComparisonGenerator: Debug + Sync + Send + ComparisonGeneratorClone {
     /// The name of the comparison.
     fn name(&self) -> &str;
+    /// The kind of the comparison generator. This identifies the comparison
+    /// generator in a [`Registry`] and thus needs to stay the same, even if the
+    /// name of the comparison changes based on the settings. By default this
+    /// is the name of the comparison.
+    fn kind(&self) -> &str {
+        self.name()
+    }
     /// Generate the comparison. The comparison generator is expected to modify
     /// the comparison's times for each segment. The Attempt History is
     /// provided, in case the comparison generator requires information from the
     /// previous attempts.
     fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]);
+    /// Accesses a generic description of the settings available for this
+    /// comparison generator and their current values. By default a comparison
+    /// generator doesn't have any settings.
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::default()
+    }
+    /// Sets a setting's value by its index to the given value. By default a
+    /// comparison generator doesn't have any settings, so this does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Comparison generators with settings may panic if the type of the value
+    /// to be set is not compatible with the type of the setting's value. A
+    /// panic can also occur if the index of the setting provided is out of
+    /// bounds.
+    fn set_value(&mut self, index: usize, value: Value) {
+        let _ = (index, value);
+    }
 }
 
 /// Provides the ability to clone a Comparison Generator, even when it is stored
@@ -0,0 +1,126 @@
+//! Defines the Comparison Generator for calculating a comparison at a specific
+//! percentile of the runner's skill curve. A percentile of 10 for example
+//! results in a comparison that represents a run within the top 10% of the
+//! runner's history, while a percentile of 50 roughly results in the Median
+//! Segments. This allows the runner to pace against a realistic good run,
+//! instead of only the Personal Best, the Balanced PB or the Best Segments.
+
+use super::ComparisonGenerator;
+use crate::{
+    analysis::SkillCurve,
+    platform::prelude::*,
+    settings::{Field, SettingsDescription, Value},
+    Attempt, Segment, TimeSpan, TimingMethod,
+};
+use core::fmt::Write;
+
+/// The kind of this Comparison Generator.
+pub const KIND: &str = "Percentile";
+
+/// The Comparison Generator for calculating a comparison at a specific
+/// percentile of the runner's skill curve. The percentile is specified in
+/// percent, where 0 represents the Sum of Best Segments and 100 represents the
+/// Sum of Worst Segments. The name of the comparison is derived from the
+/// percentile, so a percentile of 25 results in the comparison `P25`.
+#[derive(Clone, Debug)]
+pub struct Percentile {
+    percentile: u32,
+    name: String,
+}
+
+impl Default for Percentile {
+    fn default() -> Self {
+        Self::new(25)
+    }
+}
+
+impl Percentile {
+    /// Creates a new Percentile Comparison Generator for the percentile
+    /// provided in percent. The percentile is clamped to the range 0 to 100.
+    pub fn new(percentile: u32) -> Self {
+        let mut generator = Self {
+            percentile: 0,
+            name: String::new(),
+        };
+        generator.set_percentile(percentile);
+        generator
+    }
+
+    /// Accesses the percentile in percent.
+    pub const fn percentile(&self) -> u32 {
+        self.percentile
+    }
+
+    /// Sets the percentile in percent. The percentile is clamped to the range
+    /// 0 to 100.
+    pub fn set_percentile(&mut self, percentile: u32) {
+        self.percentile = percentile.min(100);
+        self.name.clear();
+        let _ = write!(self.name, "P{}", self.percentile);
+    }
+}
+
+fn generate(
+    segments: &mut [Segment],
+    method: TimingMethod,
+    percentile: f64,
+    comparison: &str,
+    skill_curve: &mut SkillCurve,
+) {
+    skill_curve.for_segments(segments, method);
+
+    let mut segments = segments.iter_mut();
+    for (segment, val) in segments
+        .by_ref()
+        .zip(skill_curve.iter_split_times_at_percentile(percentile, TimeSpan::zero()))
+    {
+        segment.comparison_mut(comparison)[method] = Some(val);
+    }
+    for segment in segments {
+        segment.comparison_mut(comparison)[method] = None;
+    }
+}
+
+impl ComparisonGenerator for Percentile {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        KIND
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        let mut skill_curve = SkillCurve::new();
+        let percentile = 0.01 * self.percentile as f64;
+
+        generate(
+            segments,
+            TimingMethod::RealTime,
+            percentile,
+            &self.name,
+            &mut skill_curve,
+        );
+        generate(
+            segments,
+            TimingMethod::GameTime,
+            percentile,
+            &self.name,
+            &mut skill_curve,
+        );
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![Field::new(
+            "Percentile".into(),
+            u64::from(self.percentile).into(),
+        )])
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.set_percentile(value.into_uint().unwrap().min(100) as _),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
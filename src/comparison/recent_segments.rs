@@ -0,0 +1,276 @@
+//! Defines the Comparison Generator for calculating the Recent Segments of a
+//! [`Run`](crate::Run). Unlike the Average Segments and the Median Segments,
+//! the Recent Segments only consider a window of recent history, such as the
+//! last few attempts or the attempts of the last few days. Within that window
+//! every segment is weighted equally. This makes the comparison a realistic
+//! representation of the runner's current form, even for splits files with a
+//! long history.
+
+use super::ComparisonGenerator;
+use crate::{
+    platform::prelude::*,
+    settings::{Field, SettingsDescription, Value},
+    Attempt, Segment, TimeSpan, TimingMethod,
+};
+use core::fmt::Write;
+
+/// The kind of the Comparison Generator that calculates the Recent Segments
+/// through an arithmetic mean.
+pub const AVERAGE_KIND: &str = "Recent Average Segments";
+/// The kind of the Comparison Generator that calculates the Recent Segments
+/// through a median.
+pub const MEDIAN_KIND: &str = "Recent Median Segments";
+
+/// The statistic used for combining the segment times within the window.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Statistic {
+    /// The arithmetic mean of the segment times.
+    Average,
+    /// The median of the segment times.
+    Median,
+}
+
+/// The Comparison Generator for calculating the Recent Segments of a
+/// [`Run`](crate::Run). Only the segment times within a window of recent
+/// history are considered. The window can be limited by the number of attempts,
+/// the number of days and the number of segment times. All the limits that are
+/// set apply at the same time. A limit of `0` means that there is no limit.
+///
+/// The name of the comparison is derived from the statistic and the limits,
+/// for example `Average of Last 10 Attempts`.
+#[derive(Clone, Debug)]
+pub struct RecentSegments {
+    statistic: Statistic,
+    attempts: u32,
+    days: u32,
+    segments: u32,
+    name: String,
+}
+
+impl RecentSegments {
+    /// Creates a new Recent Segments Comparison Generator that uses the
+    /// statistic provided. By default only the last 10 attempts are considered.
+    pub fn new(statistic: Statistic) -> Self {
+        let mut generator = Self {
+            statistic,
+            attempts: 10,
+            days: 0,
+            segments: 0,
+            name: String::new(),
+        };
+        generator.update_name();
+        generator
+    }
+
+    /// Accesses the statistic used for combining the segment times.
+    pub const fn statistic(&self) -> Statistic {
+        self.statistic
+    }
+
+    /// Accesses the number of most recent attempts that are considered. `0`
+    /// means that all the attempts are considered.
+    pub const fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Sets the number of most recent attempts that are considered. `0` means
+    /// that all the attempts are considered.
+    pub fn set_attempts(&mut self, attempts: u32) {
+        self.attempts = attempts;
+        self.update_name();
+    }
+
+    /// Accesses the number of days that attempts are considered for, counting
+    /// back from the end of the latest attempt. `0` means that there is no
+    /// limit. Attempts without a known starting time are never considered if
+    /// there is a limit.
+    pub const fn days(&self) -> u32 {
+        self.days
+    }
+
+    /// Sets the number of days that attempts are considered for, counting back
+    /// from the end of the latest attempt. `0` means that there is no limit.
+    /// Attempts without a known starting time are never considered if there is
+    /// a limit.
+    pub fn set_days(&mut self, days: u32) {
+        self.days = days;
+        self.update_name();
+    }
+
+    /// Accesses the number of most recent segment times that are considered
+    /// for each segment. `0` means that there is no limit.
+    pub const fn segments(&self) -> u32 {
+        self.segments
+    }
+
+    /// Sets the number of most recent segment times that are considered for
+    /// each segment. `0` means that there is no limit.
+    pub fn set_segments(&mut self, segments: u32) {
+        self.segments = segments;
+        self.update_name();
+    }
+
+    fn update_name(&mut self) {
+        let name = &mut self.name;
+        name.clear();
+        name.push_str(match self.statistic {
+            Statistic::Average => "Average of ",
+            Statistic::Median => "Median of ",
+        });
+
+        let limits = [
+            (self.attempts, "Attempt"),
+            (self.days, "Day"),
+            (self.segments, "Segment"),
+        ];
+        let mut is_first = true;
+        for (count, unit) in limits {
+            if count == 0 {
+                continue;
+            }
+            name.push_str(if is_first { "Last " } else { ", " });
+            is_first = false;
+            let _ = write!(name, "{count} {unit}");
+            if count != 1 {
+                name.push('s');
+            }
+        }
+        if is_first {
+            name.push_str("All Segments");
+        }
+    }
+
+    /// Collects the indices of the attempts that are within the window.
+    /// Returns `None` if all the attempts are within the window.
+    fn attempts_in_window(&self, attempts: &[Attempt]) -> Option<Vec<i32>> {
+        if self.attempts == 0 && self.days == 0 {
+            return None;
+        }
+
+        // The window ends with the latest attempt rather than now, so the
+        // comparison doesn't change while the splits aren't being used.
+        let latest = attempts
+            .iter()
+            .filter_map(|attempt| attempt.ended().or(attempt.started()))
+            .max_by_key(|date_time| date_time.time);
+        let max_age = TimeSpan::from_days(self.days as f64);
+
+        let mut ids: Vec<i32> = attempts
+            .iter()
+            .filter(|attempt| {
+                self.days == 0
+                    || catch! { latest? - attempt.started()? <= max_age }.unwrap_or(false)
+            })
+            .map(Attempt::index)
+            .collect();
+
+        ids.sort_unstable();
+        if self.attempts != 0 {
+            let skip = ids.len().saturating_sub(self.attempts as usize);
+            ids.drain(..skip);
+        }
+
+        Some(ids)
+    }
+}
+
+fn generate(
+    segments: &mut [Segment],
+    times: &mut Vec<TimeSpan>,
+    generator: &RecentSegments,
+    attempts: Option<&[i32]>,
+    method: TimingMethod,
+) {
+    let max_count = match generator.segments {
+        0 => usize::MAX,
+        count => count as usize,
+    };
+
+    let mut accumulated = Some(TimeSpan::zero());
+
+    let mut previous_segment: Option<&Segment> = None;
+    for segment in segments {
+        if let Some(accumulated_val) = &mut accumulated {
+            times.clear();
+
+            for &(id, time) in segment.segment_history().iter_actual_runs().rev() {
+                if times.len() >= max_count {
+                    break;
+                }
+
+                if attempts.is_some_and(|attempts| attempts.binary_search(&id).is_err()) {
+                    continue;
+                }
+
+                if let Some(time) = time[method] {
+                    // Skip all the combined segments
+                    let skip = catch! {
+                        previous_segment?.segment_history().get(id)?[method].is_none()
+                    }
+                    .unwrap_or(false);
+
+                    if !skip {
+                        times.push(time);
+                    }
+                }
+            }
+
+            if times.is_empty() {
+                accumulated = None;
+            } else {
+                *accumulated_val += match generator.statistic {
+                    Statistic::Average => {
+                        let total: f64 = times.iter().map(TimeSpan::total_seconds).sum();
+                        TimeSpan::from_seconds(total / times.len() as f64)
+                    }
+                    Statistic::Median => {
+                        times.sort_unstable();
+                        times[(times.len() - 1) / 2]
+                    }
+                };
+            }
+        }
+        segment.comparison_mut(&generator.name)[method] = accumulated;
+        previous_segment = Some(&*segment);
+    }
+}
+
+impl ComparisonGenerator for RecentSegments {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        match self.statistic {
+            Statistic::Average => AVERAGE_KIND,
+            Statistic::Median => MEDIAN_KIND,
+        }
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let attempts = self.attempts_in_window(attempts);
+        let attempts = attempts.as_deref();
+        let times = &mut Vec::new();
+        generate(segments, times, self, attempts, TimingMethod::RealTime);
+        generate(segments, times, self, attempts, TimingMethod::GameTime);
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Last Attempts".into(), u64::from(self.attempts).into()),
+            Field::new("Last Days".into(), u64::from(self.days).into()),
+            Field::new("Last Segments".into(), u64::from(self.segments).into()),
+        ])
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        let value = value.into_uint().unwrap();
+        let value = value.try_into().unwrap_or(u32::MAX);
+        match index {
+            0 => self.set_attempts(value),
+            1 => self.set_days(value),
+            2 => self.set_segments(value),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,231 @@
+//! Provides a registry of all the kinds of Comparison Generators that are
+//! known. The registry is used for recreating Comparison Generators from their
+//! kind and settings, which is what happens when a [`Run`](crate::Run) is being
+//! loaded. User-provided Comparison Generators can be registered at runtime.
+
+use super::{
+    AverageSegments, BalancedPB, BestSegments, BestSplitTimes, ComparisonGenerator, LatestRun,
+    LinkedComparison, MedianSegments, None, Percentile, RecentSegments, WorstSegments,
+};
+use crate::{
+    platform::prelude::*,
+    settings::{Field, SettingsDescription, Value},
+    Attempt, Segment,
+};
+use core::{fmt, mem::discriminant};
+
+type Constructor = Box<dyn Fn() -> Box<dyn ComparisonGenerator> + Send + Sync>;
+
+/// A registry of all the kinds of Comparison Generators that are known. Each
+/// kind is associated with a function that creates a new Comparison Generator
+/// of that kind with its default settings.
+///
+/// # Examples
+///
+/// ```
+/// use livesplit_core::comparison::{Registry, BestSegments};
+///
+/// let mut registry = Registry::new();
+/// registry.register("My Best Segments", || Box::new(BestSegments));
+///
+/// assert!(registry.create("Median Segments").is_some());
+/// assert!(registry.create("My Best Segments").is_some());
+/// assert!(registry.create("Unknown").is_none());
+/// ```
+pub struct Registry {
+    entries: Vec<(String, Constructor)>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.kinds()).finish()
+    }
+}
+
+impl Registry {
+    /// Creates a new registry that knows about all the Comparison Generators
+    /// provided by this crate.
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register(super::best_segments::NAME, || Box::new(BestSegments));
+        registry.register(super::best_split_times::NAME, || Box::new(BestSplitTimes));
+        registry.register(super::average_segments::NAME, || Box::new(AverageSegments));
+        registry.register(super::median_segments::NAME, || Box::new(MedianSegments));
+        registry.register(super::worst_segments::NAME, || Box::new(WorstSegments));
+        registry.register(super::balanced_pb::NAME, || Box::new(BalancedPB));
+        registry.register(super::latest_run::NAME, || Box::new(LatestRun));
+        registry.register(super::linked::KIND, || Box::<LinkedComparison>::default());
+        registry.register(super::none::NAME, || Box::new(None));
+        registry.register(super::percentile::KIND, || Box::<Percentile>::default());
+        registry.register(super::recent_segments::AVERAGE_KIND, || {
+            Box::new(RecentSegments::new(
+                super::recent_segments::Statistic::Average,
+            ))
+        });
+        registry.register(super::recent_segments::MEDIAN_KIND, || {
+            Box::new(RecentSegments::new(
+                super::recent_segments::Statistic::Median,
+            ))
+        });
+        registry
+    }
+
+    /// Creates a new registry that doesn't know about any Comparison
+    /// Generators.
+    pub const fn empty() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Registers a kind of Comparison Generator. The function provided creates
+    /// a new Comparison Generator of that kind with its default settings. If
+    /// the kind is already registered, it gets replaced.
+    pub fn register<S, F>(&mut self, kind: S, constructor: F)
+    where
+        S: Into<String>,
+        F: Fn() -> Box<dyn ComparisonGenerator> + Send + Sync + 'static,
+    {
+        let kind = kind.into();
+        let constructor = Box::new(constructor);
+        if let Some((_, existing)) = self.entries.iter_mut().find(|(k, _)| *k == kind) {
+            *existing = constructor;
+        } else {
+            self.entries.push((kind, constructor));
+        }
+    }
+
+    /// Returns whether the kind of Comparison Generator is registered.
+    pub fn contains(&self, kind: &str) -> bool {
+        self.entries.iter().any(|(k, _)| k == kind)
+    }
+
+    /// Iterates over all the kinds of Comparison Generators that are
+    /// registered.
+    pub fn kinds(&self) -> impl Iterator<Item = &str> + '_ {
+        self.entries.iter().map(|(kind, _)| kind.as_str())
+    }
+
+    /// Creates a new Comparison Generator of the kind specified with its
+    /// default settings. Returns `None` if the kind is not registered.
+    pub fn create(&self, kind: &str) -> Option<Box<dyn ComparisonGenerator>> {
+        let (_, constructor) = self.entries.iter().find(|(k, _)| k == kind)?;
+        Some(constructor())
+    }
+
+    /// Creates a new Comparison Generator of the kind specified and applies the
+    /// settings provided to it. The settings are applied in order of their
+    /// index. Settings that don't exist or that don't match the type of the
+    /// setting's value are ignored. Returns `None` if the kind is not
+    /// registered.
+    pub fn create_with_settings(
+        &self,
+        kind: &str,
+        settings: impl IntoIterator<Item = Value>,
+    ) -> Option<Box<dyn ComparisonGenerator>> {
+        let mut generator = self.create(kind)?;
+        apply_settings(&mut *generator, settings);
+        Some(generator)
+    }
+
+    /// Recreates the Comparison Generator provided from this registry, keeping
+    /// its current settings. This is mostly useful for resolving
+    /// [`Unresolved`] Comparison Generators once their kind got registered.
+    /// Returns `None` if the kind is not registered.
+    pub fn recreate(
+        &self,
+        generator: &dyn ComparisonGenerator,
+    ) -> Option<Box<dyn ComparisonGenerator>> {
+        self.create_with_settings(
+            generator.kind(),
+            generator
+                .settings_description()
+                .fields
+                .into_iter()
+                .map(|field| field.value),
+        )
+    }
+}
+
+/// Applies the settings provided to the Comparison Generator in order of their
+/// index. Settings that don't exist or that don't match the type of the
+/// setting's value are ignored.
+pub fn apply_settings(
+    generator: &mut dyn ComparisonGenerator,
+    settings: impl IntoIterator<Item = Value>,
+) {
+    let fields = generator.settings_description().fields;
+    for (index, (value, field)) in settings.into_iter().zip(&*fields).enumerate() {
+        if discriminant(&field.value) == discriminant(&value) {
+            generator.set_value(index, value);
+        }
+    }
+}
+
+/// A placeholder for a Comparison Generator whose kind is not known by the
+/// [`Registry`] that was used while loading the [`Run`](crate::Run). It keeps
+/// the name, kind and settings of the Comparison Generator, so that it doesn't
+/// get lost when saving the [`Run`](crate::Run) again. It doesn't generate any
+/// times by itself. Use [`Run::resolve_comparison_generators`] once the kind is
+/// registered to replace it with the actual Comparison Generator.
+///
+/// [`Run::resolve_comparison_generators`]: crate::Run::resolve_comparison_generators
+#[derive(Clone)]
+pub struct Unresolved {
+    kind: String,
+    name: String,
+    settings: Vec<Value>,
+}
+
+impl fmt::Debug for Unresolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Unresolved")
+            .field("kind", &self.kind)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Unresolved {
+    /// Creates a new placeholder for a Comparison Generator of the kind
+    /// specified that generates the comparison with the name specified.
+    pub const fn new(kind: String, name: String, settings: Vec<Value>) -> Self {
+        Self {
+            kind,
+            name,
+            settings,
+        }
+    }
+}
+
+impl ComparisonGenerator for Unresolved {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    fn generate(&mut self, _: &mut [Segment], _: &[Attempt]) {}
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(
+            self.settings
+                .iter()
+                .enumerate()
+                .map(|(index, value)| Field::new(format!("Setting {}", index + 1), value.clone()))
+                .collect(),
+        )
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        self.settings[index] = value;
+    }
+}
@@ -0,0 +1,64 @@
+use crate::comparison::LinkedComparison;
+use crate::run::{parser, saver};
+use crate::{platform::prelude::*, Run, Segment, Time, TimeSpan};
+
+fn run(names: &[&str], split_times: &[f64]) -> Run {
+    let mut run = Run::new();
+    for (&name, &time) in names.iter().zip(split_times) {
+        let mut segment = Segment::new(name);
+        segment.set_personal_best_split_time(
+            Time::new().with_real_time(Some(TimeSpan::from_seconds(time))),
+        );
+        run.push_segment(segment);
+    }
+    run
+}
+
+fn comparison_times(run: &Run, comparison: &str) -> Vec<Option<f64>> {
+    run.segments()
+        .iter()
+        .map(|s| {
+            s.comparison(comparison)
+                .real_time
+                .map(|t| t.total_seconds())
+        })
+        .collect()
+}
+
+#[test]
+fn splits_get_aligned_by_name() {
+    let mut my_run = run(&["A", "B", "C", "D"], &[1.0, 2.0, 3.0, 4.0]);
+    let source = run(&["a", "X", "C", "End"], &[10.0, 15.0, 30.0, 40.0]);
+
+    let mut linked = LinkedComparison::new("WR".into(), "wr.lss".into());
+    linked.set_source(&source);
+    my_run.add_comparison_generator(Box::new(linked)).unwrap();
+    my_run.regenerate_comparisons();
+
+    assert_eq!(
+        comparison_times(&my_run, "WR"),
+        [Some(10.0), None, Some(30.0), Some(40.0)]
+    );
+}
+
+#[test]
+fn refreshing_after_loading_works() {
+    let mut my_run = run(&["A", "B"], &[1.0, 2.0]);
+    let mut linked = LinkedComparison::new("WR".into(), "wr.lss".into());
+    linked.set_source(&run(&["A", "B"], &[5.0, 6.0]));
+    my_run.add_comparison_generator(Box::new(linked)).unwrap();
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&my_run, &mut buf).unwrap();
+    let mut parsed = parser::livesplit::parse(&buf).unwrap();
+    assert_eq!(comparison_times(&parsed, "WR"), [None, None]);
+
+    let mut paths = Vec::new();
+    parsed.refresh_linked_comparisons(|path| {
+        paths.push(path.to_owned());
+        Some(run(&["A", "B"], &[7.0, 8.0]))
+    });
+    assert_eq!(paths, ["wr.lss"]);
+    assert_eq!(parsed.comparison_generators().last().unwrap().name(), "WR");
+    assert_eq!(comparison_times(&parsed, "WR"), [Some(7.0), Some(8.0)]);
+}
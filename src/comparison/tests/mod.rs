@@ -1,4 +1,8 @@
 mod average;
 mod balanced_pb;
 mod empty;
+mod linked;
 mod median;
+mod percentile;
+mod recent;
+mod registry;
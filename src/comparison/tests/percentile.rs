@@ -0,0 +1,55 @@
+use crate::comparison::{percentile::Percentile, ComparisonGenerator};
+use crate::util::tests_helper::run_with_splits;
+use crate::{Run, Segment, TimeSpan, Timer};
+
+fn timer_with(percentile: u32) -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut()
+        .push(Box::new(Percentile::new(percentile)));
+    let mut timer = Timer::new(run).unwrap();
+
+    run_with_splits(&mut timer, &[1.0, 4.0]);
+    run_with_splits(&mut timer, &[3.0, 5.0]);
+    run_with_splits(&mut timer, &[2.0, 7.0]);
+
+    timer
+}
+
+fn final_time(timer: &Timer) -> Option<TimeSpan> {
+    let name = timer.run().comparison_generators()[0].name();
+    timer.run().segment(1).comparison(name).game_time
+}
+
+#[test]
+fn names() {
+    assert_eq!(Percentile::new(10).name(), "P10");
+    assert_eq!(Percentile::new(150).name(), "P100");
+}
+
+#[test]
+fn lowest_percentile_is_sum_of_best() {
+    assert_eq!(
+        final_time(&timer_with(0)),
+        Some(TimeSpan::from_seconds(3.0))
+    );
+}
+
+#[test]
+fn highest_percentile_is_sum_of_worst() {
+    assert_eq!(
+        final_time(&timer_with(100)),
+        Some(TimeSpan::from_seconds(8.0))
+    );
+}
+
+#[test]
+fn percentiles_are_ordered() {
+    let p25 = final_time(&timer_with(25)).unwrap();
+    let p75 = final_time(&timer_with(75)).unwrap();
+    assert!(p25 > TimeSpan::from_seconds(3.0));
+    assert!(p25 < p75);
+    assert!(p75 < TimeSpan::from_seconds(8.0));
+}
@@ -0,0 +1,130 @@
+use crate::comparison::{
+    recent_segments::{RecentSegments, Statistic},
+    ComparisonGenerator,
+};
+use crate::util::tests_helper::{run_with_splits, run_with_splits_opt};
+use crate::{platform::DateTime, AtomicDateTime, Run, Segment, Time, TimeSpan, Timer};
+
+fn timer_with(generator: RecentSegments) -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut().push(Box::new(generator));
+    Timer::new(run).unwrap()
+}
+
+fn comparison(timer: &Timer, index: usize) -> Option<TimeSpan> {
+    let name = timer.run().comparison_generators()[0].name();
+    timer.run().segment(index).comparison(name).game_time
+}
+
+#[test]
+fn names() {
+    let mut generator = RecentSegments::new(Statistic::Average);
+    assert_eq!(generator.name(), "Average of Last 10 Attempts");
+    generator.set_days(1);
+    assert_eq!(generator.name(), "Average of Last 10 Attempts, 1 Day");
+    generator.set_attempts(0);
+    generator.set_days(0);
+    assert_eq!(generator.name(), "Average of All Segments");
+
+    let mut generator = RecentSegments::new(Statistic::Median);
+    generator.set_attempts(0);
+    generator.set_segments(5);
+    assert_eq!(generator.name(), "Median of Last 5 Segments");
+}
+
+#[test]
+fn only_the_last_attempts_are_considered() {
+    let mut generator = RecentSegments::new(Statistic::Average);
+    generator.set_attempts(2);
+    let mut timer = timer_with(generator);
+
+    run_with_splits(&mut timer, &[1.0, 11.0]);
+    run_with_splits(&mut timer, &[2.0, 12.0]);
+    run_with_splits(&mut timer, &[4.0, 14.0]);
+
+    assert_eq!(comparison(&timer, 0), Some(TimeSpan::from_seconds(3.0)));
+    assert_eq!(comparison(&timer, 1), Some(TimeSpan::from_seconds(13.0)));
+}
+
+#[test]
+fn resets_count_as_attempts() {
+    let mut generator = RecentSegments::new(Statistic::Average);
+    generator.set_attempts(2);
+    let mut timer = timer_with(generator);
+
+    run_with_splits(&mut timer, &[1.0, 11.0]);
+    run_with_splits(&mut timer, &[2.0]);
+    run_with_splits_opt(&mut timer, &[]);
+
+    assert_eq!(comparison(&timer, 0), Some(TimeSpan::from_seconds(2.0)));
+    assert_eq!(comparison(&timer, 1), None);
+}
+
+#[test]
+fn only_the_last_segments_are_considered() {
+    let mut generator = RecentSegments::new(Statistic::Median);
+    generator.set_attempts(0);
+    generator.set_segments(3);
+    let mut timer = timer_with(generator);
+
+    run_with_splits(&mut timer, &[1.0]);
+    run_with_splits(&mut timer, &[5.0]);
+    run_with_splits(&mut timer, &[2.0]);
+    run_with_splits_opt(&mut timer, &[]);
+    run_with_splits(&mut timer, &[9.0]);
+
+    assert_eq!(comparison(&timer, 0), Some(TimeSpan::from_seconds(5.0)));
+}
+
+#[test]
+fn recent_days_are_considered() {
+    const DAY: i64 = 24 * 60 * 60;
+    let date_time = |seconds| {
+        Some(AtomicDateTime::new(
+            DateTime::from_unix_timestamp(seconds).unwrap(),
+            false,
+        ))
+    };
+
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    for (index, (started, time)) in [(0, 1.0), (3 * DAY, 3.0), (3 * DAY + 60, 5.0)]
+        .into_iter()
+        .enumerate()
+    {
+        let index = index as i32 + 1;
+        let time = Time::new().with_game_time(Some(TimeSpan::from_seconds(time)));
+        run.add_attempt_with_index(
+            time,
+            index,
+            date_time(started),
+            date_time(started + 30),
+            None,
+        );
+        run.segment_mut(0).segment_history_mut().insert(index, time);
+    }
+
+    let mut generator = RecentSegments::new(Statistic::Average);
+    generator.set_attempts(0);
+    generator.set_days(1);
+    let attempts = run.attempt_history().to_vec();
+    generator.generate(run.segments_mut(), &attempts);
+
+    // The attempt from three days before the latest one is outside the window,
+    // regardless of how long ago the latest attempt was.
+    let name = generator.name();
+    assert_eq!(
+        run.segment(0).comparison(name).game_time,
+        Some(TimeSpan::from_seconds(4.0))
+    );
+}
+
+#[test]
+fn large_limits_get_clamped() {
+    let mut generator = RecentSegments::new(Statistic::Average);
+    generator.set_value(0, u64::MAX.into());
+    assert_eq!(generator.attempts(), u32::MAX);
+}
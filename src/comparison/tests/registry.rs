@@ -0,0 +1,160 @@
+use crate::{
+    comparison::{ComparisonGenerator, Registry},
+    platform::prelude::*,
+    run::{parser, saver, ComparisonError, Editor},
+    settings::{Field, SettingsDescription, Value},
+    Attempt, Run, Segment,
+};
+
+#[derive(Clone, Debug)]
+struct Configurable {
+    name: String,
+    count: u64,
+}
+
+impl Default for Configurable {
+    fn default() -> Self {
+        Self {
+            name: "Last 10".into(),
+            count: 10,
+        }
+    }
+}
+
+impl ComparisonGenerator for Configurable {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn kind(&self) -> &str {
+        "Configurable"
+    }
+
+    fn generate(&mut self, _: &mut [Segment], _: &[Attempt]) {}
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![Field::new("Count".into(), self.count.into())])
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => {
+                self.count = value.into_uint().unwrap();
+                self.name = format!("Last {}", self.count);
+            }
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register("Configurable", || Box::<Configurable>::default());
+    registry
+}
+
+fn run_with_configurable() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    let generator = registry()
+        .create_with_settings("Configurable", [Value::UInt(5)])
+        .unwrap();
+    run.add_comparison_generator(generator).unwrap();
+    run
+}
+
+fn save(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::livesplit::save_run(run, &mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn settings_get_applied_when_creating() {
+    let generator = registry()
+        .create_with_settings("Configurable", [Value::UInt(5)])
+        .unwrap();
+    assert_eq!(generator.name(), "Last 5");
+}
+
+#[test]
+fn mismatched_settings_get_ignored() {
+    let generator = registry()
+        .create_with_settings("Configurable", [Value::Bool(true), Value::UInt(3)])
+        .unwrap();
+    assert_eq!(generator.name(), "Last 10");
+}
+
+#[test]
+fn default_generators_are_not_saved() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    assert!(!save(&run).contains("ComparisonGenerators"));
+}
+
+#[test]
+fn unknown_generators_survive_a_roundtrip() {
+    let run = run_with_configurable();
+    let saved = save(&run);
+
+    let parsed = parser::livesplit::parse(&saved).unwrap();
+    let generator = parsed.comparison_generators().last().unwrap();
+    assert_eq!(generator.kind(), "Configurable");
+    assert_eq!(generator.name(), "Last 5");
+    assert!(parsed.comparisons().any(|c| c == "Last 5"));
+
+    assert_eq!(save(&parsed), saved);
+}
+
+#[test]
+fn unknown_generators_can_be_resolved() {
+    let run = run_with_configurable();
+    let mut parsed = parser::livesplit::parse(&save(&run)).unwrap();
+    parsed.resolve_comparison_generators(&registry());
+
+    let generator = parsed.comparison_generators().last().unwrap();
+    assert_eq!(generator.name(), "Last 5");
+    assert_eq!(generator.settings_description().fields[0].text, "Count");
+}
+
+#[test]
+fn registered_generators_get_parsed() {
+    let saved = save(&run_with_configurable());
+    let parsed = parser::livesplit::parse_with_registry(&saved, &registry()).unwrap();
+
+    let generator = parsed.comparison_generators().last().unwrap();
+    assert_eq!(generator.name(), "Last 5");
+    assert_eq!(generator.settings_description().fields[0].text, "Count");
+
+    let parsed =
+        parser::composite::parse_with_registry(saved.as_bytes(), None, &registry()).unwrap();
+    let generator = parsed.run.comparison_generators().last().unwrap();
+    assert_eq!(generator.settings_description().fields[0].text, "Count");
+}
+
+#[test]
+fn generators_without_settings_ignore_values() {
+    let mut generator = Registry::new().create("Best Segments").unwrap();
+    generator.set_value(0, Value::UInt(5));
+    assert!(generator.settings_description().fields.is_empty());
+}
+
+#[test]
+fn renaming_generated_comparisons_gets_validated() {
+    let mut run = run_with_configurable();
+    run.add_custom_comparison("Last 7").unwrap();
+    let mut editor = Editor::new(run).unwrap();
+    let index = editor.run().comparison_generators().len() - 1;
+
+    assert_eq!(
+        editor.set_comparison_generator_value(index, 0, Value::UInt(7)),
+        Err(ComparisonError::DuplicateName)
+    );
+    assert_eq!(editor.run().comparison_generators()[index].name(), "Last 5");
+
+    editor
+        .set_comparison_generator_value(index, 0, Value::UInt(8))
+        .unwrap();
+    assert!(editor.run().comparisons().any(|c| c == "Last 8"));
+    assert!(!editor.run().comparisons().any(|c| c == "Last 5"));
+}
@@ -0,0 +1,308 @@
+//! Provides the Attempt Counter Component and relevant types for using it. The
+//! Attempt Counter Component is a component that shows how many attempts were
+//! started during the current session, today or overall, how many of them
+//! were completed, or how often the run got reset since the last personal best.
+
+use super::key_value;
+use crate::{
+    analysis::sessions::CurrentSession,
+    platform::{prelude::*, to_local, RwLock},
+    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    AtomicDateTime, Run, TimeSpan, Timer, TimerPhase, TimingMethod,
+};
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+use time::Date;
+
+#[cfg(test)]
+mod tests;
+
+/// The Attempt Counter Component is a component that shows how many attempts
+/// were started during the current session, today or overall, how many of
+/// them were completed, or how often the run got reset since the last personal
+/// best. The attempts are counted based on the attempt history and the times
+/// they were started at. The current attempt is included in the counts of
+/// started and completed attempts. It is not counted as a reset, as it didn't
+/// get reset yet.
+pub struct Component {
+    settings: Settings,
+    cache: RwLock<Cache>,
+}
+
+/// Caches the counts that require analyzing the whole attempt history, so
+/// that they only need to be determined again when the history changes.
+struct Cache {
+    current_session: CurrentSession,
+    attempts_today: Option<(usize, Date, u32)>,
+}
+
+/// Specifies which count the Attempt Counter Component shows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AttemptStatistic {
+    /// The number of attempts started during the current session.
+    Session,
+    /// The number of attempts started today in local time.
+    Today,
+    /// The total number of attempts.
+    Total,
+    /// The percentage of all the attempts that were completed.
+    CompletionPercentage,
+    /// The number of attempts that were reset since the last time a new
+    /// personal best was achieved. The current attempt is not included, as it
+    /// didn't get reset yet.
+    ResetsSinceLastPb,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The count to show.
+    pub statistic: AttemptStatistic,
+    /// The label shown for the count. If `None` is specified, a label
+    /// describing the count is used.
+    pub label: Option<String>,
+    /// The longest gap in minutes between two attempts for them to still be
+    /// considered part of the same session. This is only used if no sessions
+    /// were explicitly declared.
+    pub session_gap: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            statistic: AttemptStatistic::Session,
+            label: None,
+            session_gap: 30,
+        }
+    }
+}
+
+impl Default for Component {
+    fn default() -> Self {
+        Self::with_settings(Default::default())
+    }
+}
+
+impl Clone for Component {
+    fn clone(&self) -> Self {
+        Self::with_settings(self.settings.clone())
+    }
+}
+
+impl Component {
+    /// Creates a new Attempt Counter Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Attempt Counter Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            cache: RwLock::new(Cache {
+                current_session: CurrentSession::new(),
+                attempts_today: None,
+            }),
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Attempt Counter"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
+        let run = timer.run();
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = SemanticColor::Default;
+
+        let (key, abbreviation) = match self.settings.statistic {
+            AttemptStatistic::Session => ("Session Attempts", "Session"),
+            AttemptStatistic::Today => ("Attempts Today", "Today"),
+            AttemptStatistic::Total => ("Total Attempts", "Attempts"),
+            AttemptStatistic::CompletionPercentage => ("Completion Rate", "Completed"),
+            AttemptStatistic::ResetsSinceLastPb => ("Resets Since PB", "Resets"),
+        };
+
+        state.key.clear();
+        state.key_abbreviations.clear();
+        if let Some(label) = &self.settings.label {
+            state.key.push_str(label);
+        } else {
+            state.key.push_str(key);
+            state.key_abbreviations.push(abbreviation.into());
+        }
+
+        state.value.clear();
+        let _ = match self.settings.statistic {
+            AttemptStatistic::Session => {
+                let session = self.cache.write().unwrap().current_session.calculate(
+                    timer,
+                    TimeSpan::from_seconds(60.0 * self.settings.session_gap as f64),
+                );
+                write!(state.value, "{}", session.map_or(0, |s| s.attempts))
+            }
+            AttemptStatistic::Today => write!(
+                state.value,
+                "{}",
+                attempts_today(&mut self.cache.write().unwrap(), timer)
+            ),
+            AttemptStatistic::Total => write!(state.value, "{}", run.attempt_count()),
+            AttemptStatistic::CompletionPercentage => {
+                let attempts = run.attempt_count();
+                if attempts == 0 {
+                    write!(state.value, "0.0%")
+                } else {
+                    let finished = finished_attempts(timer);
+                    write!(
+                        state.value,
+                        "{:.1}%",
+                        100.0 * finished as f64 / attempts as f64
+                    )
+                }
+            }
+            AttemptStatistic::ResetsSinceLastPb => write!(
+                state.value,
+                "{}",
+                resets_since_last_pb(run, timer.current_timing_method())
+            ),
+        };
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = false;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Statistic".into(), self.settings.statistic.into()),
+            Field::new("Label".into(), self.settings.label.clone().into()),
+            Field::new(
+                "Session Gap (Minutes)".into(),
+                self.settings.session_gap.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.statistic = value.into(),
+            5 => self.settings.label = value.into(),
+            6 => self.settings.session_gap = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn attempts_today(cache: &mut Cache, timer: &Timer) -> u32 {
+    let now = AtomicDateTime::now().time;
+    let today = to_local(now).date();
+    let attempts = timer.run().attempt_history();
+
+    let history = match cache.attempts_today {
+        Some((len, date, count)) if len == attempts.len() && date == today => count,
+        _ => {
+            let count = attempts
+                .iter()
+                .filter(|attempt| {
+                    attempt
+                        .started()
+                        .is_some_and(|started| to_local(started.time).date() == today)
+                })
+                .count() as u32;
+            cache.attempts_today = Some((attempts.len(), today, count));
+            count
+        }
+    };
+
+    let current_started = now - timer.current_attempt_duration().to_duration();
+    let current = timer.current_phase() != TimerPhase::NotRunning
+        && to_local(current_started).date() == today;
+
+    history + current as u32
+}
+
+fn finished_attempts(timer: &Timer) -> u32 {
+    let history = timer
+        .run()
+        .attempt_history()
+        .iter()
+        .filter(|attempt| attempt.time().real_time.is_some())
+        .count() as u32;
+
+    history + (timer.current_phase() == TimerPhase::Ended) as u32
+}
+
+fn resets_since_last_pb(run: &Run, method: TimingMethod) -> u32 {
+    let mut best_time = None;
+    let mut resets = 0;
+
+    for attempt in run.attempt_history() {
+        if attempt.time().real_time.is_none() {
+            resets += 1;
+        } else if let Some(time) = attempt.time()[method] {
+            if best_time.map_or(true, |best| time < best) {
+                best_time = Some(time);
+                resets = 0;
+            }
+        }
+    }
+
+    resets
+}
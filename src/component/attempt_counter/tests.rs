@@ -0,0 +1,102 @@
+use super::{AttemptStatistic, Component};
+use crate::{
+    platform::prelude::*,
+    util::tests_helper::{create_timer, run_with_splits, run_with_splits_opt, start_run},
+    Timer,
+};
+
+fn timer_with_attempts() -> Timer {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits_opt(&mut timer, &[Some(5.0)]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+    run_with_splits_opt(&mut timer, &[Some(5.0)]);
+    timer
+}
+
+fn value(component: &mut Component, statistic: AttemptStatistic, timer: &Timer) -> String {
+    component.settings_mut().statistic = statistic;
+    component.state(timer).value
+}
+
+#[test]
+fn counts_the_attempts() {
+    let mut timer = timer_with_attempts();
+    let mut component = Component::new();
+
+    assert_eq!(value(&mut component, AttemptStatistic::Total, &timer), "4");
+    assert_eq!(value(&mut component, AttemptStatistic::Today, &timer), "4");
+    assert_eq!(
+        value(&mut component, AttemptStatistic::Session, &timer),
+        "4"
+    );
+    assert_eq!(
+        value(
+            &mut component,
+            AttemptStatistic::CompletionPercentage,
+            &timer
+        ),
+        "50.0%"
+    );
+
+    // The current attempt is counted as well.
+    start_run(&mut timer);
+    assert_eq!(value(&mut component, AttemptStatistic::Total, &timer), "5");
+    assert_eq!(value(&mut component, AttemptStatistic::Today, &timer), "5");
+    assert_eq!(
+        value(&mut component, AttemptStatistic::Session, &timer),
+        "5"
+    );
+    assert_eq!(
+        value(
+            &mut component,
+            AttemptStatistic::CompletionPercentage,
+            &timer
+        ),
+        "40.0%"
+    );
+}
+
+#[test]
+fn counts_the_resets_since_the_last_pb() {
+    let mut timer = timer_with_attempts();
+    let mut component = Component::new();
+
+    assert_eq!(
+        value(&mut component, AttemptStatistic::ResetsSinceLastPb, &timer),
+        "2"
+    );
+
+    // The current attempt didn't get reset yet.
+    start_run(&mut timer);
+    assert_eq!(
+        value(&mut component, AttemptStatistic::ResetsSinceLastPb, &timer),
+        "2"
+    );
+    timer.reset(true);
+    assert_eq!(
+        value(&mut component, AttemptStatistic::ResetsSinceLastPb, &timer),
+        "3"
+    );
+
+    run_with_splits(&mut timer, &[10.0, 15.0]);
+    assert_eq!(
+        value(&mut component, AttemptStatistic::ResetsSinceLastPb, &timer),
+        "0"
+    );
+}
+
+#[test]
+fn uses_the_configured_label() {
+    let timer = timer_with_attempts();
+    let mut component = Component::new();
+
+    let state = component.state(&timer);
+    assert_eq!(state.key, "Session Attempts");
+    assert_eq!(state.key_abbreviations, ["Session"]);
+
+    component.settings_mut().label = Some(String::from("Tries"));
+    let state = component.state(&timer);
+    assert_eq!(state.key, "Tries");
+    assert!(state.key_abbreviations.is_empty());
+}
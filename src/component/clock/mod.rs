@@ -0,0 +1,344 @@
+//! Provides the Clock Component and relevant types for using it. The Clock
+//! Component is a component that shows the current time of day or date, or how
+//! much time has passed since the current session or a stream started.
+
+use super::key_value;
+use crate::{
+    analysis::sessions::CurrentSession,
+    platform::{prelude::*, to_local},
+    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    timing::formatter::DASH,
+    AtomicDateTime, DateTime, TimeSpan, Timer,
+};
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+
+#[cfg(test)]
+mod tests;
+
+/// The Clock Component is a component that shows the current time of day or
+/// date, or how much time has passed since the current session or a stream
+/// started. The time of day and the date are shown either in the local time
+/// zone or at a fixed offset from UTC.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+    first_shown: Option<AtomicDateTime>,
+    current_session: CurrentSession,
+}
+
+/// Specifies what the Clock Component shows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClockMode {
+    /// The current time of day.
+    Time,
+    /// The current date and time of day.
+    DateTime,
+    /// The time that has passed since the current session started.
+    SessionTime,
+    /// The time that has passed since the uptime start specified in the
+    /// settings. If there is none, the time that has passed since the
+    /// component was first shown is used instead.
+    Uptime,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// What the component shows.
+    pub mode: ClockMode,
+    /// Specifies whether the time of day is shown in the 24-hour format
+    /// rather than the 12-hour format with an AM / PM suffix.
+    pub use_24_hour_format: bool,
+    /// Specifies whether the seconds are shown.
+    pub show_seconds: bool,
+    /// Specifies whether the time of day and the date are shown in the local
+    /// time zone. Otherwise the UTC offset is used.
+    pub use_local_time_zone: bool,
+    /// The offset from UTC in minutes that the time of day and the date are
+    /// shown at if the local time zone is not used.
+    pub utc_offset: i64,
+    /// The point in time the uptime is measured from, in the form
+    /// `YYYY-MM-DD HH:MM` or `YYYY-MM-DD HH:MM:SS`. It is interpreted in the
+    /// same time zone as the time of day. If `None` is specified or it can't
+    /// be parsed, the uptime is measured from when the component was first
+    /// shown.
+    pub uptime_start: Option<String>,
+    /// The longest gap in minutes between two attempts for them to still be
+    /// considered part of the same session. This is only used if no sessions
+    /// were explicitly declared.
+    pub session_gap: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            mode: ClockMode::Time,
+            use_24_hour_format: true,
+            show_seconds: true,
+            use_local_time_zone: true,
+            utc_offset: 0,
+            uptime_start: None,
+            session_gap: 30,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Clock Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Clock Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            first_shown: None,
+            current_session: CurrentSession::new(),
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Clock"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&mut self, state: &mut key_value::State, timer: &Timer) {
+        let now = AtomicDateTime::now();
+        let first_shown = *self.first_shown.get_or_insert(now);
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = SemanticColor::Default;
+
+        let (key, abbreviation) = match self.settings.mode {
+            ClockMode::Time => ("Current Time", "Time"),
+            ClockMode::DateTime => ("Current Date", "Date"),
+            ClockMode::SessionTime => ("Session Time", "Session"),
+            ClockMode::Uptime => ("Uptime", "Uptime"),
+        };
+
+        state.key.clear();
+        state.key.push_str(key);
+
+        state.value.clear();
+        match self.settings.mode {
+            ClockMode::Time => {
+                let now = self.to_time_zone(now.time);
+                self.write_time_of_day(&mut state.value, now.time());
+            }
+            ClockMode::DateTime => {
+                let now = self.to_time_zone(now.time);
+                write_date(&mut state.value, now.date());
+                state.value.push(' ');
+                self.write_time_of_day(&mut state.value, now.time());
+            }
+            ClockMode::SessionTime => {
+                let session = self.current_session.calculate(
+                    timer,
+                    TimeSpan::from_seconds(60.0 * self.settings.session_gap as f64),
+                );
+                if let Some(session) = session {
+                    self.write_elapsed(&mut state.value, now - session.started);
+                } else {
+                    state.value.push_str(DASH);
+                }
+            }
+            ClockMode::Uptime => {
+                let start = self
+                    .settings
+                    .uptime_start
+                    .as_deref()
+                    .and_then(|start| self.parse_uptime_start(start))
+                    .unwrap_or(first_shown.time);
+                self.write_elapsed(&mut state.value, now - start);
+            }
+        }
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push(abbreviation.into());
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = true;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&mut self, timer: &Timer) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    fn to_time_zone(&self, date_time: DateTime) -> DateTime {
+        if self.settings.use_local_time_zone {
+            to_local(date_time)
+        } else {
+            let offset = i32::try_from(self.settings.utc_offset.saturating_mul(60))
+                .ok()
+                .and_then(|seconds| UtcOffset::from_whole_seconds(seconds).ok())
+                .unwrap_or(UtcOffset::UTC);
+            date_time.to_offset(offset)
+        }
+    }
+
+    fn parse_uptime_start(&self, text: &str) -> Option<DateTime> {
+        let (date, time) = text.trim().split_once(' ')?;
+
+        let mut date = date.splitn(3, '-');
+        let date = Date::from_calendar_date(
+            date.next()?.parse().ok()?,
+            Month::try_from(date.next()?.parse::<u8>().ok()?).ok()?,
+            date.next()?.parse().ok()?,
+        )
+        .ok()?;
+
+        let mut time = time.trim().splitn(3, ':');
+        let time = Time::from_hms(
+            time.next()?.parse().ok()?,
+            time.next()?.parse().ok()?,
+            time.next().map_or(Some(0), |s| s.parse().ok())?,
+        )
+        .ok()?;
+
+        // The offset needs to be the one in effect at the start itself, which
+        // differs from the current one if there's a daylight saving time
+        // change in between. As the start isn't known before its offset is,
+        // it gets approximated with the offset at the same time in UTC first.
+        let date_time = PrimitiveDateTime::new(date, time);
+        let approximation = self.to_time_zone(date_time.assume_utc()).offset();
+        let approximation = date_time.assume_offset(approximation);
+        Some(date_time.assume_offset(self.to_time_zone(approximation).offset()))
+    }
+
+    fn write_time_of_day(&self, buf: &mut String, time: Time) {
+        let _ = if self.settings.use_24_hour_format {
+            write!(buf, "{:02}:{:02}", time.hour(), time.minute())
+        } else {
+            let hour = match time.hour() % 12 {
+                0 => 12,
+                hour => hour,
+            };
+            write!(buf, "{hour}:{:02}", time.minute())
+        };
+        if self.settings.show_seconds {
+            let _ = write!(buf, ":{:02}", time.second());
+        }
+        if !self.settings.use_24_hour_format {
+            buf.push_str(if time.hour() < 12 { " AM" } else { " PM" });
+        }
+    }
+
+    fn write_elapsed(&self, buf: &mut String, elapsed: TimeSpan) {
+        let total_seconds = elapsed.to_duration().whole_seconds().max(0);
+        let (hours, minutes, seconds) = (
+            total_seconds / 3600,
+            (total_seconds / 60) % 60,
+            total_seconds % 60,
+        );
+
+        let _ = write!(buf, "{hours}:{minutes:02}");
+        if self.settings.show_seconds {
+            let _ = write!(buf, ":{seconds:02}");
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Mode".into(), self.settings.mode.into()),
+            Field::new(
+                "Use 24-Hour Format".into(),
+                self.settings.use_24_hour_format.into(),
+            ),
+            Field::new("Show Seconds".into(), self.settings.show_seconds.into()),
+            Field::new(
+                "Use Local Time Zone".into(),
+                self.settings.use_local_time_zone.into(),
+            ),
+            Field::new(
+                "UTC Offset (Minutes)".into(),
+                self.settings.utc_offset.into(),
+            ),
+            Field::new(
+                "Uptime Start".into(),
+                self.settings.uptime_start.clone().into(),
+            ),
+            Field::new(
+                "Session Gap (Minutes)".into(),
+                self.settings.session_gap.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.mode = value.into(),
+            5 => self.settings.use_24_hour_format = value.into(),
+            6 => self.settings.show_seconds = value.into(),
+            7 => self.settings.use_local_time_zone = value.into(),
+            8 => self.settings.utc_offset = value.into(),
+            9 => self.settings.uptime_start = value.into(),
+            10 => self.settings.session_gap = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn write_date(buf: &mut String, date: Date) {
+    let _ = write!(
+        buf,
+        "{}-{:02}-{:02}",
+        date.year(),
+        u8::from(date.month()),
+        date.day()
+    );
+}
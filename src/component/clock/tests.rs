@@ -0,0 +1,70 @@
+use super::{write_date, Component, Settings};
+use crate::{platform::prelude::*, DateTime, TimeSpan};
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+fn utc(date: (i32, Month, u8), (hour, minute): (u8, u8)) -> DateTime {
+    PrimitiveDateTime::new(
+        Date::from_calendar_date(date.0, date.1, date.2).unwrap(),
+        Time::from_hms(hour, minute, 0).unwrap(),
+    )
+    .assume_utc()
+}
+
+fn component(use_24_hour_format: bool, show_seconds: bool) -> Component {
+    Component::with_settings(Settings {
+        use_24_hour_format,
+        show_seconds,
+        use_local_time_zone: false,
+        utc_offset: 90,
+        ..Default::default()
+    })
+}
+
+fn time_of_day(component: &Component, time: Time) -> String {
+    let mut buf = String::new();
+    component.write_time_of_day(&mut buf, time);
+    buf
+}
+
+#[test]
+fn formats_the_time_of_day() {
+    let time = Time::from_hms(0, 5, 9).unwrap();
+    assert_eq!(time_of_day(&component(true, true), time), "00:05:09");
+    assert_eq!(time_of_day(&component(true, false), time), "00:05");
+    assert_eq!(time_of_day(&component(false, true), time), "12:05:09 AM");
+
+    let time = Time::from_hms(13, 45, 0).unwrap();
+    assert_eq!(time_of_day(&component(false, false), time), "1:45 PM");
+}
+
+#[test]
+fn applies_the_utc_offset() {
+    let component = component(true, true);
+    let now = component.to_time_zone(utc((2023, Month::December, 31), (23, 0)));
+
+    let mut buf = String::new();
+    write_date(&mut buf, now.date());
+    buf.push(' ');
+    component.write_time_of_day(&mut buf, now.time());
+    assert_eq!(buf, "2024-01-01 00:30:00");
+}
+
+#[test]
+fn measures_the_uptime_from_the_configured_start() {
+    let component = component(true, false);
+    let now = utc((2024, Month::January, 1), (2, 0));
+
+    let start = component.parse_uptime_start("2024-01-01 00:15").unwrap();
+    assert_eq!(start, utc((2023, Month::December, 31), (22, 45)));
+
+    let mut buf = String::new();
+    component.write_elapsed(&mut buf, (now - start).into());
+    assert_eq!(buf, "3:15");
+
+    assert!(component.parse_uptime_start("01/01/2024").is_none());
+    assert!(component.parse_uptime_start("2024-01-01 25:00").is_none());
+
+    let mut buf = String::new();
+    component.write_elapsed(&mut buf, TimeSpan::from_seconds(-5.0));
+    assert_eq!(buf, "0:00");
+}
@@ -1,13 +1,18 @@
 //! Provides the Current Pace Component and relevant types for using it. The
 //! Current Pace Component is a component that shows a prediction of the current
 //! attempt's final time, if the current attempt's pace matches the chosen
-//! comparison for the remainder of the run.
+//! comparison for the remainder of the run. Optionally it can show an interval
+//! that the final time is likely to be in, based on a simulation of the
+//! remainder of the run.
 
 use super::key_value;
 use crate::{
-    analysis::current_pace,
+    analysis::{
+        current_pace,
+        prediction::{Predictor, DEFAULT_SAMPLES},
+    },
     comparison,
-    platform::prelude::*,
+    platform::{prelude::*, RwLock},
     settings::{Color, Field, Gradient, SettingsDescription, Value},
     timing::{
         formatter::{Accuracy, Regular, TimeFormatter},
@@ -19,12 +24,15 @@ use alloc::borrow::Cow;
 use core::fmt::Write;
 use serde::{Deserialize, Serialize};
 
+/// The share of the simulated final times that the interval shown covers.
+const CONFIDENCE: f64 = 0.8;
+
 /// The Current Pace Component is a component that shows a prediction of the
 /// current attempt's final time, if the current attempt's pace matches the
 /// chosen comparison for the remainder of the run.
-#[derive(Default, Clone)]
 pub struct Component {
     settings: Settings,
+    predictor: RwLock<Predictor>,
 }
 
 /// The Settings for this component.
@@ -47,6 +55,11 @@ pub struct Settings {
     pub value_color: Option<Color>,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// Specifies whether to show the interval that the final time is going to
+    /// be in with a probability of 80% instead of a single time. The interval
+    /// is determined by simulating the remainder of the attempt many times
+    /// with the segment times of the history.
+    pub show_confidence_interval: bool,
 }
 
 impl Default for Settings {
@@ -58,10 +71,24 @@ impl Default for Settings {
             label_color: None,
             value_color: None,
             accuracy: Accuracy::Seconds,
+            show_confidence_interval: false,
         }
     }
 }
 
+impl Default for Component {
+    fn default() -> Self {
+        Self::with_settings(Default::default())
+    }
+}
+
+impl Clone for Component {
+    fn clone(&self) -> Self {
+        // The cached simulation is not worth cloning, it simply gets rerun.
+        Self::with_settings(self.settings.clone())
+    }
+}
+
 impl Component {
     /// Creates a new Current Pace Component.
     pub fn new() -> Self {
@@ -70,7 +97,10 @@ impl Component {
 
     /// Creates a new Current Pace Component with the given settings.
     pub const fn with_settings(settings: Settings) -> Self {
-        Self { settings }
+        Self {
+            settings,
+            predictor: RwLock::new(Predictor::new(DEFAULT_SAMPLES, false)),
+        }
     }
 
     /// Accesses the settings of the component.
@@ -124,11 +154,26 @@ impl Component {
         state.key.push_str(&key); // FIXME: Uncow this
 
         state.value.clear();
-        let _ = write!(
-            state.value,
-            "{}",
-            Regular::with_accuracy(self.settings.accuracy).format(current_pace)
-        );
+        let formatter = Regular::with_accuracy(self.settings.accuracy);
+        let interval = if self.settings.show_confidence_interval {
+            self.predictor
+                .write()
+                .unwrap()
+                .predict(timer)
+                .map(|prediction| prediction.confidence_interval(CONFIDENCE))
+        } else {
+            None
+        };
+        if let Some((fastest, slowest)) = interval {
+            let _ = write!(
+                state.value,
+                "{} – {}",
+                formatter.format(fastest),
+                formatter.format(slowest)
+            );
+        } else {
+            let _ = write!(state.value, "{}", formatter.format(current_pace));
+        }
 
         state.key_abbreviations.clear();
         // FIXME: This &* probably is different when key is uncowed
@@ -157,7 +202,8 @@ impl Component {
         }
 
         state.display_two_rows = self.settings.display_two_rows;
-        state.updates_frequently = updates_frequently;
+        state.updates_frequently = updates_frequently
+            || (interval.is_some() && timer.current_phase() == TimerPhase::Running);
     }
 
     /// Calculates the component's state based on the timer provided.
@@ -183,6 +229,10 @@ impl Component {
             Field::new("Label Color".into(), self.settings.label_color.into()),
             Field::new("Value Color".into(), self.settings.value_color.into()),
             Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new(
+                "Show Confidence Interval".into(),
+                self.settings.show_confidence_interval.into(),
+            ),
         ])
     }
 
@@ -201,6 +251,7 @@ impl Component {
             3 => self.settings.label_color = value.into(),
             4 => self.settings.value_color = value.into(),
             5 => self.settings.accuracy = value.into(),
+            6 => self.settings.show_confidence_interval = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
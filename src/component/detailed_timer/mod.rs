@@ -4,7 +4,7 @@
 //! current segment. Other information, like segment times of up to two
 //! comparisons, the segment icon, and the segment's name, can also be shown.
 
-use super::timer;
+use super::timer::{self, CountdownMode};
 use crate::{
     analysis::comparison_single_segment_time,
     comparison::{self, best_segments, none},
@@ -438,6 +438,22 @@ impl Component {
                 self.settings.segment_name_color.into(),
             ),
             Field::new("Display Icon".into(), self.settings.display_icon.into()),
+            Field::new(
+                "Timer Countdown".into(),
+                self.settings.timer.countdown.into(),
+            ),
+            Field::new(
+                "Timer Goal Time".into(),
+                self.settings.timer.goal_time.into(),
+            ),
+            Field::new(
+                "Segment Timer Countdown".into(),
+                self.settings.segment_timer.countdown.into(),
+            ),
+            Field::new(
+                "Segment Timer Goal Time".into(),
+                self.settings.segment_timer.goal_time.into(),
+            ),
         ])
     }
 
@@ -515,6 +531,26 @@ impl Component {
             18 => self.settings.show_segment_name = value.into(),
             19 => self.settings.segment_name_color = value.into(),
             20 => self.settings.display_icon = value.into(),
+            21 => {
+                let value: CountdownMode = value.into();
+                self.settings.timer.countdown = value;
+                self.timer.settings_mut().countdown = value;
+            }
+            22 => {
+                let value: Option<TimeSpan> = value.into();
+                self.settings.timer.goal_time = value;
+                self.timer.settings_mut().goal_time = value;
+            }
+            23 => {
+                let value: CountdownMode = value.into();
+                self.settings.segment_timer.countdown = value;
+                self.segment_timer.settings_mut().countdown = value;
+            }
+            24 => {
+                let value: Option<TimeSpan> = value.into();
+                self.settings.segment_timer.goal_time = value;
+                self.segment_timer.settings_mut().goal_time = value;
+            }
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -1,5 +1,11 @@
-use super::{Component, Settings};
-use crate::{GeneralLayoutSettings, Run, Segment, Timer};
+use super::{Component, CountdownMode, Settings};
+use crate::{
+    settings::SemanticColor,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings, Run, Segment, Time, TimeSpan, Timer, TimingMethod,
+};
 
 fn prepare() -> (Timer, Component, GeneralLayoutSettings) {
     let mut run = Run::new();
@@ -184,3 +190,81 @@ fn shows_icon_again_when_remounting() {
         .filter(|s| !s.is_empty())
         .is_some());
 }
+
+#[test]
+fn counts_down_to_the_comparison() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(4.0));
+
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut component = Component::new();
+    component.set_value(21, CountdownMode::Comparison.into());
+    component.set_value(23, CountdownMode::Comparison.into());
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.timer.time, "26");
+    assert_eq!(state.segment_timer.time, "6");
+    assert_ne!(state.timer.semantic_color, SemanticColor::BehindLosingTime);
+
+    // Exceeding the comparison makes the times negative.
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(35.0));
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.timer.time, "−5");
+    assert_eq!(state.segment_timer.time, "−3");
+    assert_eq!(state.timer.semantic_color, SemanticColor::BehindLosingTime);
+    assert_eq!(
+        state.segment_timer.semantic_color,
+        SemanticColor::BehindLosingTime
+    );
+}
+
+#[test]
+fn counts_down_to_the_goal_time() {
+    let mut timer = create_timer(&["A", "B"]);
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(4.0));
+
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut component = Component::new();
+    component.set_value(21, CountdownMode::GoalTime.into());
+    component.set_value(23, CountdownMode::GoalTime.into());
+    component.set_value(24, Some(TimeSpan::from_seconds(60.0)).into());
+
+    // Without a goal time, the timer counts up.
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.timer.time, "4");
+    assert_eq!(state.segment_timer.time, "56");
+
+    // Without a comparison, the timer counts up as well.
+    component.set_value(21, CountdownMode::Comparison.into());
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.timer.time, "4");
+}
+
+#[test]
+fn counts_down_with_the_timing_method_the_time_falls_back_to() {
+    let mut run = Run::new();
+    let mut segment = Segment::new("A");
+    *segment.personal_best_split_time_mut() = Time::new()
+        .with_real_time(Some(TimeSpan::from_seconds(10.0)))
+        .with_game_time(Some(TimeSpan::from_seconds(100.0)));
+    run.push_segment(segment);
+    let mut timer = Timer::new(run).unwrap();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    // There is no Game Time, so both the timer and the segment timer fall back
+    // to Real Time, which they then need to count down to as well.
+    timer.start();
+
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut component = Component::new();
+    component.set_value(21, CountdownMode::Comparison.into());
+    component.set_value(23, CountdownMode::Comparison.into());
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.timer.time, "9");
+    assert_eq!(state.segment_timer.time, "9");
+}
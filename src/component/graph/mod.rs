@@ -5,6 +5,8 @@
 //! its y-coordinate is proportional to the split delta. The entire diagram is
 //! refered to as the chart and it contains the graph. The x-axis is the
 //! horizontal line that separates positive deltas from negative ones.
+//! Additional comparisons can be overlaid as lines, and the deltas of the
+//! individual segments can be shown as bars instead.
 
 // The words "padding" and "content" are from the CSS box model. "Padding" is an
 // area at the top/bottom that stays empty so that the graph doesn't touch the
@@ -12,14 +14,21 @@
 
 use crate::{
     analysis, comparison,
-    platform::prelude::*,
+    platform::{math::f64::floor, prelude::*},
     settings::{Color, Field, SettingsDescription, Value},
-    timing::Snapshot,
+    timing::{
+        formatter::{Accuracy, Delta, TimeFormatter},
+        Snapshot,
+    },
     GeneralLayoutSettings, TimeSpan, Timer, TimerPhase,
 };
 use alloc::borrow::Cow;
+use core::fmt::Write;
 use serde::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 const WIDTH: f32 = 1.0;
 const HEIGHT: f32 = 1.0;
 const DEFAULT_X_AXIS: f32 = HEIGHT / 2.0;
@@ -70,6 +79,44 @@ pub struct Settings {
     pub complete_fill_color: Color,
     /// The height of the chart.
     pub height: u32,
+    /// Specifies whether the horizontal grid lines are labeled with the deltas
+    /// they represent.
+    pub show_axis_labels: bool,
+    /// The number of most recent segments the graph zooms into. If this is set
+    /// to 0, the whole attempt is shown.
+    pub zoom_segment_count: u32,
+    /// Specifies whether the delta of each individual segment is shown as a
+    /// bar, instead of the deltas of the splits being shown as a line. The
+    /// additional comparisons are not shown in that case.
+    pub show_segment_delta_bars: bool,
+    /// Additional comparisons to overlay on the graph as lines. Their deltas
+    /// are based on the split times of the current attempt, just like the
+    /// deltas of the chosen comparison.
+    pub additional_comparisons: Vec<ComparisonLine>,
+}
+
+/// An additional comparison to overlay on the graph as a line.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ComparisonLine {
+    /// The name of the comparison.
+    pub comparison: String,
+    /// The color of the line.
+    pub color: Color,
+    /// The style of the line.
+    pub line_style: LineStyle,
+}
+
+/// The style of a line shown by the Graph Component.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LineStyle {
+    /// The line is solid.
+    Solid,
+    /// The line consists of dashes.
+    #[default]
+    Dashed,
+    /// The line consists of dots.
+    Dotted,
 }
 
 /// The state object describes the information to visualize for this component.
@@ -118,10 +165,57 @@ pub struct State {
     pub best_segment_color: Color,
     /// The height of the chart.
     pub height: u32,
+    /// The lines of the additional comparisons to overlay on the graph. They
+    /// share the coordinate system of the graph's points.
+    pub comparison_lines: Vec<ComparisonLineState>,
+    /// The bars showing the deltas of the individual segments. Each bar spans
+    /// from the x-axis to its y-coordinate. This is empty unless the segment
+    /// deltas are to be shown, in which case the graph's points only consist
+    /// of a placeholder point.
+    pub bars: Vec<Bar>,
+    /// The labels of the horizontal grid lines. This is empty if the labels
+    /// are not supposed to be shown.
+    pub axis_labels: Vec<AxisLabel>,
 }
 
-/// Describes a point on the graph to visualize.
+/// The state object describing a line of an additional comparison to
+/// visualize.
 #[derive(Serialize, Deserialize)]
+pub struct ComparisonLineState {
+    /// The name of the comparison.
+    pub comparison: String,
+    /// The points of the line. Connect them to visualize the line.
+    pub points: Vec<Point>,
+    /// The color of the line.
+    pub color: Color,
+    /// The style of the line.
+    pub line_style: LineStyle,
+}
+
+/// Describes a bar showing the delta of an individual segment.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bar {
+    /// The x-coordinate of the start of the segment.
+    pub start: f32,
+    /// The x-coordinate of the end of the segment.
+    pub end: f32,
+    /// The y-coordinate of the end of the bar that is not on the x-axis.
+    pub y: f32,
+    /// The color of the bar.
+    pub color: Color,
+}
+
+/// Describes the label of a horizontal grid line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AxisLabel {
+    /// The y-coordinate of the grid line.
+    pub y: f32,
+    /// The delta the grid line represents.
+    pub text: String,
+}
+
+/// Describes a point on the graph to visualize.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Point {
     /// The x-coordinate of the point.
     pub x: f32,
@@ -148,6 +242,20 @@ impl Default for Settings {
             partial_fill_color: Color::rgba(1.0, 1.0, 1.0, 0.25),
             complete_fill_color: Color::rgba(1.0, 1.0, 1.0, 0.4),
             height: 80,
+            show_axis_labels: false,
+            zoom_segment_count: 0,
+            show_segment_delta_bars: false,
+            additional_comparisons: Vec::new(),
+        }
+    }
+}
+
+impl Default for ComparisonLine {
+    fn default() -> Self {
+        Self {
+            comparison: String::from(comparison::best_segments::NAME),
+            color: Color::rgba(1.0, 212.0 / 255.0, 0.0, 1.0),
+            line_style: LineStyle::Dashed,
         }
     }
 }
@@ -166,7 +274,6 @@ impl State {
 /// Private struct to reduce the number of function arguments.
 #[derive(Default)]
 struct DrawInfo {
-    points: Vec<Point>,
     /// The lowest delta value in seconds.
     min_delta: f32,
     /// The highest delta value in seconds.
@@ -175,10 +282,62 @@ struct DrawInfo {
     scale_factor_y: Option<f32>,
     padding_y: f32,
     split_index: usize,
+    /// The index of the first segment shown on the chart.
+    first_segment: usize,
+    /// The index of the segment whose split time is on the left edge of the
+    /// chart. If this is `None`, the left edge is the start of the attempt.
+    start_split: Option<usize>,
+    /// The split time in seconds on the left edge of the chart.
+    start_time: f32,
     flip_graph: bool,
     is_live_delta_active: bool,
 }
 
+/// All the series shown on the chart. Their y-coordinates are deltas until
+/// they get transformed.
+#[derive(Default)]
+struct Series {
+    points: Vec<Point>,
+    comparison_lines: Vec<ComparisonLineState>,
+    bars: Vec<Bar>,
+}
+
+impl DrawInfo {
+    fn include_delta(&mut self, delta: f32) {
+        if delta > self.max_delta {
+            self.max_delta = delta;
+        } else if delta < self.min_delta {
+            self.min_delta = delta;
+        }
+    }
+
+    fn x(&self, split_time: TimeSpan) -> f32 {
+        (split_time.total_seconds() as f32 - self.start_time) * self.scale_factor_x.unwrap_or(0.0)
+    }
+
+    fn y(&self, delta: f32) -> f32 {
+        if let Some(scale_factor_y) = self.scale_factor_y {
+            let y = (self.max_delta - delta) * scale_factor_y + self.padding_y;
+            if self.flip_graph {
+                HEIGHT - y
+            } else {
+                y
+            }
+        } else {
+            DEFAULT_X_AXIS
+        }
+    }
+
+    fn delta(&self, y: f32) -> f32 {
+        if let Some(scale_factor_y) = self.scale_factor_y {
+            let y = if self.flip_graph { HEIGHT - y } else { y };
+            self.max_delta - (y - self.padding_y) / scale_factor_y
+        } else {
+            0.0
+        }
+    }
+}
+
 #[derive(Default)]
 struct GridLines {
     /// The offset of the first grid line followed by the grid line distance.
@@ -237,13 +396,14 @@ impl Component {
             flip_graph: self.settings.flip_graph,
             ..DrawInfo::default()
         };
+        let mut series = Series::default();
 
         let x_axis = self
-            .calculate_graph(timer, &mut draw_info)
+            .calculate_graph(timer, layout_settings, &mut draw_info, &mut series)
             .unwrap_or(DEFAULT_X_AXIS);
 
-        if draw_info.points.is_empty() {
-            draw_info.points.push(Point {
+        if series.points.is_empty() {
+            series.points.push(Point {
                 x: 0.0,
                 y: DEFAULT_X_AXIS,
                 is_best_segment: false,
@@ -252,11 +412,14 @@ impl Component {
 
         let grid_lines = calculate_grid_lines(&draw_info, x_axis);
         update_grid_line_vecs(state, grid_lines);
+        update_axis_labels(state, &draw_info, self.settings.show_axis_labels);
         self.copy_settings_to_state(state);
         state.best_segment_color = layout_settings.best_segment_color;
         state.middle = x_axis;
         state.is_live_delta_active = draw_info.is_live_delta_active;
-        state.points = draw_info.points;
+        state.points = series.points;
+        state.comparison_lines = series.comparison_lines;
+        state.bars = series.bars;
     }
 
     /// Calculates the component's state based on the timer and layout settings
@@ -270,7 +433,7 @@ impl Component {
     /// Accesses a generic description of the settings available for this
     /// component and their current values.
     pub fn settings_description(&self) -> SettingsDescription {
-        SettingsDescription::with_fields(vec![
+        let mut fields = vec![
             Field::new(
                 "Comparison".into(),
                 self.settings.comparison_override.clone().into(),
@@ -306,7 +469,34 @@ impl Component {
                 "Complete Fill Color".into(),
                 self.settings.complete_fill_color.into(),
             ),
-        ])
+            Field::new(
+                "Show Axis Labels".into(),
+                self.settings.show_axis_labels.into(),
+            ),
+            Field::new(
+                "Zoom to Last Segments".into(),
+                u64::from(self.settings.zoom_segment_count).into(),
+            ),
+            Field::new(
+                "Show Segment Deltas".into(),
+                self.settings.show_segment_delta_bars.into(),
+            ),
+            Field::new(
+                "Additional Comparisons".into(),
+                Value::UInt(self.settings.additional_comparisons.len() as _),
+            ),
+        ];
+
+        for line in &self.settings.additional_comparisons {
+            fields.push(Field::new(
+                "Comparison".into(),
+                line.comparison.clone().into(),
+            ));
+            fields.push(Field::new("Color".into(), line.color.into()));
+            fields.push(Field::new("Line Style".into(), line.line_style.into()));
+        }
+
+        SettingsDescription::with_fields(fields)
     }
 
     /// Sets a setting's value by its index to the given value.
@@ -329,35 +519,94 @@ impl Component {
             8 => self.settings.graph_lines_color = value.into(),
             9 => self.settings.partial_fill_color = value.into(),
             10 => self.settings.complete_fill_color = value.into(),
-            _ => panic!("Unsupported Setting Index"),
+            11 => self.settings.show_axis_labels = value.into(),
+            12 => self.settings.zoom_segment_count = value.into_uint().unwrap() as _,
+            13 => self.settings.show_segment_delta_bars = value.into(),
+            14 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings
+                    .additional_comparisons
+                    .resize_with(new_len, Default::default);
+            }
+            index => {
+                let index = index - 15;
+                let line = &mut self.settings.additional_comparisons[index / 3];
+                match index % 3 {
+                    0 => line.comparison = value.into(),
+                    1 => line.color = value.into(),
+                    2 => line.line_style = value.into(),
+                    _ => unreachable!(),
+                }
+            }
         }
     }
 
-    fn calculate_graph(&self, timer: &Snapshot<'_>, draw_info: &mut DrawInfo) -> Option<f32> {
+    fn calculate_graph(
+        &self,
+        timer: &Snapshot<'_>,
+        layout_settings: &GeneralLayoutSettings,
+        draw_info: &mut DrawInfo,
+        series: &mut Series,
+    ) -> Option<f32> {
         let settings = &self.settings;
         draw_info.split_index = timer.current_split_index()?;
         let comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let comparison = comparison::or_current(comparison, timer);
 
+        calculate_zoom_window(timer, draw_info, settings.zoom_segment_count);
         calculate_horizontal_scaling(timer, draw_info, settings.live_graph);
         draw_info.scale_factor_x?;
 
-        draw_info.points = Vec::with_capacity(draw_info.split_index + 1);
-        draw_info.points.push(Point {
-            x: 0.0,
-            y: 0.0, // Not the final value of y, this will end up on the x-axis.
-            is_best_segment: false,
-        });
+        if settings.show_segment_delta_bars {
+            calculate_segment_bars(
+                timer,
+                draw_info,
+                &mut series.bars,
+                comparison,
+                settings.show_best_segments,
+                layout_settings,
+            );
+            if settings.live_graph {
+                calculate_live_segment_bar(
+                    timer,
+                    draw_info,
+                    &mut series.bars,
+                    comparison,
+                    layout_settings,
+                );
+            }
+        } else {
+            series.points =
+                calculate_split_points(timer, draw_info, comparison, settings.show_best_segments);
+            if settings.live_graph {
+                draw_info.is_live_delta_active =
+                    calculate_live_delta_point(timer, draw_info, &mut series.points, comparison);
+            }
 
-        calculate_split_points(timer, draw_info, comparison, settings.show_best_segments);
-        if settings.live_graph {
-            calculate_live_delta_point(timer, draw_info, comparison);
+            for line in &settings.additional_comparisons {
+                let Some(comparison) = timer.run().comparisons().find(|&c| c == line.comparison)
+                else {
+                    continue;
+                };
+
+                let mut points = calculate_split_points(timer, draw_info, comparison, false);
+                if settings.live_graph {
+                    calculate_live_delta_point(timer, draw_info, &mut points, comparison);
+                }
+
+                series.comparison_lines.push(ComparisonLineState {
+                    comparison: line.comparison.clone(),
+                    points,
+                    color: line.color,
+                    line_style: line.line_style,
+                });
+            }
         }
 
         calculate_vertical_scaling(draw_info);
         let x_axis = calculate_x_axis(draw_info);
 
-        transform_y_coordinates(draw_info);
+        transform_y_coordinates(draw_info, series);
 
         Some(x_axis)
     }
@@ -385,6 +634,29 @@ impl Component {
     }
 }
 
+/// Determines the segments shown on the chart. If the chart is zoomed in, its
+/// left edge is the last split time before the first segment shown.
+fn calculate_zoom_window(timer: &Timer, draw_info: &mut DrawInfo, zoom_segment_count: u32) {
+    if zoom_segment_count == 0 {
+        return;
+    }
+
+    let timing_method = timer.current_timing_method();
+    draw_info.first_segment = draw_info
+        .split_index
+        .saturating_sub(zoom_segment_count as usize);
+
+    draw_info.start_split = timer.run().segments()[..draw_info.first_segment]
+        .iter()
+        .rposition(|segment| segment.split_time()[timing_method].is_some());
+
+    if let Some(start_split) = draw_info.start_split {
+        if let Some(time) = timer.run().segment(start_split).split_time()[timing_method] {
+            draw_info.start_time = time.total_seconds() as f32;
+        }
+    }
+}
+
 fn calculate_horizontal_scaling(timer: &Snapshot<'_>, draw_info: &mut DrawInfo, live_graph: bool) {
     let timing_method = timer.current_timing_method();
 
@@ -407,8 +679,8 @@ fn calculate_horizontal_scaling(timer: &Snapshot<'_>, draw_info: &mut DrawInfo,
         }
     }
 
-    if final_split > 0.0 {
-        draw_info.scale_factor_x = Some(WIDTH / final_split);
+    if final_split > draw_info.start_time {
+        draw_info.scale_factor_x = Some(WIDTH / (final_split - draw_info.start_time));
     }
 
     // Else scaling doesn't matter and scale_factor_x stays None.
@@ -422,41 +694,64 @@ fn calculate_split_points(
     draw_info: &mut DrawInfo,
     comparison: &str,
     show_best_segments: bool,
-) {
+) -> Vec<Point> {
     let timing_method = timer.current_timing_method();
+    let segments = timer.run().segments();
+
+    let start_delta = draw_info.start_split.and_then(|i| {
+        let split_time = segments[i].split_time()[timing_method]?;
+        let comparison_time = segments[i].comparison(comparison)[timing_method]?;
+        Some((split_time - comparison_time).total_seconds() as f32)
+    });
 
-    for (i, segment) in timer.run().segments()[..draw_info.split_index]
+    let mut points = Vec::with_capacity(draw_info.split_index - draw_info.first_segment + 2);
+    if let Some(delta) = start_delta {
+        draw_info.include_delta(delta);
+    }
+    points.push(Point {
+        x: 0.0,
+        // Not the final value of y. Without a delta, this will end up on the x-axis.
+        y: start_delta.unwrap_or_default(),
+        is_best_segment: false,
+    });
+
+    for (i, segment) in segments
         .iter()
         .enumerate()
+        .take(draw_info.split_index)
+        .skip(draw_info.first_segment)
     {
         catch! {
             let split_time = segment.split_time()[timing_method]?;
             let comparison_time = segment.comparison(comparison)[timing_method]?;
             let delta = (split_time - comparison_time).total_seconds() as f32;
 
-            if delta > draw_info.max_delta {
-                draw_info.max_delta = delta;
-            } else if delta < draw_info.min_delta {
-                draw_info.min_delta = delta;
-            }
-
-            let x = split_time.total_seconds() as f32 * draw_info.scale_factor_x.unwrap_or(0.0);
+            draw_info.include_delta(delta);
 
             let is_best_segment =
                 show_best_segments && analysis::check_best_segment(timer, i, timing_method);
 
-            draw_info.points.push(Point {
-                x,
+            points.push(Point {
+                x: draw_info.x(split_time),
                 y: delta, // Not the final value of y.
                 is_best_segment,
             });
         };
     }
+
+    points
 }
 
-fn calculate_live_delta_point(timer: &Snapshot<'_>, draw_info: &mut DrawInfo, comparison: &str) {
+/// Adds the point visualizing the live delta, if there is one. Returns whether
+/// the point got added.
+fn calculate_live_delta_point(
+    timer: &Snapshot<'_>,
+    draw_info: &mut DrawInfo,
+    points: &mut Vec<Point>,
+    comparison: &str,
+) -> bool {
     if timer.current_phase() == TimerPhase::Ended {
-        return;
+        return false;
     }
 
     let timing_method = timer.current_timing_method();
@@ -479,18 +774,115 @@ fn calculate_live_delta_point(timer: &Snapshot<'_>, draw_info: &mut DrawInfo, co
 
     if let Some(live_delta) = live_delta {
         let delta = live_delta.total_seconds() as f32;
-        if delta > draw_info.max_delta {
-            draw_info.max_delta = delta;
-        } else if delta < draw_info.min_delta {
-            draw_info.min_delta = delta;
-        }
+        draw_info.include_delta(delta);
 
-        draw_info.points.push(Point {
+        points.push(Point {
             x: WIDTH,
             y: delta, // Not the final value of y.
             is_best_segment: false,
         });
-        draw_info.is_live_delta_active = true;
+        true
+    } else {
+        false
+    }
+}
+
+/// Calculates a bar for every segment with a segment delta. Every bar spans
+/// the segment's interval on the x-axis. Just like with the points, the deltas
+/// are stored as the bars' y-coordinates and will have to be corrected before
+/// rendering.
+fn calculate_segment_bars(
+    timer: &Timer,
+    draw_info: &mut DrawInfo,
+    bars: &mut Vec<Bar>,
+    comparison: &str,
+    show_best_segments: bool,
+    layout_settings: &GeneralLayoutSettings,
+) {
+    let timing_method = timer.current_timing_method();
+    let mut start = 0.0;
+
+    for (i, segment) in timer
+        .run()
+        .segments()
+        .iter()
+        .enumerate()
+        .take(draw_info.split_index)
+        .skip(draw_info.first_segment)
+    {
+        let Some(split_time) = segment.split_time()[timing_method] else {
+            continue;
+        };
+        let end = draw_info.x(split_time);
+
+        if let Some(delta) = analysis::previous_segment_delta(timer, i, comparison, timing_method) {
+            let delta = delta.total_seconds() as f32;
+            draw_info.include_delta(delta);
+
+            let color =
+                if show_best_segments && analysis::check_best_segment(timer, i, timing_method) {
+                    layout_settings.best_segment_color
+                } else {
+                    segment_delta_color(delta, layout_settings)
+                };
+
+            bars.push(Bar {
+                start,
+                end,
+                y: delta, // Not the final value of y.
+                color,
+            });
+        }
+
+        start = end;
+    }
+}
+
+/// Adds the bar visualizing the delta of the current segment. It is only
+/// shown once time is being lost on the current segment.
+fn calculate_live_segment_bar(
+    timer: &Snapshot<'_>,
+    draw_info: &mut DrawInfo,
+    bars: &mut Vec<Bar>,
+    comparison: &str,
+    layout_settings: &GeneralLayoutSettings,
+) {
+    if timer.current_phase() == TimerPhase::Ended {
+        return;
+    }
+
+    let timing_method = timer.current_timing_method();
+    let Some(delta) =
+        analysis::live_segment_delta(timer, draw_info.split_index, comparison, timing_method)
+    else {
+        return;
+    };
+
+    let delta = delta.total_seconds() as f32;
+    if delta <= 0.0 {
+        return;
+    }
+    draw_info.include_delta(delta);
+
+    let start = timer.run().segments()[..draw_info.split_index]
+        .iter()
+        .rev()
+        .find_map(|segment| segment.split_time()[timing_method])
+        .map_or(0.0, |time| draw_info.x(time).max(0.0));
+
+    bars.push(Bar {
+        start,
+        end: WIDTH,
+        y: delta, // Not the final value of y.
+        color: segment_delta_color(delta, layout_settings),
+    });
+}
+
+fn segment_delta_color(delta: f32, layout_settings: &GeneralLayoutSettings) -> Color {
+    if delta < 0.0 {
+        layout_settings.ahead_gaining_time_color
+    } else {
+        layout_settings.behind_losing_time_color
     }
 }
 
@@ -592,22 +984,39 @@ fn update_grid_line_vecs(state: &mut State, grid_lines: GridLines) {
     }
 }
 
-/// Before calling this function, the deltas are stored as the points'.
-/// y-coordinates. This will calculate the actual y-coordinates and replace the
-/// deltas. The reason why this can't be done in the first loop is that
-/// `min_`/`max_delta` is not known yet at that point in time.
-fn transform_y_coordinates(draw_info: &mut DrawInfo) {
-    if let Some(scale_factor_y) = draw_info.scale_factor_y {
-        for point in &mut draw_info.points {
-            let delta = point.y;
-            point.y = (draw_info.max_delta - delta) * scale_factor_y + draw_info.padding_y;
-            if draw_info.flip_graph {
-                point.y = HEIGHT - point.y;
-            }
-        }
-    } else {
-        for point in &mut draw_info.points {
-            point.y = DEFAULT_X_AXIS;
-        }
+/// Labels the horizontal grid lines with the deltas they represent, rounded to
+/// whole seconds.
+fn update_axis_labels(state: &mut State, draw_info: &DrawInfo, show_axis_labels: bool) {
+    state.axis_labels.clear();
+    if !show_axis_labels {
+        return;
+    }
+
+    let formatter = Delta::custom(true, Accuracy::Seconds);
+    for &y in &state.horizontal_grid_lines {
+        let delta = floor(draw_info.delta(y) as f64 + 0.5);
+        let mut text = String::new();
+        let _ = write!(text, "{}", formatter.format(TimeSpan::from_seconds(delta)));
+        state.axis_labels.push(AxisLabel { y, text });
+    }
+}
+
+/// Before calling this function, the deltas are stored as the y-coordinates of
+/// the points and bars. This will calculate the actual y-coordinates and
+/// replace the deltas. The reason why this can't be done in the first loop is
+/// that `min_`/`max_delta` is not known yet at that point in time.
+fn transform_y_coordinates(draw_info: &DrawInfo, series: &mut Series) {
+    let points = series
+        .comparison_lines
+        .iter_mut()
+        .flat_map(|line| &mut line.points)
+        .chain(&mut series.points);
+
+    for point in points {
+        point.y = draw_info.y(point.y);
+    }
+
+    for bar in &mut series.bars {
+        bar.y = draw_info.y(bar.y);
     }
 }
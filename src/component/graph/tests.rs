@@ -0,0 +1,130 @@
+use super::{ComparisonLine, Component, LineStyle, Point};
+use crate::{
+    comparison,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+    GeneralLayoutSettings, Timer,
+};
+
+fn timer_with_attempt() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+    start_run(&mut timer);
+    // The deltas are +2, -1 and +3. The segment deltas are +2, -3 and +4.
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.0), Some(19.0), Some(33.0)]);
+    timer
+}
+
+fn component() -> Component {
+    let mut component = Component::new();
+    component.settings_mut().live_graph = false;
+    component
+}
+
+#[test]
+fn zooms_into_the_last_segments() {
+    let timer = timer_with_attempt();
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut component = component();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_x_coordinates(&state.points, &[0.0, 12.0 / 33.0, 19.0 / 33.0, 1.0]);
+
+    component.settings_mut().zoom_segment_count = 2;
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_x_coordinates(&state.points, &[0.0, 1.0 / 3.0, 1.0]);
+
+    // The chart starts at the delta of the split before the first segment
+    // shown, which is behind the comparison.
+    let [start, b, c] = [state.points[0], state.points[1], state.points[2]];
+    assert!(c.y < start.y && start.y < state.middle && state.middle < b.y);
+}
+
+#[test]
+fn overlays_additional_comparisons() {
+    let timer = timer_with_attempt();
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut component = component();
+    component.settings_mut().additional_comparisons = vec![
+        ComparisonLine {
+            line_style: LineStyle::Dotted,
+            ..ComparisonLine::default()
+        },
+        ComparisonLine {
+            comparison: String::from("Unknown"),
+            ..ComparisonLine::default()
+        },
+    ];
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.comparison_lines.len(), 1);
+
+    // The best segments match the personal best after a single attempt.
+    let line = &state.comparison_lines[0];
+    assert_eq!(line.comparison, comparison::best_segments::NAME);
+    assert_eq!(line.line_style, LineStyle::Dotted);
+    assert_eq!(line.points, state.points);
+}
+
+#[test]
+fn shows_segment_deltas_as_bars() {
+    let timer = timer_with_attempt();
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut component = component();
+    component.settings_mut().show_segment_delta_bars = true;
+    component.settings_mut().additional_comparisons = vec![ComparisonLine::default()];
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.points.len(), 1);
+    assert!(state.comparison_lines.is_empty());
+    assert_eq!(state.bars.len(), 3);
+
+    let bounds = state.bars.iter().map(|bar| [bar.start, bar.end]);
+    let expected = [
+        [0.0, 12.0 / 33.0],
+        [12.0 / 33.0, 19.0 / 33.0],
+        [19.0 / 33.0, 1.0],
+    ];
+    for (actual, expected) in bounds.zip(expected) {
+        assert!((actual[0] - expected[0]).abs() < 1e-6);
+        assert!((actual[1] - expected[1]).abs() < 1e-6);
+    }
+
+    let [a, b, c] = [state.bars[0], state.bars[1], state.bars[2]];
+    assert!(c.y < a.y && a.y < state.middle && state.middle < b.y);
+    assert_eq!(a.color, layout_settings.behind_losing_time_color);
+    assert_eq!(b.color, layout_settings.ahead_gaining_time_color);
+
+    component.settings_mut().show_best_segments = true;
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(state.bars[1].color, layout_settings.best_segment_color);
+}
+
+#[test]
+fn labels_the_axis() {
+    let timer = timer_with_attempt();
+    let layout_settings = GeneralLayoutSettings::default();
+    let mut component = component();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert!(state.axis_labels.is_empty());
+
+    component.settings_mut().show_axis_labels = true;
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let labels: Vec<_> = state.axis_labels.iter().map(|l| &*l.text).collect();
+    assert_eq!(labels, ["+3", "+2", "+1", "+0", "−1"]);
+    assert!(state
+        .axis_labels
+        .iter()
+        .zip(&state.horizontal_grid_lines)
+        .all(|(label, &y)| label.y == y));
+}
+
+#[track_caller]
+fn assert_x_coordinates(actual: &[Point], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual, &x) in actual.iter().zip(expected) {
+        assert!((actual.x - x).abs() < 1e-6, "{actual:?}");
+    }
+}
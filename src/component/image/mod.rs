@@ -0,0 +1,219 @@
+//! Provides the Image Component and relevant types for using it. The Image
+//! Component is a component that shows an image, such as a route map or
+//! character art, the icon of the current segment or the game's icon.
+
+use crate::{
+    platform::prelude::*,
+    settings::{CachedImageId, Field, Gradient, Image, ImageData, SettingsDescription, Value},
+    Timer, TimerPhase,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Image Component is a component that shows an image, such as a route
+/// map or character art, the icon of the current segment or the game's icon.
+#[derive(Default, Clone)]
+pub struct Component {
+    image_id: CachedImageId,
+    settings: Settings,
+}
+
+/// Specifies which image the Image Component shows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImageMode {
+    /// The image stored in the settings of the component.
+    StaticImage,
+    /// The icon of the current segment. If there is no attempt in progress,
+    /// the icon of the first segment is shown.
+    SegmentIcon,
+    /// The icon of the game.
+    GameIcon,
+}
+
+/// Specifies how the image is fit into the space of the Image Component.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AspectMode {
+    /// The image is scaled to fit into the component while keeping its aspect
+    /// ratio.
+    #[default]
+    Fit,
+    /// The image is stretched to fill the whole component.
+    Stretch,
+}
+
+/// Specifies how the image is aligned horizontally if it doesn't fill the
+/// whole width of the Image Component.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImageAlignment {
+    /// The image is aligned to the left.
+    Left,
+    /// The image is centered.
+    #[default]
+    Center,
+    /// The image is aligned to the right.
+    Right,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies which image is shown.
+    pub mode: ImageMode,
+    /// The image shown if the mode is set to show a static image.
+    pub image: Image,
+    /// The size of the component.
+    pub size: u32,
+    /// Specifies how the image is fit into the space of the component.
+    pub aspect_mode: AspectMode,
+    /// Specifies how the image is aligned horizontally.
+    pub alignment: ImageAlignment,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: Gradient::Transparent,
+            mode: ImageMode::StaticImage,
+            image: Image::default(),
+            size: 100,
+            aspect_mode: AspectMode::Fit,
+            alignment: ImageAlignment::Center,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The image encoded as the raw file bytes. This value is only specified
+    /// whenever the image changes. If you explicitly want to query this value,
+    /// remount the component. The buffer itself may be empty. This indicates
+    /// that there is no image.
+    pub image_change: Option<ImageData>,
+    /// The size of the component.
+    pub size: u32,
+    /// Specifies how the image is fit into the space of the component.
+    pub aspect_mode: AspectMode,
+    /// Specifies how the image is aligned horizontally.
+    pub alignment: ImageAlignment,
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Image Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Image Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Image"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&mut self, state: &mut State, timer: &Timer) {
+        let run = timer.run();
+
+        let image = match self.settings.mode {
+            ImageMode::StaticImage => Some(&self.settings.image),
+            ImageMode::SegmentIcon => {
+                let segment = if timer.current_phase() == TimerPhase::Ended {
+                    run.segments().last()
+                } else {
+                    timer.current_split().or_else(|| run.segments().first())
+                };
+                segment.map(|segment| segment.icon())
+            }
+            ImageMode::GameIcon => Some(run.game_icon()),
+        };
+
+        state.background = self.settings.background;
+        state.image_change = self.image_id.update_with(image).map(Into::into);
+        state.size = self.settings.size;
+        state.aspect_mode = self.settings.aspect_mode;
+        state.alignment = self.settings.alignment;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&mut self, timer: &Timer) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Remounts the component as if it was freshly initialized. The image
+    /// shown by this component is only provided in the state objects whenever
+    /// the image changes or whenever the component's state is first queried.
+    /// Remounting returns the image again, whenever its state is queried the
+    /// next time.
+    pub fn remount(&mut self) {
+        self.image_id.reset();
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Mode".into(), self.settings.mode.into()),
+            Field::new("Image".into(), self.settings.image.clone().into()),
+            Field::new("Size".into(), u64::from(self.settings.size).into()),
+            Field::new("Aspect Mode".into(), self.settings.aspect_mode.into()),
+            Field::new("Alignment".into(), self.settings.alignment.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.mode = value.into(),
+            2 => self.settings.image = value.into(),
+            3 => self.settings.size = value.into_uint().unwrap() as _,
+            4 => self.settings.aspect_mode = value.into(),
+            5 => self.settings.alignment = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use super::{Component, ImageMode, Settings};
+use crate::{settings::Image, Run, Segment, Timer};
+
+fn prepare() -> Timer {
+    let mut run = Run::new();
+    run.set_game_icon([0x12]);
+    let mut segment = Segment::new("foo");
+    segment.set_icon([0x34]);
+    run.push_segment(segment);
+    let mut segment = Segment::new("bar");
+    segment.set_icon([0x56]);
+    run.push_segment(segment);
+    Timer::new(run).unwrap()
+}
+
+fn image_change(component: &mut Component, timer: &Timer) -> Option<Vec<u8>> {
+    component
+        .state(timer)
+        .image_change
+        .map(|image| image.to_vec())
+}
+
+#[test]
+fn shows_the_icon_of_the_current_segment() {
+    let mut timer = prepare();
+    let mut component = Component::with_settings(Settings {
+        mode: ImageMode::SegmentIcon,
+        ..Default::default()
+    });
+
+    // Outside of an attempt the first segment's icon is shown.
+    assert_eq!(image_change(&mut component, &timer), Some(vec![0x34]));
+
+    timer.start();
+    assert_eq!(image_change(&mut component, &timer), None);
+
+    timer.split();
+    assert_eq!(image_change(&mut component, &timer), Some(vec![0x56]));
+
+    // The last segment's icon is kept at the end of the attempt.
+    timer.split();
+    assert_eq!(image_change(&mut component, &timer), None);
+
+    timer.reset(true);
+    assert_eq!(image_change(&mut component, &timer), Some(vec![0x34]));
+}
+
+#[test]
+fn tracks_changes_of_the_static_image() {
+    let timer = prepare();
+    let mut component = Component::new();
+
+    assert_eq!(image_change(&mut component, &timer), Some(vec![]));
+    assert_eq!(image_change(&mut component, &timer), None);
+
+    component.set_value(2, Image::new(&[0x78]).into());
+    assert_eq!(image_change(&mut component, &timer), Some(vec![0x78]));
+    assert_eq!(image_change(&mut component, &timer), None);
+
+    component.remount();
+    assert_eq!(image_change(&mut component, &timer), Some(vec![0x78]));
+}
+
+#[test]
+fn shows_the_game_icon() {
+    let timer = prepare();
+    let mut component = Component::new();
+    component.state(&timer);
+
+    component.set_value(1, ImageMode::GameIcon.into());
+    assert_eq!(image_change(&mut component, &timer), Some(vec![0x12]));
+}
@@ -0,0 +1,357 @@
+//! Provides the Input Display Component and relevant types for using it. The
+//! Input Display Component visualizes which keys are currently held down and
+//! which keys got pressed recently, which allows showing the inputs of a run on
+//! stream. The key presses are
+//! provided through a [`KeyEventFeed`](crate::KeyEventFeed), which the
+//! [`HotkeySystem`](crate::HotkeySystem) can fill with the key events it
+//! observes.
+
+use super::key_value;
+use crate::{
+    hotkey::{Hotkey, KeyCode},
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    KeyEventFeed,
+};
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Input Display Component visualizes which of a configurable set of keys
+/// are currently held down. The keys are arranged in a grid and are
+/// highlighted while they are pressed. Optionally the number of times each key
+/// got pressed and the keys that got pressed most recently are shown as well.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+    feed: KeyEventFeed,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The keys to visualize, in the order they are laid out in the grid.
+    pub keys: Vec<Key>,
+    /// The number of keys shown in each row of the grid.
+    pub keys_per_row: u32,
+    /// The color of a key while it is not pressed.
+    pub released_key_color: Color,
+    /// The color of a key while it is pressed.
+    pub pressed_key_color: Color,
+    /// The color of a key's label while it is not pressed. If `None` is
+    /// specified, the color is taken from the layout.
+    pub released_text_color: Option<Color>,
+    /// The color of a key's label while it is pressed.
+    pub pressed_text_color: Color,
+    /// Specifies whether the number of times each key got pressed should be
+    /// shown below its label.
+    pub show_press_counts: bool,
+    /// The number of recently pressed keys to show below the grid, starting
+    /// with the most recent one. Nothing is shown if this is 0. At most 16 key
+    /// presses are remembered.
+    pub recent_key_count: u32,
+}
+
+/// A key to be visualized by the Input Display Component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Key {
+    /// The key code of the key.
+    pub key_code: KeyCode,
+    /// The label shown for the key. If `None` is specified, the name of the
+    /// key on a US keyboard is used instead.
+    pub label: Option<String>,
+}
+
+impl Default for Key {
+    fn default() -> Self {
+        Self {
+            key_code: KeyCode::Space,
+            label: None,
+        }
+    }
+}
+
+impl Key {
+    fn resolved_label(&self) -> &str {
+        self.label
+            .as_deref()
+            .unwrap_or_else(|| self.key_code.resolve_en_us())
+    }
+}
+
+impl From<KeyCode> for Key {
+    fn from(key_code: KeyCode) -> Self {
+        Self {
+            key_code,
+            label: None,
+        }
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            keys: vec![
+                KeyCode::ArrowLeft.into(),
+                KeyCode::ArrowUp.into(),
+                KeyCode::ArrowDown.into(),
+                KeyCode::ArrowRight.into(),
+            ],
+            keys_per_row: 4,
+            released_key_color: Color::rgba(1.0, 1.0, 1.0, 0.1),
+            pressed_key_color: Color::rgba(1.0, 1.0, 1.0, 0.9),
+            released_text_color: None,
+            pressed_text_color: Color::rgba(0.0, 0.0, 0.0, 1.0),
+            show_press_counts: false,
+            recent_key_count: 0,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The keys to visualize, in the order they are laid out in the grid.
+    pub keys: Vec<KeyState>,
+    /// The number of keys shown in each row of the grid. This is always at
+    /// least 1.
+    pub keys_per_row: u32,
+    /// The color of a key while it is not pressed.
+    pub released_key_color: Color,
+    /// The color of a key while it is pressed.
+    pub pressed_key_color: Color,
+    /// The color of a key's label while it is not pressed. If `None` is
+    /// specified, the color is taken from the layout.
+    pub released_text_color: Option<Color>,
+    /// The color of a key's label while it is pressed.
+    pub pressed_text_color: Color,
+    /// Specifies whether the number of times each key got pressed is shown
+    /// below its label.
+    pub show_press_counts: bool,
+    /// The labels of the recently pressed keys, starting with the most recent
+    /// one.
+    pub recent_keys: Vec<String>,
+    /// Specifies whether the recently pressed keys are shown below the grid.
+    /// This may be `true` even if no keys got pressed yet.
+    pub show_recent_keys: bool,
+}
+
+/// The state object describes a single key to visualize.
+#[derive(Default, Serialize, Deserialize)]
+pub struct KeyState {
+    /// The label of the key.
+    pub label: String,
+    /// The number of times the key got pressed. This is empty if the press
+    /// counts are not supposed to be shown.
+    pub press_count: String,
+    /// Specifies whether the key is currently held down.
+    pub is_pressed: bool,
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Input Display Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Input Display Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the key event feed the component visualizes. Clone it and
+    /// provide it with the key events of the keys returned by
+    /// [`key_codes`](Self::key_codes), for example via
+    /// [`HotkeySystem::set_key_event_feed`](crate::HotkeySystem::set_key_event_feed).
+    pub const fn key_event_feed(&self) -> &KeyEventFeed {
+        &self.feed
+    }
+
+    /// Returns the key codes of all the keys the component visualizes.
+    pub fn key_codes(&self) -> impl Iterator<Item = KeyCode> + '_ {
+        self.settings.keys.iter().map(|key| key.key_code)
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Input Display"
+    }
+
+    /// Updates the component's state based on the key events received so far.
+    pub fn update_state(&self, state: &mut State) {
+        let settings = &self.settings;
+
+        state.background = settings.background;
+        state.keys_per_row = settings.keys_per_row.max(1);
+        state.released_key_color = settings.released_key_color;
+        state.pressed_key_color = settings.pressed_key_color;
+        state.released_text_color = settings.released_text_color;
+        state.pressed_text_color = settings.pressed_text_color;
+        state.show_press_counts = settings.show_press_counts;
+
+        state
+            .keys
+            .resize_with(settings.keys.len(), Default::default);
+
+        for (key, key_state) in settings.keys.iter().zip(&mut state.keys) {
+            let status = self.feed.status(key.key_code);
+
+            key_state.label.clear();
+            key_state.label.push_str(key.resolved_label());
+
+            key_state.press_count.clear();
+            if settings.show_press_counts {
+                let _ = write!(key_state.press_count, "{}", status.press_count);
+            }
+
+            key_state.is_pressed = status.is_pressed;
+        }
+
+        state.show_recent_keys = settings.recent_key_count != 0;
+
+        let mut recent_key_count = 0;
+        self.feed.for_each_recent_press(|key_code| {
+            if recent_key_count == settings.recent_key_count as usize {
+                return;
+            }
+            let Some(key) = settings.keys.iter().find(|key| key.key_code == key_code) else {
+                return;
+            };
+            if let Some(label) = state.recent_keys.get_mut(recent_key_count) {
+                label.clear();
+                label.push_str(key.resolved_label());
+            } else {
+                state.recent_keys.push(key.resolved_label().into());
+            }
+            recent_key_count += 1;
+        });
+        state.recent_keys.truncate(recent_key_count);
+    }
+
+    /// Calculates the component's state based on the key events received so
+    /// far.
+    pub fn state(&self) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut fields = vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Keys Per Row".into(),
+                u64::from(self.settings.keys_per_row).into(),
+            ),
+            Field::new(
+                "Released Key Color".into(),
+                self.settings.released_key_color.into(),
+            ),
+            Field::new(
+                "Pressed Key Color".into(),
+                self.settings.pressed_key_color.into(),
+            ),
+            Field::new(
+                "Released Text Color".into(),
+                self.settings.released_text_color.into(),
+            ),
+            Field::new(
+                "Pressed Text Color".into(),
+                self.settings.pressed_text_color.into(),
+            ),
+            Field::new(
+                "Show Press Counts".into(),
+                self.settings.show_press_counts.into(),
+            ),
+            Field::new(
+                "Recent Keys".into(),
+                u64::from(self.settings.recent_key_count).into(),
+            ),
+            Field::new("Keys".into(), Value::UInt(self.settings.keys.len() as _)),
+        ];
+
+        for key in &self.settings.keys {
+            fields.push(Field::new(
+                "Key".into(),
+                Some(Hotkey::from(key.key_code)).into(),
+            ));
+            fields.push(Field::new("Label".into(), key.label.clone().into()));
+        }
+
+        SettingsDescription::with_fields(fields)
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.keys_per_row = value.into_uint().unwrap() as _,
+            2 => self.settings.released_key_color = value.into(),
+            3 => self.settings.pressed_key_color = value.into(),
+            4 => self.settings.released_text_color = value.into(),
+            5 => self.settings.pressed_text_color = value.into(),
+            6 => self.settings.show_press_counts = value.into(),
+            7 => self.settings.recent_key_count = value.into_uint().unwrap() as _,
+            8 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings.keys.resize_with(new_len, Default::default);
+            }
+            index => {
+                let index = index - 9;
+                let key = &mut self.settings.keys[index / 2];
+                match index % 2 {
+                    0 => {
+                        // Keys can't be unassigned, so clearing the hotkey
+                        // keeps the key that was assigned before.
+                        if let Some(hotkey) = value.into_hotkey().unwrap() {
+                            key.key_code = hotkey.key_code;
+                        }
+                    }
+                    1 => key.label = value.into(),
+                    _ => unreachable!(),
+                }
+            }
+        }
+    }
+}
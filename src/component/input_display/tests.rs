@@ -0,0 +1,119 @@
+use super::{Component, Key};
+use crate::{
+    hotkey::{KeyCode, KeyEvent},
+    KeyEventFeed,
+};
+
+#[test]
+fn highlights_pressed_keys() {
+    let component = Component::new();
+    let feed = component.key_event_feed().clone();
+
+    let state = component.state();
+    let labels: Vec<_> = state.keys.iter().map(|k| &*k.label).collect();
+    assert_eq!(labels, ["←", "↑", "↓", "→"]);
+    assert!(state.keys.iter().all(|k| !k.is_pressed));
+
+    feed.push(KeyCode::ArrowUp, KeyEvent::Pressed);
+    feed.push(KeyCode::ArrowRight, KeyEvent::Pressed);
+    feed.push(KeyCode::KeyA, KeyEvent::Pressed);
+    let state = component.state();
+    let pressed: Vec<_> = state.keys.iter().map(|k| k.is_pressed).collect();
+    assert_eq!(pressed, [false, true, false, true]);
+
+    feed.push(KeyCode::ArrowUp, KeyEvent::Released);
+    let state = component.state();
+    let pressed: Vec<_> = state.keys.iter().map(|k| k.is_pressed).collect();
+    assert_eq!(pressed, [false, false, false, true]);
+}
+
+#[test]
+fn counts_presses() {
+    let mut component = Component::new();
+    component.settings_mut().keys = vec![
+        Key {
+            key_code: KeyCode::KeyZ,
+            label: Some(String::from("Jump")),
+        },
+        KeyCode::KeyX.into(),
+    ];
+    let feed = component.key_event_feed().clone();
+
+    for event in [
+        KeyEvent::Pressed,
+        // Holding the key down doesn't count as additional presses.
+        KeyEvent::Pressed,
+        KeyEvent::Released,
+        KeyEvent::Pressed,
+    ] {
+        feed.push(KeyCode::KeyZ, event);
+    }
+
+    let state = component.state();
+    assert_eq!(state.keys[0].label, "Jump");
+    assert!(state.keys.iter().all(|k| k.press_count.is_empty()));
+
+    component.settings_mut().show_press_counts = true;
+    let state = component.state();
+    assert_eq!(state.keys[0].press_count, "2");
+    assert_eq!(state.keys[1].press_count, "0");
+
+    feed.reset_press_counts();
+    let state = component.state();
+    assert_eq!(state.keys[0].press_count, "0");
+    assert!(state.keys[0].is_pressed);
+}
+
+#[test]
+fn clones_share_the_feed() {
+    let feed = KeyEventFeed::new();
+    let component = Component::new();
+    feed.push(KeyCode::ArrowLeft, KeyEvent::Pressed);
+    assert!(!component.state().keys[0].is_pressed);
+
+    let cloned = component.clone();
+    component
+        .key_event_feed()
+        .push(KeyCode::ArrowLeft, KeyEvent::Pressed);
+    assert!(cloned.state().keys[0].is_pressed);
+}
+
+#[test]
+fn shows_recently_pressed_keys() {
+    let mut component = Component::new();
+    let feed = component.key_event_feed().clone();
+
+    for key_code in [
+        KeyCode::ArrowUp,
+        KeyCode::KeyA,
+        KeyCode::ArrowLeft,
+        KeyCode::ArrowUp,
+    ] {
+        feed.push(key_code, KeyEvent::Pressed);
+        feed.push(key_code, KeyEvent::Released);
+    }
+
+    let state = component.state();
+    assert!(!state.show_recent_keys);
+    assert!(state.recent_keys.is_empty());
+
+    // Only the keys the component visualizes are shown, starting with the
+    // most recent one.
+    component.settings_mut().recent_key_count = 2;
+    let state = component.state();
+    assert!(state.show_recent_keys);
+    assert_eq!(state.recent_keys, ["↑", "←"]);
+
+    component.settings_mut().recent_key_count = 5;
+    assert_eq!(component.state().recent_keys, ["↑", "←", "↑"]);
+
+    // Only a limited number of key presses is remembered.
+    for _ in 0..20 {
+        feed.push(KeyCode::ArrowDown, KeyEvent::Pressed);
+        feed.push(KeyCode::ArrowDown, KeyEvent::Released);
+    }
+    assert_eq!(component.state().recent_keys, ["↓"; 5]);
+
+    feed.reset_press_counts();
+    assert!(component.state().recent_keys.is_empty());
+}
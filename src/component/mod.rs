@@ -3,40 +3,58 @@
 //! information is provided as state objects in a way that can easily be
 //! visualized by any kind of User Interface.
 
+pub mod attempt_counter;
 pub mod blank_space;
+pub mod clock;
 pub mod current_comparison;
 pub mod current_pace;
 pub mod delta;
 pub mod detailed_timer;
 pub mod graph;
+pub mod image;
+pub mod input_display;
 pub mod pb_chance;
 pub mod possible_time_save;
 pub mod previous_segment;
+pub mod segment_notes;
 pub mod segment_time;
 pub mod separator;
+pub mod session;
 pub mod splits;
 pub mod sum_of_best;
+pub mod survival_chance;
 pub mod text;
+pub mod time_lost;
 pub mod timer;
 pub mod title;
 pub mod total_playtime;
+pub mod variable;
 
 pub mod key_value;
 
+pub use attempt_counter::Component as AttemptCounter;
 pub use blank_space::Component as BlankSpace;
+pub use clock::Component as Clock;
 pub use current_comparison::Component as CurrentComparison;
 pub use current_pace::Component as CurrentPace;
 pub use delta::Component as Delta;
 pub use detailed_timer::Component as DetailedTimer;
 pub use graph::Component as Graph;
+pub use image::Component as Image;
+pub use input_display::Component as InputDisplay;
 pub use pb_chance::Component as PbChance;
 pub use possible_time_save::Component as PossibleTimeSave;
 pub use previous_segment::Component as PreviousSegment;
+pub use segment_notes::Component as SegmentNotes;
 pub use segment_time::Component as SegmentTime;
 pub use separator::Component as Separator;
+pub use session::Component as Session;
 pub use splits::Component as Splits;
 pub use sum_of_best::Component as SumOfBest;
+pub use survival_chance::Component as SurvivalChance;
 pub use text::Component as Text;
+pub use time_lost::Component as TimeLost;
 pub use timer::Component as Timer;
 pub use title::Component as Title;
 pub use total_playtime::Component as TotalPlaytime;
+pub use variable::Component as Variable;
@@ -3,13 +3,21 @@
 //! Personal Best. If there is no active attempt it shows the general chance of
 //! beating the Personal Best. During an attempt it actively changes based on
 //! how well the attempt is going.
+//!
+//! Optionally the chance can be determined by simulating the remainder of the
+//! attempt many times with the segment times of the history, instead of being
+//! derived from the skill curve.
 
 use super::key_value;
 use crate::{
-    analysis::pb_chance,
-    platform::prelude::*,
+    analysis::{
+        pb_chance,
+        prediction::{Predictor, DEFAULT_SAMPLES},
+    },
+    platform::{prelude::*, RwLock},
     settings::{Color, Field, Gradient, SettingsDescription, Value},
     timing::Snapshot,
+    TimerPhase,
 };
 use core::fmt::Write;
 use serde::{Deserialize, Serialize};
@@ -18,9 +26,9 @@ use serde::{Deserialize, Serialize};
 /// the Personal Best. If there is no active attempt it shows the general chance
 /// of beating the Personal Best. During an attempt it actively changes based on
 /// how well the attempt is going.
-#[derive(Default, Clone)]
 pub struct Component {
     settings: Settings,
+    predictor: RwLock<Predictor>,
 }
 
 /// The Settings for this component.
@@ -38,6 +46,13 @@ pub struct Settings {
     /// The color of the value. If `None` is specified, the color is taken from
     /// the layout.
     pub value_color: Option<Color>,
+    /// Specifies whether the chance should be determined by simulating the
+    /// remainder of the attempt many times with the segment times of the
+    /// history.
+    pub use_simulation: bool,
+    /// Specifies whether more recent segment times should be more likely to
+    /// be used by the simulation than older ones.
+    pub weight_recent_attempts: bool,
 }
 
 impl Default for Settings {
@@ -47,10 +62,25 @@ impl Default for Settings {
             display_two_rows: false,
             label_color: None,
             value_color: None,
+            use_simulation: false,
+            weight_recent_attempts: false,
         }
     }
 }
 
+impl Default for Component {
+    fn default() -> Self {
+        Self::with_settings(Default::default())
+    }
+}
+
+impl Clone for Component {
+    fn clone(&self) -> Self {
+        // The cached simulation is not worth cloning, it simply gets rerun.
+        Self::with_settings(self.settings.clone())
+    }
+}
+
 impl Component {
     /// Creates a new Possible Time Save Component.
     pub fn new() -> Self {
@@ -59,7 +89,10 @@ impl Component {
 
     /// Creates a new Possible Time Save Component with the given settings.
     pub const fn with_settings(settings: Settings) -> Self {
-        Self { settings }
+        Self {
+            settings,
+            predictor: RwLock::new(Predictor::new(DEFAULT_SAMPLES, false)),
+        }
     }
 
     /// Accesses the settings of the component.
@@ -79,7 +112,11 @@ impl Component {
 
     /// Updates the component's state based on the timer provided.
     pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
-        let (chance, is_live) = pb_chance::for_timer(timer);
+        let (chance, is_live) = if self.settings.use_simulation {
+            self.simulated_chance(timer)
+        } else {
+            pb_chance::for_timer(timer)
+        };
 
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
@@ -97,6 +134,32 @@ impl Component {
         state.updates_frequently = is_live;
     }
 
+    fn simulated_chance(&self, timer: &Snapshot<'_>) -> (f64, bool) {
+        let method = timer.current_timing_method();
+        let Some(pb_time) = timer
+            .run()
+            .segments()
+            .last()
+            .and_then(|s| s.personal_best_split_time()[method])
+        else {
+            // If there is no PB time, then it's always a 100% chance.
+            return (1.0, false);
+        };
+
+        let mut predictor = self.predictor.write().unwrap();
+        predictor.set_weight_recent(self.settings.weight_recent_attempts);
+
+        match predictor.predict(timer) {
+            Some(prediction) => (
+                prediction.probability_of_beating(pb_time),
+                timer.current_phase() == TimerPhase::Running,
+            ),
+            // Without any history for some of the remaining segments, there is
+            // nothing to simulate, so the skill curve is used instead.
+            None => pb_chance::for_timer(timer),
+        }
+    }
+
     /// Calculates the component's state based on the timer provided.
     pub fn state(&self, timer: &Snapshot<'_>) -> key_value::State {
         let mut state = Default::default();
@@ -115,6 +178,11 @@ impl Component {
             ),
             Field::new("Label Color".into(), self.settings.label_color.into()),
             Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Use Simulation".into(), self.settings.use_simulation.into()),
+            Field::new(
+                "Weight Recent Attempts".into(),
+                self.settings.weight_recent_attempts.into(),
+            ),
         ])
     }
 
@@ -131,6 +199,8 @@ impl Component {
             1 => self.settings.display_two_rows = value.into(),
             2 => self.settings.label_color = value.into(),
             3 => self.settings.value_color = value.into(),
+            4 => self.settings.use_simulation = value.into(),
+            5 => self.settings.weight_recent_attempts = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -0,0 +1,188 @@
+//! Provides the Segment Notes Component and relevant types for using it. The
+//! Segment Notes Component shows the notes the runner stored for the current
+//! segment, such as route notes.
+
+use super::key_value;
+use crate::{
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    Timer, TimerPhase,
+};
+use serde::{Deserialize, Serialize};
+
+/// The Segment Notes Component shows the notes the runner stored for the
+/// current segment, such as route notes. If the timer is not running, the notes
+/// of the first segment are shown, so that the runner can prepare for it.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether the name of the segment should be shown above its
+    /// notes.
+    pub show_segment_name: bool,
+    /// Specifies whether the notes should be interpreted as Markdown by the
+    /// visualization.
+    pub markdown: bool,
+    /// The number of lines of text the component should be tall.
+    pub lines: u32,
+    /// The color of the segment's name. If `None` is specified, the color is
+    /// taken from the layout.
+    pub name_color: Option<Color>,
+    /// The color of the notes. If `None` is specified, the color is taken from
+    /// the layout.
+    pub text_color: Option<Color>,
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The name of the segment the notes belong to. This is `None` if the name
+    /// is not supposed to be shown.
+    pub segment_name: Option<String>,
+    /// The notes of the segment. These may consist of multiple lines.
+    pub notes: String,
+    /// Specifies whether the notes are supposed to be interpreted as Markdown.
+    pub is_markdown: bool,
+    /// The number of lines of text the component should be tall. This includes
+    /// the line for the segment's name, if it's shown.
+    pub lines: u32,
+    /// The color of the segment's name. If `None` is specified, the color is
+    /// taken from the layout.
+    pub name_color: Option<Color>,
+    /// The color of the notes. If `None` is specified, the color is taken from
+    /// the layout.
+    pub text_color: Option<Color>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            show_segment_name: false,
+            markdown: false,
+            lines: 4,
+            name_color: None,
+            text_color: None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Segment Notes Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Segment Notes Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Segment Notes"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut State, timer: &Timer) {
+        state.background = self.settings.background;
+        state.is_markdown = self.settings.markdown;
+        state.name_color = self.settings.name_color;
+        state.text_color = self.settings.text_color;
+        state.lines = self.settings.lines.max(1);
+
+        let segments = timer.run().segments();
+        let segment = match timer.current_phase() {
+            TimerPhase::NotRunning => segments.first(),
+            TimerPhase::Ended => segments.last(),
+            _ => timer.current_split(),
+        };
+
+        state.notes.clear();
+        if let Some(segment) = segment {
+            state.notes.push_str(segment.notes());
+        }
+
+        if self.settings.show_segment_name {
+            let name = state.segment_name.get_or_insert_with(String::new);
+            name.clear();
+            if let Some(segment) = segment {
+                name.push_str(segment.name());
+            }
+        } else {
+            state.segment_name = None;
+        }
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Show Segment Name".into(),
+                self.settings.show_segment_name.into(),
+            ),
+            Field::new("Markdown".into(), self.settings.markdown.into()),
+            Field::new("Lines".into(), u64::from(self.settings.lines).into()),
+            Field::new("Name Color".into(), self.settings.name_color.into()),
+            Field::new("Text Color".into(), self.settings.text_color.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.show_segment_name = value.into(),
+            2 => self.settings.markdown = value.into(),
+            3 => self.settings.lines = value.into_uint().unwrap() as _,
+            4 => self.settings.name_color = value.into(),
+            5 => self.settings.text_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
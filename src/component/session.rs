@@ -0,0 +1,218 @@
+//! Provides the Session Component and relevant types for using it. The
+//! Session Component is a component that shows a statistic about the current
+//! play session, such as the number of attempts or the best time achieved
+//! during it.
+
+use super::key_value;
+use crate::{
+    analysis::sessions::CurrentSession,
+    platform::{prelude::*, RwLock},
+    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    timing::formatter::{Days, Regular, TimeFormatter},
+    TimeSpan, Timer,
+};
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+
+/// The Session Component is a component that shows a statistic about the
+/// current play session, such as the number of attempts or the best time
+/// achieved during it. If the runner explicitly declared sessions through the
+/// timer, the active session is used. Otherwise attempts that are separated by
+/// gaps no longer than the session gap are considered part of the same
+/// session.
+pub struct Component {
+    settings: Settings,
+    current_session: RwLock<CurrentSession>,
+}
+
+/// Specifies which statistic about the current session is shown.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionStatistic {
+    /// The number of attempts started during the session.
+    Attempts,
+    /// The number of attempts that were reset during the session.
+    Resets,
+    /// The number of new best segments achieved during the session.
+    BestSegments,
+    /// The fastest final time achieved during the session.
+    BestTime,
+    /// The playtime of the session.
+    Playtime,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The statistic about the current session to show.
+    pub statistic: SessionStatistic,
+    /// The longest gap in minutes between two attempts for them to still be
+    /// considered part of the same session. This is only used if no sessions
+    /// were explicitly declared.
+    pub session_gap: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            statistic: SessionStatistic::Attempts,
+            session_gap: 30,
+        }
+    }
+}
+
+impl Default for Component {
+    fn default() -> Self {
+        Self::with_settings(Default::default())
+    }
+}
+
+impl Clone for Component {
+    fn clone(&self) -> Self {
+        Self::with_settings(self.settings.clone())
+    }
+}
+
+impl Component {
+    /// Creates a new Session Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Session Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            current_session: RwLock::new(CurrentSession::new()),
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Session"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
+        let session = self.current_session.write().unwrap().calculate(
+            timer,
+            TimeSpan::from_seconds(60.0 * self.settings.session_gap as f64),
+        );
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = SemanticColor::Default;
+
+        let (key, abbreviation) = match self.settings.statistic {
+            SessionStatistic::Attempts => ("Session Attempts", "Attempts"),
+            SessionStatistic::Resets => ("Session Resets", "Resets"),
+            SessionStatistic::BestSegments => ("Session Best Segments", "Golds"),
+            SessionStatistic::BestTime => ("Session Best Time", "Best"),
+            SessionStatistic::Playtime => ("Session Playtime", "Playtime"),
+        };
+
+        state.key.clear();
+        state.key.push_str(key);
+
+        state.value.clear();
+        let _ = match self.settings.statistic {
+            SessionStatistic::Attempts => {
+                write!(state.value, "{}", session.map_or(0, |s| s.attempts))
+            }
+            SessionStatistic::Resets => {
+                write!(state.value, "{}", session.map_or(0, |s| s.resets))
+            }
+            SessionStatistic::BestSegments => {
+                write!(state.value, "{}", session.map_or(0, |s| s.best_segments))
+            }
+            SessionStatistic::BestTime => write!(
+                state.value,
+                "{}",
+                Regular::new().format(session.and_then(|s| s.best_time))
+            ),
+            SessionStatistic::Playtime => write!(
+                state.value,
+                "{}",
+                Days::new().format(session.map_or(TimeSpan::zero(), |s| s.playtime))
+            ),
+        };
+
+        state.key_abbreviations.clear();
+        state.key_abbreviations.push(abbreviation.into());
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = self.settings.statistic == SessionStatistic::Playtime
+            && timer.current_phase().is_running();
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Statistic".into(), self.settings.statistic.into()),
+            Field::new(
+                "Session Gap (Minutes)".into(),
+                self.settings.session_gap.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.statistic = value.into(),
+            5 => self.settings.session_gap = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
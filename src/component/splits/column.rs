@@ -1,15 +1,16 @@
 use crate::{
-    analysis::{self, possible_time_save, split_color},
+    analysis::{self, distributions, possible_time_save, segment_outlook::Outlook, split_color},
     comparison,
     component::splits::Settings as SplitsSettings,
-    platform::prelude::*,
+    platform::{prelude::*, RwLock},
+    run::Section,
     settings::{Color, SemanticColor},
     timing::{
-        formatter::{Delta, Regular, SegmentTime, TimeFormatter},
+        formatter::{Delta, Regular, SegmentTime, TimeFormatter, DASH},
         Snapshot,
     },
     util::Clear,
-    GeneralLayoutSettings, Segment, TimeSpan, TimingMethod,
+    GeneralLayoutSettings, Run, TimeSpan, TimingMethod,
 };
 use core::fmt::Write;
 use serde::{Deserialize, Serialize};
@@ -26,13 +27,15 @@ pub struct ColumnSettings {
     pub kind: ColumnKind,
 }
 
-/// The kind of a column. It can either be a column that shows a variable or a
-/// time.
+/// The kind of a column. It can either be a column that shows a variable, a
+/// chart or a time.
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ColumnKind {
     /// A column that shows a variable.
     Variable(VariableColumn),
+    /// A column that shows a chart of the recent segment times.
+    Chart(ChartColumn),
     /// A column that shows a time.
     Time(TimeColumn),
 }
@@ -66,6 +69,32 @@ pub struct VariableColumn {
     pub variable_name: String,
 }
 
+/// A column that shows a chart of the recent segment times.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ChartColumn {
+    /// The kind of chart to show.
+    pub chart: ChartKind,
+    /// The amount of most recent segment times to visualize in the chart.
+    #[serde(default = "default_chart_segment_count")]
+    pub segment_count: u32,
+    /// Specifies the Timing Method to use. If set to `None` the Timing Method
+    /// of the Timer is used for the segment times. Otherwise the Timing Method
+    /// provided is used.
+    #[serde(default)]
+    pub timing_method: Option<TimingMethod>,
+}
+
+/// The kind of chart shown by a chart column.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ChartKind {
+    /// A sparkline connecting the most recent segment times, from the oldest
+    /// to the newest.
+    #[default]
+    Sparkline,
+    /// A histogram showing the distribution of the most recent segment times.
+    Histogram,
+}
+
 /// Specifies the value a segment starts out with before it gets replaced
 /// with the current attempt's information when splitting.
 #[derive(Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -81,6 +110,13 @@ pub enum ColumnStartWith {
     /// The column starts out with the time that can be saved on each individual
     /// segment stored in the comparison that is being compared against.
     PossibleTimeSave,
+    /// The column starts out with the probability of getting a new best
+    /// segment on each individual segment, based on the recent segment times.
+    GoldProbability,
+    /// The column starts out with the time that is expected to be saved on
+    /// each individual segment compared to the comparison that is being
+    /// compared against, based on the recent segment times.
+    ExpectedTimeSave,
 }
 
 /// Once a certain condition is met, which is usually being on the split or
@@ -113,6 +149,16 @@ pub enum ColumnUpdateWith {
     /// Segment component. If there is no time saved or lost, then value gets
     /// replaced by the current attempt's segment time instead.
     SegmentDeltaWithFallback,
+    /// While the segment is active, the value gets replaced by the probability
+    /// of getting a new best segment, which shrinks as the segment goes on.
+    /// Once the segment ends, it shows whether the current attempt got a new
+    /// best segment.
+    GoldProbability,
+    /// While the segment is active, the value gets replaced by the time that
+    /// is expected to be saved compared to the comparison, which shrinks as
+    /// the segment goes on. Once the segment ends, it shows the time that the
+    /// current attempt actually saved compared to the comparison.
+    ExpectedTimeSave,
 }
 
 /// Specifies when a column's value gets updated.
@@ -139,6 +185,20 @@ impl Default for ColumnSettings {
     }
 }
 
+impl Default for ChartColumn {
+    fn default() -> Self {
+        ChartColumn {
+            chart: ChartKind::Sparkline,
+            segment_count: default_chart_segment_count(),
+            timing_method: None,
+        }
+    }
+}
+
+const fn default_chart_segment_count() -> u32 {
+    20
+}
+
 impl Default for TimeColumn {
     fn default() -> Self {
         TimeColumn {
@@ -163,31 +223,129 @@ pub struct ColumnState {
     /// This value indicates whether the column is currently frequently being
     /// updated. This can be used for rendering optimizations.
     pub updates_frequently: bool,
+    /// The chart to visualize instead of the value. If this is `None`, the
+    /// column shows its value as text. The chart is supposed to be drawn in
+    /// the visual color.
+    pub chart: Option<ColumnChart>,
+}
+
+/// A chart of the recent segment times of a segment, shown in a column instead
+/// of a text value. All values are in the range `0..=1`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ColumnChart {
+    /// The heights of the points of a sparkline, from the oldest to the newest
+    /// segment time. The shortest segment time is at the height 0 and the
+    /// longest one at the height 1. If the segment got completed in the
+    /// current attempt, its segment time is the newest one.
+    Sparkline(Vec<f32>),
+    /// The heights of the bins of a histogram, relative to the bin containing
+    /// the most segment times. The bins evenly divide the interval from the
+    /// shortest to the longest segment time.
+    Histogram(Vec<f32>),
 }
 
 impl Clear for ColumnState {
     fn clear(&mut self) {
         self.value.clear();
+        self.chart = None;
     }
 }
 
+const HISTOGRAM_BIN_COUNT: usize = 8;
+
 enum ColumnFormatter {
     Time,
     Delta,
     SegmentTime,
+    Percentage(Option<f64>),
+}
+
+/// The value of a time column, along with whether it is a live value.
+type UpdateValue = ((Option<TimeSpan>, SemanticColor, ColumnFormatter), bool);
+
+/// The state shared by all the columns of the splits that get updated.
+pub struct UpdateContext<'a> {
+    pub timer: &'a Snapshot<'a>,
+    pub splits_settings: &'a SplitsSettings,
+    pub layout_settings: &'a GeneralLayoutSettings,
+    pub current_split: Option<usize>,
+    pub method: TimingMethod,
+    pub outlooks: &'a OutlookCache,
+}
+
+/// Caches the outlooks of the segments for both timing methods. Collecting the
+/// segment times of an outlook requires iterating over the segment histories,
+/// so this only happens again once they change.
+pub struct OutlookCache(RwLock<Vec<[Option<CachedOutlook>; 2]>>);
+
+/// An outlook along with the revisions of the segment histories it was
+/// collected from.
+type CachedOutlook = ((usize, usize), Outlook);
+
+impl Default for OutlookCache {
+    fn default() -> Self {
+        Self(RwLock::new(Vec::new()))
+    }
 }
 
+impl Clone for OutlookCache {
+    fn clone(&self) -> Self {
+        Default::default()
+    }
+}
+
+impl OutlookCache {
+    fn with<R>(
+        &self,
+        run: &Run,
+        segment_index: usize,
+        method: TimingMethod,
+        f: impl FnOnce(&Outlook) -> R,
+    ) -> R {
+        // The outlook of a segment also depends on the previous segment's
+        // history, as combined segment times are skipped.
+        let key = (
+            run.segment(segment_index).segment_history().revision(),
+            segment_index
+                .checked_sub(1)
+                .map_or(0, |i| run.segment(i).segment_history().revision()),
+        );
+
+        let mut outlooks = self.0.write().unwrap();
+        if outlooks.len() != run.len() {
+            outlooks.clear();
+            outlooks.resize_with(run.len(), Default::default);
+        }
+
+        let (cached_key, outlook) = outlooks[segment_index][method as usize]
+            .get_or_insert_with(|| (key, Outlook::new(run, segment_index, method)));
+        if *cached_key != key {
+            *cached_key = key;
+            *outlook = Outlook::new(run, segment_index, method);
+        }
+        f(outlook)
+    }
+}
+
+/// Updates the column of a row showing either a single segment or a section
+/// with its subsplits collapsed. The segment based values, such as segment
+/// times and segment deltas, are combined over all the segments of the
+/// section, while everything else is based on the segment ending it.
 pub fn update_state(
     state: &mut ColumnState,
     column_settings: &ColumnSettings,
-    timer: &Snapshot<'_>,
-    splits_settings: &SplitsSettings,
-    layout_settings: &GeneralLayoutSettings,
-    segment: &Segment,
-    segment_index: usize,
-    current_split: Option<usize>,
-    method: TimingMethod,
+    context: &UpdateContext<'_>,
+    section: Section,
 ) {
+    let UpdateContext {
+        timer,
+        layout_settings,
+        current_split,
+        method,
+        ..
+    } = *context;
+    let segment_index = section.end();
+    let segment = timer.run().segment(segment_index);
     match &column_settings.kind {
         ColumnKind::Variable(column) => {
             state.value.clear();
@@ -211,46 +369,176 @@ pub fn update_state(
             state.semantic_color = SemanticColor::Default;
             state.visual_color = layout_settings.text_color;
             state.updates_frequently = false;
+            state.chart = None;
+        }
+        ColumnKind::Chart(column) => {
+            state.value.clear();
+            update_chart_column(state, column, timer, segment_index, current_split, method);
+            state.semantic_color = SemanticColor::Default;
+            state.visual_color = layout_settings.text_color;
+            state.updates_frequently = false;
         }
         ColumnKind::Time(column) => {
-            update_time_column(
-                state,
-                column,
+            state.chart = None;
+            update_time_column(state, column, context, section);
+        }
+    }
+}
+
+/// Updates the column of a row summarizing the hidden segments from `start` to
+/// `end` (inclusive). Only delta columns show a value, which is the combined
+/// delta of the summarized segments, once all of them are completed.
+pub fn update_summary_state(
+    state: &mut ColumnState,
+    column_settings: &ColumnSettings,
+    context: &UpdateContext<'_>,
+    start: usize,
+    end: usize,
+) {
+    let UpdateContext {
+        timer,
+        splits_settings,
+        layout_settings,
+        current_split,
+        method,
+        ..
+    } = *context;
+    state.value.clear();
+    state.semantic_color = SemanticColor::Default;
+    state.updates_frequently = false;
+    state.chart = None;
+
+    if let ColumnKind::Time(column) = &column_settings.kind {
+        if column.update_with.is_delta() && current_split.is_some_and(|i| end < i) {
+            let method = column.timing_method.unwrap_or(method);
+            let comparison = comparison::or_current(
+                comparison::resolve(&column.comparison_override, timer),
                 timer,
-                splits_settings,
-                layout_settings,
-                segment,
-                segment_index,
-                current_split,
-                method,
             );
+            let run = timer.run();
+
+            if let Some(delta) = analysis::last_delta(run, end, comparison, method) {
+                let previous_delta = start
+                    .checked_sub(1)
+                    .and_then(|i| analysis::last_delta(run, i, comparison, method))
+                    .unwrap_or_default();
+                let combined_delta = delta - previous_delta;
+
+                state.semantic_color =
+                    match (delta < TimeSpan::zero(), combined_delta < TimeSpan::zero()) {
+                        (true, true) => SemanticColor::AheadGainingTime,
+                        (true, false) => SemanticColor::AheadLosingTime,
+                        (false, true) => SemanticColor::BehindGainingTime,
+                        (false, false) => SemanticColor::BehindLosingTime,
+                    };
+                let _ = write!(
+                    state.value,
+                    "{}",
+                    Delta::custom(
+                        splits_settings.delta_drop_decimals,
+                        splits_settings.delta_time_accuracy,
+                    )
+                    .format(combined_delta)
+                );
+            }
         }
     }
+
+    state.visual_color = state.semantic_color.visualize(layout_settings);
 }
 
-fn update_time_column(
+fn update_chart_column(
     state: &mut ColumnState,
-    column_settings: &TimeColumn,
+    column: &ChartColumn,
     timer: &Snapshot<'_>,
-    splits_settings: &SplitsSettings,
-    layout_settings: &GeneralLayoutSettings,
-    segment: &Segment,
     segment_index: usize,
     current_split: Option<usize>,
     method: TimingMethod,
 ) {
+    let method = column.timing_method.unwrap_or(method);
+    let count = column.segment_count as usize;
+
+    let mut values = match state.chart.take() {
+        Some(ColumnChart::Sparkline(values) | ColumnChart::Histogram(values)) => values,
+        None => Vec::new(),
+    };
+    values.clear();
+
+    // The segment times are collected from the newest to the oldest.
+    if current_split > Some(segment_index) && count > 0 {
+        if let Some(time) = analysis::previous_segment_time(timer, segment_index, method) {
+            values.push(time.total_seconds() as f32);
+        }
+    }
+    let remaining = count - values.len();
+    values.extend(
+        distributions::segment_times(timer.run().segments(), segment_index, method)
+            .rev()
+            .take(remaining)
+            .map(|time| time as f32),
+    );
+
+    let Some((min, max)) = values.iter().fold(None, |range, &time| {
+        Some(range.map_or((time, time), |(min, max): (f32, f32)| {
+            (min.min(time), max.max(time))
+        }))
+    }) else {
+        return;
+    };
+    let range = max - min;
+
+    state.chart = Some(match column.chart {
+        ChartKind::Sparkline => {
+            values.reverse();
+            for value in &mut values {
+                *value = if range > 0.0 {
+                    (*value - min) / range
+                } else {
+                    0.5
+                };
+            }
+            ColumnChart::Sparkline(values)
+        }
+        ChartKind::Histogram => {
+            let mut bins = [0u32; HISTOGRAM_BIN_COUNT];
+            for &time in &values {
+                let bin = if range > 0.0 {
+                    ((time - min) / range * HISTOGRAM_BIN_COUNT as f32) as usize
+                } else {
+                    HISTOGRAM_BIN_COUNT / 2
+                };
+                bins[bin.min(HISTOGRAM_BIN_COUNT - 1)] += 1;
+            }
+            let highest = bins.iter().copied().max().unwrap_or_default().max(1) as f32;
+
+            values.clear();
+            values.extend(bins.iter().map(|&count| count as f32 / highest));
+            ColumnChart::Histogram(values)
+        }
+    });
+}
+
+fn update_time_column(
+    state: &mut ColumnState,
+    column_settings: &TimeColumn,
+    context: &UpdateContext<'_>,
+    section: Section,
+) {
+    let UpdateContext {
+        timer,
+        splits_settings,
+        layout_settings,
+        method,
+        outlooks,
+        ..
+    } = *context;
+    let segment_index = section.end();
+    let segment = timer.run().segment(segment_index);
     let method = column_settings.timing_method.unwrap_or(method);
     let resolved_comparison = comparison::resolve(&column_settings.comparison_override, timer);
     let comparison = comparison::or_current(resolved_comparison, timer);
-    let update_value = time_column_update_value(
-        column_settings,
-        timer,
-        segment,
-        segment_index,
-        current_split,
-        method,
-        comparison,
-    );
+    let update_value =
+        time_column_update_value(column_settings, context, section, method, comparison);
     let updated = update_value.is_some();
     let ((column_value, semantic_color, formatter), is_live) = update_value.unwrap_or_else(|| {
         (
@@ -262,12 +550,7 @@ fn update_time_column(
                     ColumnFormatter::Time,
                 ),
                 ColumnStartWith::ComparisonSegmentTime => (
-                    analysis::comparison_combined_segment_time(
-                        timer.run(),
-                        segment_index,
-                        comparison,
-                        method,
-                    ),
+                    analysis::comparison_section_time(timer.run(), section, comparison, method),
                     SemanticColor::Default,
                     ColumnFormatter::SegmentTime,
                 ),
@@ -276,12 +559,33 @@ fn update_time_column(
                     SemanticColor::Default,
                     ColumnFormatter::SegmentTime,
                 ),
+                ColumnStartWith::GoldProbability => (
+                    None,
+                    SemanticColor::Default,
+                    ColumnFormatter::Percentage(outlooks.with(
+                        timer.run(),
+                        segment_index,
+                        method,
+                        |outlook| outlook.gold_probability(timer.run()),
+                    )),
+                ),
+                ColumnStartWith::ExpectedTimeSave => (
+                    outlooks.with(timer.run(), segment_index, method, |outlook| {
+                        outlook.expected_time_save(timer.run(), comparison)
+                    }),
+                    SemanticColor::Default,
+                    ColumnFormatter::SegmentTime,
+                ),
             },
             false,
         )
     });
     let is_empty = column_settings.start_with == ColumnStartWith::Empty && !updated;
-    state.updates_frequently = is_live && column_value.is_some();
+    let has_value = match formatter {
+        ColumnFormatter::Percentage(percentage) => percentage.is_some(),
+        _ => column_value.is_some(),
+    };
+    state.updates_frequently = is_live && has_value;
     state.value.clear();
     if !is_empty {
         let _ = match formatter {
@@ -307,6 +611,10 @@ fn update_time_column(
                         .format(column_value)
                 )
             }
+            ColumnFormatter::Percentage(Some(percentage)) => {
+                write!(state.value, "{:.0}%", 100.0 * percentage)
+            }
+            ColumnFormatter::Percentage(None) => write!(state.value, "{DASH}"),
         };
     }
     state.semantic_color = semantic_color;
@@ -315,15 +623,23 @@ fn update_time_column(
 
 fn time_column_update_value(
     column: &TimeColumn,
-    timer: &Snapshot<'_>,
-    segment: &Segment,
-    segment_index: usize,
-    current_split: Option<usize>,
+    context: &UpdateContext<'_>,
+    section: Section,
     method: TimingMethod,
     comparison: &str,
-) -> Option<((Option<TimeSpan>, SemanticColor, ColumnFormatter), bool)> {
+) -> Option<UpdateValue> {
     use self::{ColumnUpdateTrigger::*, ColumnUpdateWith::*};
 
+    let UpdateContext {
+        timer,
+        current_split,
+        outlooks,
+        ..
+    } = *context;
+
+    let segment_index = section.end();
+    let segment = timer.run().segment(segment_index);
+
     if current_split < Some(segment_index) {
         // Didn't reach the segment yet.
         return None;
@@ -394,21 +710,21 @@ fn time_column_update_value(
         ),
 
         (SegmentTime, false) => (
-            analysis::previous_segment_time(timer, segment_index, method),
+            analysis::previous_section_time(timer, section, method),
             SemanticColor::Default,
             ColumnFormatter::SegmentTime,
         ),
         (SegmentTime, true) => (
-            analysis::live_segment_time(timer, segment_index, method),
+            analysis::live_section_time(timer, section, method),
             SemanticColor::Default,
             ColumnFormatter::SegmentTime,
         ),
 
         (SegmentDelta | SegmentDeltaWithFallback, false) => {
-            let delta = analysis::previous_segment_delta(timer, segment_index, comparison, method);
+            let delta = analysis::previous_section_delta(timer, section, comparison, method);
             let (value, formatter) = if delta.is_none() && column.update_with.has_fallback() {
                 (
-                    analysis::previous_segment_time(timer, segment_index, method),
+                    analysis::previous_section_time(timer, section, method),
                     ColumnFormatter::SegmentTime,
                 )
             } else {
@@ -421,10 +737,52 @@ fn time_column_update_value(
             )
         }
         (SegmentDelta | SegmentDeltaWithFallback, true) => (
-            analysis::live_segment_delta(timer, segment_index, comparison, method),
+            analysis::live_section_delta(timer, section, comparison, method),
             SemanticColor::Default,
             ColumnFormatter::Delta,
         ),
+
+        (GoldProbability, false) => {
+            let got_gold = analysis::previous_segment_time(timer, segment_index, method)
+                .map(|_| analysis::check_best_segment(timer, segment_index, method));
+            (
+                None,
+                if got_gold == Some(true) {
+                    SemanticColor::BestSegment
+                } else {
+                    SemanticColor::Default
+                },
+                ColumnFormatter::Percentage(got_gold.map(|g| if g { 1.0 } else { 0.0 })),
+            )
+        }
+        (GoldProbability, true) => (
+            None,
+            SemanticColor::Default,
+            ColumnFormatter::Percentage(outlooks.with(
+                timer.run(),
+                segment_index,
+                method,
+                |outlook| outlook.live_gold_probability(timer),
+            )),
+        ),
+
+        (ExpectedTimeSave, false) => (
+            catch! {
+                let comparison_time =
+                    analysis::comparison_section_time(timer.run(), section, comparison, method)?;
+                let segment_time = analysis::previous_section_time(timer, section, method)?;
+                (comparison_time - segment_time).max(TimeSpan::zero())
+            },
+            SemanticColor::Default,
+            ColumnFormatter::SegmentTime,
+        ),
+        (ExpectedTimeSave, true) => (
+            outlooks.with(timer.run(), segment_index, method, |outlook| {
+                outlook.live_expected_time_save(timer, comparison)
+            }),
+            SemanticColor::Default,
+            ColumnFormatter::SegmentTime,
+        ),
     };
 
     Some((value, is_live))
@@ -433,7 +791,22 @@ fn time_column_update_value(
 impl ColumnUpdateWith {
     const fn is_segment_based(self) -> bool {
         use ColumnUpdateWith::*;
-        matches!(self, SegmentDelta | SegmentTime | SegmentDeltaWithFallback)
+        matches!(
+            self,
+            SegmentDelta
+                | SegmentTime
+                | SegmentDeltaWithFallback
+                | GoldProbability
+                | ExpectedTimeSave
+        )
+    }
+
+    const fn is_delta(self) -> bool {
+        use ColumnUpdateWith::*;
+        matches!(
+            self,
+            Delta | DeltaWithFallback | SegmentDelta | SegmentDeltaWithFallback
+        )
     }
 
     const fn has_fallback(self) -> bool {
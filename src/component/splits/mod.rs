@@ -3,19 +3,26 @@
 //! Each [`Segment`](crate::run::Segment) is shown in a tabular fashion showing
 //! the segment icon, segment name, the delta compared to the chosen comparison,
 //! and the split time. The list provides scrolling functionality, so not every
-//! [`Segment`](crate::run::Segment) needs to be shown all the time.
+//! [`Segment`](crate::run::Segment) needs to be shown all the time. The
+//! segments can optionally be grouped into [`Section`]s, whose subsplits can
+//! be collapsed.
 
 use crate::{
+    analysis,
     platform::prelude::*,
+    run::Section,
     settings::{
         self, CachedImageId, Color, Field, Gradient, ImageData, ListGradient, SettingsDescription,
         Value,
     },
-    timing::{formatter::Accuracy, Snapshot},
+    timing::{formatter::Accuracy, Snapshot, TimeStamp},
     util::{Clear, ClearVec},
-    GeneralLayoutSettings,
+    GeneralLayoutSettings, Run,
+};
+use core::{
+    cmp::{max, min},
+    fmt::Write,
 };
-use core::cmp::{max, min};
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -24,13 +31,17 @@ mod tests;
 mod column;
 
 pub use column::{
-    ColumnKind, ColumnSettings, ColumnStartWith, ColumnState, ColumnUpdateTrigger,
-    ColumnUpdateWith, TimeColumn, VariableColumn,
+    ChartColumn, ChartKind, ColumnChart, ColumnKind, ColumnSettings, ColumnStartWith, ColumnState,
+    ColumnUpdateTrigger, ColumnUpdateWith, TimeColumn, VariableColumn,
 };
 
-const SETTINGS_BEFORE_COLUMNS: usize = 15;
+const SETTINGS_BEFORE_COLUMNS: usize = 24;
 const SETTINGS_PER_TIME_COLUMN: usize = 6;
 const SETTINGS_PER_VARIABLE_COLUMN: usize = 2;
+const SETTINGS_PER_CHART_COLUMN: usize = 5;
+
+/// The duration of the scrolling animation in seconds.
+const SCROLL_ANIMATION_DURATION: f32 = 0.25;
 
 /// The Splits Component is the main component for visualizing all the split
 /// times. Each [`Segment`](crate::run::Segment) is shown in a tabular fashion
@@ -44,6 +55,85 @@ pub struct Component {
     settings: Settings,
     current_split_index: Option<usize>,
     scroll_offset: isize,
+    rows: Vec<Row>,
+    visible_rows: Vec<Row>,
+    window_start: Option<usize>,
+    scroll_animation: Option<(TimeStamp, f32)>,
+    outlooks: column::OutlookCache,
+}
+
+/// A single row of the list of splits.
+#[derive(Copy, Clone)]
+enum Row {
+    /// The row shows the segment with the index provided.
+    Segment { index: usize, is_subsplit: bool },
+    /// The row shows a section with its subsplits collapsed.
+    CollapsedSection(Section),
+    /// The row is the header of a section with its subsplits expanded.
+    SectionHeader(Section),
+    /// The row summarizes the hidden rows covering the segments from `start`
+    /// to `end` (inclusive), which consist of `count` segments.
+    Summary {
+        start: usize,
+        end: usize,
+        count: usize,
+    },
+}
+
+impl Row {
+    const fn covers(&self, segment_index: usize) -> bool {
+        match *self {
+            Row::Segment { index, .. } => index == segment_index,
+            Row::CollapsedSection(section) => section.contains(segment_index),
+            Row::SectionHeader(_) => false,
+            Row::Summary { start, end, .. } => start <= segment_index && segment_index <= end,
+        }
+    }
+
+    /// The first and the last segment covered by the row. Section headers
+    /// don't cover any segments.
+    const fn segments(&self) -> Option<(usize, usize)> {
+        match *self {
+            Row::Segment { index, .. } => Some((index, index)),
+            Row::CollapsedSection(section) => Some((section.start(), section.end())),
+            Row::SectionHeader(_) => None,
+            Row::Summary { start, end, .. } => Some((start, end)),
+        }
+    }
+
+    /// Merges the row into the summary of the hidden rows in front of it.
+    const fn summarize(summary: Option<Row>, row: &Row) -> Option<Row> {
+        let Some((start, end)) = row.segments() else {
+            return summary;
+        };
+        let count = match *row {
+            Row::Summary { count, .. } => count,
+            _ => end - start + 1,
+        };
+        Some(match summary {
+            Some(Row::Summary {
+                start, count: c, ..
+            }) => Row::Summary {
+                start,
+                end,
+                count: c + count,
+            },
+            _ => Row::Summary { start, end, count },
+        })
+    }
+}
+
+/// Specifies when the subsplits of the sections are shown by the Splits
+/// Component.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SubsplitVisibility {
+    /// Only the subsplits of the section the current segment belongs to are
+    /// shown. All the other sections are collapsed.
+    CurrentSection,
+    /// The subsplits of all the sections are always shown.
+    Always,
+    /// The subsplits are never shown. All the sections are collapsed.
+    Never,
 }
 
 /// The Settings for this component.
@@ -63,6 +153,12 @@ pub struct Settings {
     /// segments to be shown in this scrolling window when it automatically
     /// scrolls.
     pub split_preview_count: usize,
+    /// When the window showing the segments automatically scrolls, this
+    /// determines the maximum number of past segments to keep shown above the
+    /// current segment, so the window focuses on the current segment and the
+    /// remaining space is used for future segments. If this is set to 0, the
+    /// window only scrolls once the upcoming segments wouldn't fit otherwise.
+    pub past_split_count: usize,
     /// Specifies whether thin separators should be shown between the individual
     /// segments shown by the component.
     pub show_thin_separators: bool,
@@ -98,6 +194,43 @@ pub struct Settings {
     pub delta_drop_decimals: bool,
     /// Specifies whether to show the names of the columns above the splits.
     pub show_column_labels: bool,
+    /// Specifies whether the segments are grouped into sections, following the
+    /// naming convention of LiveSplit's Subsplits. Subsplits are segments whose
+    /// names start with `-`. They belong to the section of the next segment
+    /// that isn't a subsplit, which may specify the section's name in curly
+    /// braces, such as `{World 1}Boss`. If this is disabled, the segment names
+    /// are shown as they are.
+    pub group_subsplits: bool,
+    /// Specifies when the subsplits of the sections are shown. Sections whose
+    /// subsplits are not shown are collapsed into a single row showing the
+    /// name of the section and the times of the segment ending the section.
+    pub subsplit_visibility: SubsplitVisibility,
+    /// Specifies whether only the section the current segment belongs to is
+    /// shown.
+    pub current_section_only: bool,
+    /// Specifies whether the segments of sections with their subsplits shown
+    /// are indented.
+    pub indent_subsplits: bool,
+    /// Specifies whether a header row is shown above the subsplits of a
+    /// section. The header shows the name of the section and the times of the
+    /// segment ending the section.
+    pub show_section_header: bool,
+    /// Specifies whether the segments that are hidden, either because they are
+    /// scrolled out of the window or because of the compact mode, are
+    /// summarized by a row such as `… 47 splits …`. The summary row takes up
+    /// one of the rows of the window. The delta columns of a summary of
+    /// completed segments show the combined delta of those segments.
+    pub show_hidden_split_summaries: bool,
+    /// Specifies whether only the completed segments where the delta changed
+    /// color are shown, meaning the attempt switched between being ahead and
+    /// behind the current comparison or between gaining and losing time at
+    /// those segments. The most recently completed segment, as well as the
+    /// current and the upcoming segments are always shown.
+    pub compact_mode: bool,
+    /// Specifies whether the window of segments is supposed to scroll smoothly.
+    /// The state then provides the offset to visualize the splits with while
+    /// the scrolling animation is in progress.
+    pub smooth_scrolling: bool,
     /// The columns to show on the splits. These can be configured in various
     /// way to show split times, segment times, deltas and so on. The columns
     /// are defined from right to left.
@@ -117,9 +250,24 @@ pub struct SplitState {
     pub is_current_split: bool,
     /// The index of the segment based on all the segments of the run. This may
     /// differ from the index of this `SplitState` in the `State` object, as
-    /// there can be a scrolling window, showing only a subset of segments. Each
-    /// index is guaranteed to be unique.
+    /// there can be a scrolling window, showing only a subset of segments. A
+    /// collapsed section uses the index of its last segment. Section headers
+    /// and the rows summarizing hidden segments don't show a single segment, so
+    /// they use indices beyond the segments of the run. Each index is
+    /// guaranteed to be unique.
     pub index: usize,
+    /// Describes if this segment is a subsplit of a section with its subsplits
+    /// shown and is supposed to be indented.
+    pub is_subsplit: bool,
+    /// Describes if this is the header of a section with its subsplits shown,
+    /// rather than an actual segment.
+    pub is_section_header: bool,
+    /// Describes if this row shows a section with its subsplits collapsed,
+    /// rather than a single segment.
+    pub is_collapsed_section: bool,
+    /// Describes if this row summarizes multiple segments that are hidden,
+    /// rather than being an actual segment.
+    pub is_summary: bool,
 }
 
 impl Clear for SplitState {
@@ -178,6 +326,13 @@ pub struct State {
     /// The gradient to show behind the current segment as an indicator of it
     /// being the current segment.
     pub current_split_gradient: Gradient,
+    /// Specifies whether the subsplits are supposed to be indented.
+    pub indent_subsplits: bool,
+    /// The offset in rows by which the splits are supposed to be shifted
+    /// downwards while the window of segments is smoothly scrolling. The offset
+    /// moves towards 0 over the course of the scrolling animation and is 0 when
+    /// no animation is in progress.
+    pub scroll_offset: f32,
 }
 
 impl Default for Settings {
@@ -189,6 +344,7 @@ impl Default for Settings {
             ),
             visual_split_count: 16,
             split_preview_count: 1,
+            past_split_count: 0,
             show_thin_separators: true,
             separator_last_split: true,
             always_show_last_split: true,
@@ -203,6 +359,14 @@ impl Default for Settings {
             delta_time_accuracy: Accuracy::Tenths,
             delta_drop_decimals: true,
             show_column_labels: false,
+            group_subsplits: false,
+            subsplit_visibility: SubsplitVisibility::CurrentSection,
+            current_section_only: false,
+            indent_subsplits: true,
+            show_section_header: true,
+            show_hidden_split_summaries: false,
+            compact_mode: false,
+            smooth_scrolling: false,
             columns: vec![
                 ColumnSettings {
                     name: String::from("Time"),
@@ -307,54 +471,113 @@ impl Component {
         let run = timer.run();
         self.icon_ids.resize(run.len(), CachedImageId::default());
 
+        let current_split = timer.current_split_index();
+        let method = timer.current_timing_method();
+
+        self.update_rows(timer, current_split);
+        let row_count = self.rows.len();
+        let current_row = current_split.map(|current_split| {
+            self.rows
+                .iter()
+                .position(|row| row.covers(current_split))
+                .unwrap_or(row_count)
+        });
+
         let mut visual_split_count = self.settings.visual_split_count;
         if visual_split_count == 0 {
-            visual_split_count = run.len();
+            visual_split_count = row_count;
         }
 
-        let current_split = timer.current_split_index();
-        let method = timer.current_timing_method();
-
-        let locked_last_split = isize::from(self.settings.always_show_last_split);
-        let skip_count = min(
-            current_split.map_or(0, |current_split| {
-                max(
-                    0,
-                    current_split as isize
-                        + self.settings.split_preview_count as isize
-                        + locked_last_split
-                        + 1
-                        - visual_split_count as isize,
-                )
-            }),
-            run.len() as isize - visual_split_count as isize,
-        );
-        self.scroll_offset = min(
-            max(self.scroll_offset, -skip_count),
-            run.len() as isize - skip_count - visual_split_count as isize,
-        );
-        let skip_count = max(0, skip_count + self.scroll_offset) as usize;
-        let take_count = visual_split_count - locked_last_split as usize;
         let always_show_last_split = self.settings.always_show_last_split;
+        let locked_last_split = usize::from(always_show_last_split);
+        // The summaries need to leave at least one row of the window for the
+        // segments themselves.
+        let show_summaries = self.settings.show_hidden_split_summaries
+            && visual_split_count >= 3 + locked_last_split;
+
+        // Summarizing the hidden rows takes up rows of the window, which in turn
+        // may cause more rows to be hidden. So the window is determined again
+        // until the amount of summaries settles.
+        let mut summary_count = 0;
+        let (skip_count, take_count, scroll_offset, top_summary, bottom_summary) = loop {
+            let window_size = visual_split_count - summary_count;
+            let (skip_count, scroll_offset) = self.window(row_count, current_row, window_size);
+            let take_count = window_size.saturating_sub(locked_last_split);
+            let top_summary = show_summaries && skip_count > 0;
+            let bottom_summary =
+                show_summaries && always_show_last_split && skip_count + take_count + 1 < row_count;
+            let new_summary_count = usize::from(top_summary) + usize::from(bottom_summary);
+            if new_summary_count <= summary_count {
+                // The window may have been sized for more summaries than are
+                // needed in the end, so the rows left over go to the segments.
+                let take_count = take_count + (summary_count - new_summary_count);
+                let bottom_summary = bottom_summary && skip_count + take_count + 1 < row_count;
+                break (
+                    skip_count,
+                    take_count,
+                    scroll_offset,
+                    top_summary,
+                    bottom_summary,
+                );
+            }
+            summary_count = new_summary_count;
+        };
+        self.scroll_offset = scroll_offset;
 
         let show_final_separator = self.settings.separator_last_split
             && always_show_last_split
-            && skip_count + take_count + 1 < run.len();
+            && skip_count + take_count + 1 < row_count;
+
+        let window_start = min(skip_count, row_count);
+        let window_end = min(skip_count + take_count, row_count);
+        self.visible_rows.clear();
+        if top_summary {
+            self.visible_rows
+                .extend(self.rows[..window_start].iter().fold(None, Row::summarize));
+        }
+        self.visible_rows
+            .extend_from_slice(&self.rows[window_start..window_end]);
+        if always_show_last_split && window_end < row_count {
+            if bottom_summary {
+                self.visible_rows.extend(
+                    self.rows[window_end..row_count - 1]
+                        .iter()
+                        .fold(None, Row::summarize),
+                );
+            }
+            self.visible_rows.push(self.rows[row_count - 1]);
+        }
+
+        let animation_offset = if self.settings.smooth_scrolling {
+            self.update_scroll_animation(window_start)
+        } else {
+            self.scroll_animation = None;
+            0.0
+        };
+        self.window_start = Some(window_start);
+
+        let Self {
+            icon_ids,
+            settings,
+            visible_rows,
+            ..
+        } = self;
 
         let Settings {
             show_thin_separators,
             fill_with_blank_space,
             display_two_rows,
+            group_subsplits,
             ref columns,
             ..
-        } = self.settings;
+        } = *settings;
 
-        state.background = self.settings.background;
+        state.background = settings.background;
 
-        if self.settings.show_column_labels {
+        if settings.show_column_labels {
             let column_labels = state.column_labels.get_or_insert_with(Default::default);
             column_labels.clear();
-            for c in &self.settings.columns {
+            for c in &settings.columns {
                 column_labels.push().push_str(&c.name);
             }
         } else {
@@ -364,54 +587,105 @@ impl Component {
         let icon_changes = &mut state.icon_changes;
         icon_changes.clear();
 
+        let context = column::UpdateContext {
+            timer,
+            splits_settings: settings,
+            layout_settings,
+            current_split,
+            method,
+            outlooks: &self.outlooks,
+        };
+
         state.splits.clear();
-        for ((i, segment), icon_id) in run
-            .segments()
-            .iter()
-            .enumerate()
-            .zip(self.icon_ids.iter_mut())
-            .skip(skip_count)
-            .filter(|&((i, _), _)| {
-                i - skip_count < take_count || (always_show_last_split && i + 1 == run.len())
-            })
-        {
+        for &row in &*visible_rows {
             let state = state.splits.push_with(|| SplitState {
                 name: String::new(),
                 columns: ClearVec::new(),
                 is_current_split: false,
                 index: 0,
+                is_subsplit: false,
+                is_section_header: false,
+                is_collapsed_section: false,
+                is_summary: false,
             });
 
-            if let Some(icon_change) = icon_id.update_with(Some(segment.icon())) {
-                icon_changes.push(IconChange {
-                    segment_index: i,
-                    icon: icon_change.into(),
-                });
+            let (section, name, is_current_split) = match row {
+                Row::Segment { index, .. } => {
+                    let segment = run.segment(index);
+                    let name = if group_subsplits {
+                        segment.display_name()
+                    } else {
+                        segment.name()
+                    };
+                    (
+                        Section::new(index, index),
+                        name,
+                        current_split == Some(index),
+                    )
+                }
+                Row::CollapsedSection(section) => (
+                    section,
+                    section.name(run.segments()),
+                    current_split.is_some_and(|i| section.contains(i)),
+                ),
+                Row::SectionHeader(section) => (section, section.name(run.segments()), false),
+                Row::Summary { start, end, .. } => (Section::new(start, end), "", false),
+            };
+            let i = section.end();
+            let segment = run.segment(i);
+
+            if let Row::Segment { .. } | Row::CollapsedSection(_) = row {
+                if let Some(icon_change) = icon_ids[i].update_with(Some(segment.icon())) {
+                    icon_changes.push(IconChange {
+                        segment_index: i,
+                        icon: icon_change.into(),
+                    });
+                }
             }
 
-            state.name.push_str(segment.name());
+            if let Row::Summary { count, .. } = row {
+                let _ = write!(
+                    state.name,
+                    "… {count} {} …",
+                    if count == 1 { "split" } else { "splits" },
+                );
+            } else {
+                state.name.push_str(name);
+            }
 
             for column in columns {
-                column::update_state(
-                    state.columns.push_with(|| ColumnState {
-                        value: String::new(),
-                        semantic_color: Default::default(),
-                        visual_color: Color::transparent(),
-                        updates_frequently: false,
-                    }),
-                    column,
-                    timer,
-                    &self.settings,
-                    layout_settings,
-                    segment,
-                    i,
-                    current_split,
-                    method,
-                );
+                let column_state = state.columns.push_with(|| ColumnState {
+                    value: String::new(),
+                    semantic_color: Default::default(),
+                    visual_color: Color::transparent(),
+                    updates_frequently: false,
+                    chart: None,
+                });
+                if let Row::Summary { start, end, .. } = row {
+                    column::update_summary_state(column_state, column, &context, start, end);
+                } else {
+                    column::update_state(column_state, column, &context, section);
+                }
             }
 
-            state.is_current_split = Some(i) == current_split;
-            state.index = i;
+            state.is_current_split = is_current_split;
+            // Blank rows use the even indices from the top, so the odd ones are
+            // shared by the section headers and the summaries.
+            state.index = match row {
+                Row::SectionHeader(section) => usize::MAX - 4 * section.start(),
+                Row::Summary { start, .. } => usize::MAX - 2 - 4 * start,
+                _ => i,
+            };
+            state.is_subsplit = matches!(
+                row,
+                Row::Segment {
+                    is_subsplit: true,
+                    ..
+                }
+            );
+            state.is_section_header = matches!(row, Row::SectionHeader(_));
+            state.is_collapsed_section = matches!(row, Row::CollapsedSection(_));
+            state.is_summary = matches!(row, Row::Summary { .. });
         }
 
         if fill_with_blank_space && state.splits.len() < visual_split_count {
@@ -422,9 +696,17 @@ impl Component {
                     columns: ClearVec::new(),
                     is_current_split: false,
                     index: 0,
+                    is_subsplit: false,
+                    is_section_header: false,
+                    is_collapsed_section: false,
+                    is_summary: false,
                 });
                 state.is_current_split = false;
                 state.index = (usize::max_value() ^ 1) - 2 * i;
+                state.is_subsplit = false;
+                state.is_section_header = false;
+                state.is_collapsed_section = false;
+                state.is_summary = false;
             }
         }
 
@@ -432,7 +714,187 @@ impl Component {
         state.show_thin_separators = show_thin_separators;
         state.show_final_separator = show_final_separator;
         state.display_two_rows = display_two_rows;
-        state.current_split_gradient = self.settings.current_split_gradient;
+        state.current_split_gradient = settings.current_split_gradient;
+        state.indent_subsplits = settings.indent_subsplits;
+        state.scroll_offset = animation_offset;
+    }
+
+    /// Determines the amount of rows to skip for a window of the size
+    /// provided, along with the scroll offset clamped to the rows available.
+    fn window(
+        &self,
+        row_count: usize,
+        current_row: Option<usize>,
+        visual_split_count: usize,
+    ) -> (usize, isize) {
+        let visual_split_count = visual_split_count as isize;
+        let locked_last_split = isize::from(self.settings.always_show_last_split);
+        let past_split_count = self.settings.past_split_count as isize;
+
+        let skip_count = min(
+            current_row.map_or(0, |current_row| {
+                let current_row = current_row as isize;
+                let skip_count = max(
+                    0,
+                    current_row
+                        + self.settings.split_preview_count as isize
+                        + locked_last_split
+                        + 1
+                        - visual_split_count,
+                );
+                if past_split_count != 0 {
+                    max(skip_count, current_row - past_split_count)
+                } else {
+                    skip_count
+                }
+            }),
+            row_count as isize - visual_split_count,
+        );
+        let scroll_offset = min(
+            max(self.scroll_offset, -skip_count),
+            row_count as isize - skip_count - visual_split_count,
+        );
+        (max(0, skip_count + scroll_offset) as usize, scroll_offset)
+    }
+
+    /// Starts a new scrolling animation if the window moved and returns the
+    /// remaining offset of the animation that is in progress.
+    fn update_scroll_animation(&mut self, window_start: usize) -> f32 {
+        let now = TimeStamp::now();
+        let mut offset = 0.0;
+        if let Some((start, initial_offset)) = self.scroll_animation {
+            let progress = (now - start).total_seconds() as f32 / SCROLL_ANIMATION_DURATION;
+            if progress < 1.0 {
+                // Ease out, so the scrolling slows down towards the end.
+                offset = initial_offset * (1.0 - progress) * (1.0 - progress);
+            } else {
+                self.scroll_animation = None;
+            }
+        }
+        if let Some(previous_start) = self.window_start {
+            if previous_start != window_start {
+                offset += window_start as f32 - previous_start as f32;
+                self.scroll_animation = Some((now, offset));
+            }
+        }
+        offset
+    }
+
+    /// Determines the rows to show, which are either the segments themselves
+    /// or, if the segments are grouped into sections, the sections with their
+    /// subsplits either collapsed or expanded.
+    fn update_rows(&mut self, timer: &Snapshot<'_>, current_split: Option<usize>) {
+        let run = timer.run();
+        self.rows.clear();
+
+        if self.settings.group_subsplits {
+            self.add_sections(run, current_split);
+        } else {
+            self.rows.extend((0..run.len()).map(|index| Row::Segment {
+                index,
+                is_subsplit: false,
+            }));
+        }
+
+        if self.settings.compact_mode {
+            if let Some(current_split) = current_split {
+                self.compact_rows(timer, current_split);
+            }
+        }
+    }
+
+    fn add_sections(&mut self, run: &Run, current_split: Option<usize>) {
+        // Before the attempt starts, the first section is considered the
+        // current section, so it can be previewed.
+        let focused_split = current_split.unwrap_or(0);
+
+        for section in run.sections() {
+            let is_current_section = section.contains(focused_split);
+            if self.settings.current_section_only && !is_current_section {
+                continue;
+            }
+
+            let show_subsplits = match self.settings.subsplit_visibility {
+                SubsplitVisibility::CurrentSection => is_current_section,
+                SubsplitVisibility::Always => true,
+                SubsplitVisibility::Never => false,
+            };
+
+            if !section.has_subsplits() {
+                self.rows.push(Row::Segment {
+                    index: section.end(),
+                    is_subsplit: false,
+                });
+            } else if show_subsplits {
+                if self.settings.show_section_header {
+                    self.rows.push(Row::SectionHeader(section));
+                }
+                self.rows
+                    .extend((section.start()..=section.end()).map(|index| Row::Segment {
+                        index,
+                        is_subsplit: true,
+                    }));
+            } else {
+                self.rows.push(Row::CollapsedSection(section));
+            }
+        }
+    }
+
+    /// Hides the rows of the completed segments where the color of the delta
+    /// didn't change, meaning the attempt didn't switch between being ahead
+    /// and behind the current comparison or between gaining and losing time.
+    /// If the hidden rows are to be summarized, consecutive hidden rows are
+    /// replaced by a single summary row.
+    fn compact_rows(&mut self, timer: &Snapshot<'_>, current_split: usize) {
+        let segments = timer.run().segments();
+        let comparison = timer.current_comparison();
+        let method = timer.current_timing_method();
+        let summarize = self.settings.show_hidden_split_summaries;
+
+        let last_completed_row = self
+            .rows
+            .iter()
+            .rposition(|row| row.segments().is_some_and(|(_, end)| end < current_split));
+        let mut previous_color = None;
+        let mut len = 0;
+
+        for index in 0..self.rows.len() {
+            let row = self.rows[index];
+            let Some((_, end)) = row.segments().filter(|&(_, end)| end < current_split) else {
+                self.rows[len] = row;
+                len += 1;
+                continue;
+            };
+
+            let segment = &segments[end];
+            let color = catch! {
+                let delta = segment.split_time()[method]? - segment.comparison(comparison)[method]?;
+                analysis::split_color(timer, Some(delta), end, true, false, comparison, method)
+            };
+            let changed_color = color.is_some() && color != previous_color;
+            if color.is_some() {
+                previous_color = color;
+            }
+
+            if changed_color || Some(index) == last_completed_row {
+                self.rows[len] = row;
+                len += 1;
+            } else if summarize {
+                let summary = match len.checked_sub(1).map(|i| self.rows[i]) {
+                    Some(summary @ Row::Summary { .. }) => {
+                        len -= 1;
+                        Some(summary)
+                    }
+                    _ => None,
+                };
+                if let Some(summary) = Row::summarize(summary, &row) {
+                    self.rows[len] = summary;
+                    len += 1;
+                }
+            }
+        }
+
+        self.rows.truncate(len);
     }
 
     /// Calculates the component's state based on the timer and layout settings
@@ -504,6 +966,39 @@ impl Component {
                 "Show Column Labels".into(),
                 self.settings.show_column_labels.into(),
             ),
+            Field::new(
+                "Group Subsplits".into(),
+                self.settings.group_subsplits.into(),
+            ),
+            Field::new(
+                "Show Subsplits".into(),
+                self.settings.subsplit_visibility.into(),
+            ),
+            Field::new(
+                "Show Current Section Only".into(),
+                self.settings.current_section_only.into(),
+            ),
+            Field::new(
+                "Indent Subsplits".into(),
+                self.settings.indent_subsplits.into(),
+            ),
+            Field::new(
+                "Show Section Header".into(),
+                self.settings.show_section_header.into(),
+            ),
+            Field::new(
+                "Maximum Past Splits".into(),
+                Value::UInt(self.settings.past_split_count as _),
+            ),
+            Field::new(
+                "Summarize Hidden Splits".into(),
+                self.settings.show_hidden_split_summaries.into(),
+            ),
+            Field::new("Compact Mode".into(), self.settings.compact_mode.into()),
+            Field::new(
+                "Smooth Scrolling".into(),
+                self.settings.smooth_scrolling.into(),
+            ),
             Field::new(
                 "Columns".into(),
                 Value::UInt(self.settings.columns.len() as _),
@@ -516,6 +1011,7 @@ impl Component {
                 .iter()
                 .map(|column| match column.kind {
                     ColumnKind::Variable(_) => SETTINGS_PER_VARIABLE_COLUMN,
+                    ColumnKind::Chart(_) => SETTINGS_PER_CHART_COLUMN,
                     ColumnKind::Time(_) => SETTINGS_PER_TIME_COLUMN,
                 })
                 .sum(),
@@ -537,6 +1033,23 @@ impl Component {
                         column.variable_name.clone().into(),
                     ));
                 }
+                ColumnKind::Chart(column) => {
+                    settings.fields.push(Field::new(
+                        "Column Type".into(),
+                        settings::ColumnKind::Chart.into(),
+                    ));
+                    settings
+                        .fields
+                        .push(Field::new("Chart".into(), column.chart.into()));
+                    settings.fields.push(Field::new(
+                        "Segment Count".into(),
+                        u64::from(column.segment_count).into(),
+                    ));
+                    settings.fields.push(Field::new(
+                        "Timing Method".into(),
+                        column.timing_method.into(),
+                    ));
+                }
                 ColumnKind::Time(column) => {
                     settings.fields.push(Field::new(
                         "Column Type".into(),
@@ -590,7 +1103,16 @@ impl Component {
             11 => self.settings.delta_time_accuracy = value.into(),
             12 => self.settings.delta_drop_decimals = value.into(),
             13 => self.settings.show_column_labels = value.into(),
-            14 => {
+            14 => self.settings.group_subsplits = value.into(),
+            15 => self.settings.subsplit_visibility = value.into(),
+            16 => self.settings.current_section_only = value.into(),
+            17 => self.settings.indent_subsplits = value.into(),
+            18 => self.settings.show_section_header = value.into(),
+            19 => self.settings.past_split_count = value.into_uint().unwrap() as _,
+            20 => self.settings.show_hidden_split_summaries = value.into(),
+            21 => self.settings.compact_mode = value.into(),
+            22 => self.settings.smooth_scrolling = value.into(),
+            23 => {
                 let new_len = value.into_uint().unwrap() as usize;
                 self.settings.columns.resize(new_len, Default::default());
             }
@@ -608,6 +1130,9 @@ impl Component {
                                     settings::ColumnKind::Variable => {
                                         ColumnKind::Variable(Default::default())
                                     }
+                                    settings::ColumnKind::Chart => {
+                                        ColumnKind::Chart(Default::default())
+                                    }
                                 }
                             }
                         }
@@ -622,6 +1147,17 @@ impl Component {
                             }
                             index -= 1;
                         }
+                        ColumnKind::Chart(column) => {
+                            if index < 3 {
+                                match index {
+                                    0 => column.chart = value.into(),
+                                    1 => column.segment_count = value.into_uint().unwrap() as _,
+                                    _ => column.timing_method = value.into(),
+                                }
+                                return;
+                            }
+                            index -= 3;
+                        }
                         ColumnKind::Time(column) => {
                             if index < 5 {
                                 match index {
@@ -3,13 +3,20 @@ use super::{
     State,
 };
 use crate::{
-    component::splits::{ColumnKind, TimeColumn},
+    analysis::segment_outlook,
+    comparison::personal_best,
+    component::splits::{ChartColumn, ChartKind, ColumnChart, ColumnKind, TimeColumn},
     settings::SemanticColor::{
         self, AheadGainingTime as AheadGaining, BehindLosingTime as BehindLosing,
         BestSegment as Best, Default as Text,
     },
-    util::tests_helper::{make_progress_run_with_splits_opt, run_with_splits_opt, start_run},
+    timing::formatter::{SegmentTime, TimeFormatter},
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt,
+        start_run,
+    },
     Run, Segment, TimeSpan, Timer,
+    TimingMethod::GameTime,
 };
 
 type Values = &'static [([&'static str; 6], [SemanticColor; 6])];
@@ -1343,3 +1350,211 @@ fn check_column_color(state: &State, split_index: usize, expected_color: Semanti
         expected_color
     );
 }
+
+fn chart_component(chart: ChartKind, segment_count: u32) -> Component {
+    Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            name: String::from("Chart"),
+            kind: ColumnKind::Chart(ChartColumn {
+                chart,
+                segment_count,
+                timing_method: None,
+            }),
+        }],
+        ..Default::default()
+    })
+}
+
+#[test]
+fn column_sparkline() {
+    let layout_settings = Default::default();
+    let mut timer = timer();
+    for time in [10.0, 20.0, 15.0] {
+        run_with_splits_opt(&mut timer, &[Some(time)]);
+    }
+
+    let mut component = chart_component(ChartKind::Sparkline, 3);
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        state.splits[0].columns[0].chart,
+        Some(ColumnChart::Sparkline(vec![0.0, 1.0, 0.5])),
+    );
+    assert_eq!(state.splits[0].columns[0].value, "");
+    assert_eq!(state.splits[1].columns[0].chart, None);
+
+    // The segment time of the current attempt is the newest one, once the
+    // segment is completed.
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.0)]);
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        state.splits[0].columns[0].chart,
+        Some(ColumnChart::Sparkline(vec![1.0, 0.375, 0.0])),
+    );
+}
+
+#[test]
+fn column_histogram() {
+    let layout_settings = Default::default();
+    let mut timer = timer();
+    for time in [10.0, 20.0, 15.0, 11.0] {
+        run_with_splits_opt(&mut timer, &[Some(time)]);
+    }
+
+    let mut component = chart_component(ChartKind::Histogram, 20);
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        state.splits[0].columns[0].chart,
+        Some(ColumnChart::Histogram(vec![
+            1.0, 0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 0.5
+        ])),
+    );
+    assert_eq!(state.splits[1].columns[0].chart, None);
+}
+
+fn outlook_component(start_with: ColumnStartWith, update_with: ColumnUpdateWith) -> Component {
+    Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            name: String::from("Outlook"),
+            kind: ColumnKind::Time(TimeColumn {
+                start_with,
+                update_with,
+                update_trigger: ColumnUpdateTrigger::OnStartingSegment,
+                ..Default::default()
+            }),
+        }],
+        ..Default::default()
+    })
+}
+
+fn outlook_timer() -> Timer {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[12.0, 26.0]);
+    run_with_splits(&mut timer, &[8.0, 24.0]);
+    timer
+}
+
+fn percentage(probability: Option<f64>) -> String {
+    format!("{:.0}%", 100.0 * probability.unwrap())
+}
+
+fn segment_time(time: Option<TimeSpan>) -> String {
+    SegmentTime::with_accuracy(Settings::default().segment_time_accuracy)
+        .format(time)
+        .to_string()
+}
+
+#[test]
+fn column_gold_probability() {
+    let layout_settings = Default::default();
+    let mut timer = outlook_timer();
+    let mut component = outlook_component(
+        ColumnStartWith::GoldProbability,
+        ColumnUpdateWith::GoldProbability,
+    );
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let probability = segment_outlook::gold_probability(timer.run(), 0, GameTime);
+    assert_eq!(state.splits[0].columns[0].value, percentage(probability));
+    assert_eq!(
+        state.splits[1].columns[0].value,
+        percentage(segment_outlook::gold_probability(timer.run(), 1, GameTime)),
+    );
+
+    // A slow segment time lowers the chance of getting a gold.
+    run_with_splits(&mut timer, &[15.0, 30.0]);
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let lower_probability = segment_outlook::gold_probability(timer.run(), 0, GameTime);
+    assert!(lower_probability < probability);
+    assert_eq!(
+        state.splits[0].columns[0].value,
+        percentage(lower_probability)
+    );
+
+    // The current segment takes the time that already elapsed into account.
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(7.5));
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        state.splits[0].columns[0].value,
+        percentage(segment_outlook::live_gold_probability(
+            &timer.snapshot(),
+            0,
+            GameTime
+        )),
+    );
+    assert!(state.splits[0].columns[0].updates_frequently);
+}
+
+#[test]
+fn column_gold_probability_follows_history_edits() {
+    let layout_settings = Default::default();
+    let mut timer = outlook_timer();
+    let mut component = outlook_component(
+        ColumnStartWith::GoldProbability,
+        ColumnUpdateWith::DontUpdate,
+    );
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let probability = segment_outlook::gold_probability(timer.run(), 0, GameTime);
+    assert_eq!(state.splits[0].columns[0].value, percentage(probability));
+
+    // Removing the slowest segment time raises the chance of getting a gold,
+    // even though neither the number of attempts nor the segments changed.
+    let mut run = timer.run().clone();
+    run.segment_mut(0).segment_history_mut().remove(2);
+    timer.set_run(run).unwrap();
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    let higher_probability = segment_outlook::gold_probability(timer.run(), 0, GameTime);
+    assert!(higher_probability > probability);
+    assert_eq!(
+        state.splits[0].columns[0].value,
+        percentage(higher_probability)
+    );
+}
+
+#[test]
+fn column_expected_time_save() {
+    let layout_settings = Default::default();
+    let mut timer = outlook_timer();
+    let mut component = outlook_component(
+        ColumnStartWith::ExpectedTimeSave,
+        ColumnUpdateWith::ExpectedTimeSave,
+    );
+
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    for segment_index in 0..2 {
+        assert_eq!(
+            state.splits[segment_index].columns[0].value,
+            segment_time(segment_outlook::expected_time_save(
+                timer.run(),
+                segment_index,
+                personal_best::NAME,
+                GameTime,
+            )),
+        );
+    }
+
+    // A completed segment shows the time that was actually saved.
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(7.0)]);
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        state.splits[0].columns[0].value,
+        segment_time(Some(TimeSpan::from_seconds(3.0))),
+    );
+    assert_eq!(
+        state.splits[1].columns[0].value,
+        segment_time(segment_outlook::live_expected_time_save(
+            &timer.snapshot(),
+            1,
+            personal_best::NAME,
+            GameTime,
+        )),
+    );
+}
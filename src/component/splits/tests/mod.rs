@@ -1,9 +1,11 @@
 use super::{
     ColumnSettings, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, Component, Settings,
-    State,
+    State, SubsplitVisibility,
 };
 use crate::{
     component::splits::{ColumnKind, TimeColumn},
+    settings::SemanticColor,
+    util::tests_helper::{create_timer, run_with_splits, start_run},
     Run, Segment, TimeSpan, Timer, TimingMethod,
 };
 
@@ -137,3 +139,265 @@ fn unique_split_indices() {
 
     assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
 }
+
+#[test]
+fn subsplits_are_grouped_into_sections() {
+    let mut run = Run::new();
+    for name in ["-A1", "-A2", "{Area A}A3", "B", "-C1", "{Area C}C2"] {
+        run.push_segment(Segment::new(name));
+    }
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 0,
+        fill_with_blank_space: false,
+        group_subsplits: true,
+        ..Default::default()
+    });
+
+    let rows = |state: &State| {
+        state
+            .splits
+            .iter()
+            .map(|s| (s.name.clone(), s.is_subsplit, s.is_section_header))
+            .collect::<Vec<_>>()
+    };
+
+    // Before the attempt starts, the first section is expanded.
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        rows(&state),
+        [
+            ("Area A".to_string(), false, true),
+            ("A1".to_string(), true, false),
+            ("A2".to_string(), true, false),
+            ("A3".to_string(), true, false),
+            ("B".to_string(), false, false),
+            ("Area C".to_string(), false, false),
+        ]
+    );
+    assert_eq!(state.splits[5].index, 5);
+
+    timer.start();
+    for _ in 0..5 {
+        timer.split();
+    }
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        rows(&state),
+        [
+            ("Area A".to_string(), false, false),
+            ("B".to_string(), false, false),
+            ("Area C".to_string(), false, true),
+            ("C1".to_string(), true, false),
+            ("C2".to_string(), true, false),
+        ]
+    );
+    assert!(state.splits[4].is_current_split);
+    assert!(state.splits[0].is_collapsed_section);
+    assert!(!state.splits[2].is_collapsed_section);
+
+    // The header of a section doesn't share its index with the last subsplit.
+    let mut indices = state.splits.iter().map(|s| s.index).collect::<Vec<_>>();
+    assert_eq!(indices[4], 5);
+    indices.sort_unstable();
+    assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
+
+    component.settings_mut().subsplit_visibility = SubsplitVisibility::Never;
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        state.splits.iter().map(|s| &*s.name).collect::<Vec<_>>(),
+        ["Area A", "B", "Area C"]
+    );
+    assert!(state.splits[2].is_current_split);
+
+    component.settings_mut().subsplit_visibility = SubsplitVisibility::Always;
+    component.settings_mut().current_section_only = true;
+    component.settings_mut().show_section_header = false;
+    let state = component.state(&timer.snapshot(), &layout_settings);
+    assert_eq!(
+        state.splits.iter().map(|s| &*s.name).collect::<Vec<_>>(),
+        ["C1", "C2"]
+    );
+}
+
+#[test]
+fn collapsed_sections_combine_the_segments_of_the_section() {
+    let mut timer = create_timer(&["A", "-B1", "-B2", "{Area B}B3", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0, 50.0]);
+    start_run(&mut timer);
+    for split_time in [9.0, 21.0, 33.0, 44.0] {
+        timer.set_game_time(TimeSpan::from_seconds(split_time));
+        timer.split();
+    }
+
+    let segment_column = |update_with| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            update_with,
+            update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 0,
+        fill_with_blank_space: false,
+        group_subsplits: true,
+        subsplit_visibility: SubsplitVisibility::Never,
+        columns: vec![
+            segment_column(ColumnUpdateWith::SegmentTime),
+            segment_column(ColumnUpdateWith::SegmentDelta),
+        ],
+        ..Default::default()
+    });
+
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(names(&state), ["A", "Area B", "C"]);
+    assert_eq!(state.splits[1].columns[0].value, "35.00");
+    assert_eq!(state.splits[1].columns[1].value, "+5.0");
+
+    component.settings_mut().subsplit_visibility = SubsplitVisibility::Always;
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert!(state.splits[1].is_section_header);
+    assert_eq!(state.splits[1].columns[0].value, "35.00");
+    assert_eq!(state.splits[4].columns[0].value, "11.00");
+}
+
+#[test]
+fn subsplit_names_are_shown_as_they_are_without_grouping() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("-A1"));
+    run.push_segment(Segment::new("{Area A}A2"));
+    let timer = Timer::new(run).unwrap();
+    let mut component = Component::new();
+
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(state.splits[0].name, "-A1");
+    assert_eq!(state.splits[1].name, "{Area A}A2");
+    assert!(!state.splits[0].is_subsplit);
+}
+
+fn timer_with_attempt(split_times: &[f64]) -> Timer {
+    let names = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+    let mut timer = create_timer(&names);
+    run_with_splits(
+        &mut timer,
+        &[10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 100.0],
+    );
+    start_run(&mut timer);
+    for &split_time in split_times {
+        timer.set_game_time(TimeSpan::from_seconds(split_time));
+        timer.split();
+    }
+    timer
+}
+
+fn names(state: &State) -> Vec<&str> {
+    state.splits.iter().map(|s| &*s.name).collect()
+}
+
+#[test]
+fn past_split_count_focuses_the_current_split() {
+    let timer = timer_with_attempt(&[10.0, 20.0, 30.0, 40.0, 50.0]);
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 6,
+        split_preview_count: 1,
+        always_show_last_split: false,
+        ..Default::default()
+    });
+
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(names(&state), ["1", "2", "3", "4", "5", "6"]);
+
+    component.settings_mut().past_split_count = 1;
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(names(&state), ["4", "5", "6", "7", "8", "9"]);
+}
+
+#[test]
+fn hidden_splits_are_summarized() {
+    let timer = timer_with_attempt(&[9.0, 18.0, 32.0, 41.0, 45.0]);
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 5,
+        split_preview_count: 1,
+        show_hidden_split_summaries: true,
+        ..Default::default()
+    });
+
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(
+        names(&state),
+        ["… 5 splits …", "5", "6", "… 2 splits …", "9"]
+    );
+    assert!(state.splits[0].is_summary);
+    assert!(!state.splits[1].is_summary);
+    assert!(state.splits[3].is_summary);
+    assert_eq!(state.splits[0].columns[1].value, "−5.0");
+    assert_eq!(
+        state.splits[0].columns[1].semantic_color,
+        SemanticColor::AheadGainingTime
+    );
+    assert_eq!(state.splits[0].columns[0].value, "");
+    assert_eq!(state.splits[3].columns[1].value, "");
+}
+
+#[test]
+fn compact_mode_only_shows_splits_where_the_delta_changed_color() {
+    let timer = timer_with_attempt(&[9.0, 18.0, 32.0, 41.0, 45.0]);
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 0,
+        fill_with_blank_space: false,
+        compact_mode: true,
+        ..Default::default()
+    });
+
+    // The attempt is ahead and gaining time at the first two segments, falls
+    // behind at the third one, gains time back at the fourth one and is ahead
+    // again at the fifth one.
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(names(&state), ["0", "2", "3", "4", "5", "6", "7", "8", "9"]);
+    assert_eq!(state.splits[2].columns[1].value, "+1.0");
+
+    component.settings_mut().show_hidden_split_summaries = true;
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(
+        names(&state),
+        ["0", "… 1 split …", "2", "3", "4", "5", "6", "7", "8", "9"]
+    );
+    assert_eq!(state.splits[1].columns[1].value, "−1.0");
+    assert_eq!(
+        state.splits[1].columns[1].semantic_color,
+        SemanticColor::AheadGainingTime
+    );
+
+    let mut indices = state.splits.iter().map(|s| s.index).collect::<Vec<_>>();
+    indices.sort_unstable();
+    assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn smooth_scrolling_provides_an_offset() {
+    let mut timer = timer_with_attempt(&[10.0, 20.0]);
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 3,
+        split_preview_count: 0,
+        always_show_last_split: false,
+        smooth_scrolling: true,
+        ..Default::default()
+    });
+
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(names(&state), ["0", "1", "2"]);
+    assert_eq!(state.scroll_offset, 0.0);
+
+    timer.set_game_time(TimeSpan::from_seconds(30.0));
+    timer.split();
+
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(names(&state), ["1", "2", "3"]);
+    assert!(state.scroll_offset > 0.0 && state.scroll_offset <= 1.0);
+
+    component.settings_mut().smooth_scrolling = false;
+    let state = component.state(&timer.snapshot(), &Default::default());
+    assert_eq!(state.scroll_offset, 0.0);
+}
@@ -0,0 +1,196 @@
+//! Provides the Survival Chance Component and relevant types for using it. The
+//! Survival Chance Component is a component that shows how likely it is for
+//! the current attempt to reach the end of the run, based on how often attempts
+//! were reset from the current segment onward.
+
+use super::key_value;
+use crate::{
+    analysis::reset_points,
+    platform::{prelude::*, RwLock},
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::Snapshot,
+    TimerPhase,
+};
+use core::fmt::Write;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Survival Chance Component is a component that shows how likely it is
+/// for the current attempt to reach the end of the run, based on how often
+/// attempts were reset from the current segment onward. If there is no active
+/// attempt, it shows the general chance of an attempt reaching the end.
+pub struct Component {
+    settings: Settings,
+    cache: RwLock<Option<(CacheKey, f64)>>,
+}
+
+/// Determines when the survival probability needs to be calculated again.
+#[derive(Copy, Clone, PartialEq)]
+struct CacheKey {
+    attempt_count: u32,
+    attempts: usize,
+    segments: usize,
+    history: usize,
+    segment_index: usize,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+        }
+    }
+}
+
+impl Default for Component {
+    fn default() -> Self {
+        Self::with_settings(Default::default())
+    }
+}
+
+impl Clone for Component {
+    fn clone(&self) -> Self {
+        Self::with_settings(self.settings.clone())
+    }
+}
+
+impl Component {
+    /// Creates a new Survival Chance Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Survival Chance Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            cache: RwLock::new(None),
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub const fn name(&self) -> &'static str {
+        "Survival Chance"
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
+        let chance = match timer.current_phase() {
+            TimerPhase::Ended => 1.0,
+            _ => self.survival_probability(timer),
+        };
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str(self.name());
+
+        state.value.clear();
+        let _ = write!(state.value, "{:.1}%", 100.0 * chance);
+
+        state.key_abbreviations.clear();
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = false;
+    }
+
+    fn survival_probability(&self, timer: &Snapshot<'_>) -> f64 {
+        let run = timer.run();
+        let key = CacheKey {
+            attempt_count: run.attempt_count(),
+            attempts: run.attempt_history().len(),
+            segments: run.len(),
+            history: run
+                .segments()
+                .iter()
+                .map(|segment| segment.segment_history().revision())
+                .fold(0, usize::wrapping_add),
+            segment_index: timer.current_split_index().unwrap_or_default(),
+        };
+
+        // Analyzing all the reset points is too expensive to do every frame,
+        // but the result only changes between attempts and splits, or when the
+        // run's history gets modified.
+        let mut cache = self.cache.write().unwrap();
+        match *cache {
+            Some((cached_key, probability)) if cached_key == key => probability,
+            _ => {
+                let probability = reset_points::survival_probability(run, key.segment_index);
+                *cache = Some((key, probability));
+                probability
+            }
+        }
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Snapshot<'_>) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use super::Component;
+use crate::{
+    util::tests_helper::{create_timer, run_with_splits, start_run},
+    Timer,
+};
+
+fn timer_with_attempts() -> Timer {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[10.0]);
+    timer
+}
+
+#[test]
+fn shows_the_chance_of_reaching_the_end() {
+    let timer = timer_with_attempts();
+    let component = Component::new();
+
+    let state = component.state(&timer.snapshot());
+    assert_eq!(state.key, "Survival Chance");
+    assert_eq!(state.value, "50.0%");
+}
+
+#[test]
+fn ended_attempts_survived() {
+    let mut timer = timer_with_attempts();
+    let component = Component::new();
+
+    start_run(&mut timer);
+    timer.split();
+    timer.split();
+
+    assert_eq!(component.state(&timer.snapshot()).value, "100.0%");
+}
+
+#[test]
+fn modified_history_gets_picked_up() {
+    let mut timer = timer_with_attempts();
+    let component = Component::new();
+    assert_eq!(component.state(&timer.snapshot()).value, "50.0%");
+
+    // The first attempt now gets reset during the second segment as well,
+    // without changing the number of attempts.
+    let mut run = timer.run().clone();
+    run.segment_mut(1).segment_history_mut().remove(1);
+    timer.set_run(run).unwrap();
+
+    assert_eq!(component.state(&timer.snapshot()).value, "0.0%");
+}
@@ -0,0 +1,214 @@
+//! Provides the Time Lost Component and relevant types for using it. The Time
+//! Lost Component is a component that shows how much time has been lost in the
+//! current attempt, either compared to the chosen comparison or compared to the
+//! Best Segments. Segments where time was gained don't reduce the time lost.
+
+use super::key_value;
+use crate::{
+    analysis::time_lost,
+    comparison,
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{
+        formatter::{Accuracy, SegmentTime, TimeFormatter},
+        Snapshot,
+    },
+};
+use alloc::borrow::Cow;
+use core::fmt::Write as FmtWrite;
+use serde::{Deserialize, Serialize};
+
+/// The Time Lost Component is a component that shows how much time has been
+/// lost in the current attempt, either compared to the chosen comparison or
+/// compared to the Best Segments.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen. Uses the Timer's current comparison if set to
+    /// `None`. This is not used if the time lost is compared to the Best
+    /// Segments.
+    pub comparison_override: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// Specifies whether the time lost is compared to the Best Segments
+    /// instead of the comparison.
+    pub versus_best_segments: bool,
+    /// Specifies whether the time that is being lost on the current segment is
+    /// taken into account.
+    pub live: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            comparison_override: None,
+            display_two_rows: false,
+            versus_best_segments: true,
+            live: true,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Hundredths,
+        }
+    }
+}
+
+impl Component {
+    /// Creates a new Time Lost Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Time Lost Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        self.text(
+            self.settings
+                .comparison_override
+                .as_ref()
+                .map(String::as_ref),
+        )
+    }
+
+    fn text(&self, comparison: Option<&str>) -> Cow<'static, str> {
+        if self.settings.versus_best_segments {
+            return "Time Lost to Best Segments".into();
+        }
+        let mut text = Cow::from("Time Lost");
+        if let Some(comparison) = comparison {
+            write!(text.to_mut(), " ({})", comparison::shorten(comparison)).unwrap();
+        }
+        text
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut key_value::State, timer: &Snapshot<'_>) {
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let text = self.text(comparison);
+        let comparison = comparison::or_current(comparison, timer);
+
+        let time_lost = time_lost::calculate(
+            timer,
+            comparison,
+            timer.current_timing_method(),
+            self.settings.live,
+        );
+        let time = if timer.current_phase().is_running() || !time_lost.segments.is_empty() {
+            Some(if self.settings.versus_best_segments {
+                time_lost.best_segments
+            } else {
+                time_lost.comparison
+            })
+        } else {
+            None
+        };
+
+        state.background = self.settings.background;
+        state.key_color = self.settings.label_color;
+        state.value_color = self.settings.value_color;
+        state.semantic_color = Default::default();
+
+        state.key.clear();
+        state.key.push_str(&text);
+
+        state.value.clear();
+        let _ = write!(
+            state.value,
+            "{}",
+            SegmentTime::with_accuracy(self.settings.accuracy).format(time)
+        );
+
+        state.key_abbreviations.clear();
+        if self.settings.versus_best_segments {
+            state.key_abbreviations.push("Time Lost to Golds".into());
+        }
+        state.key_abbreviations.push("Time Lost".into());
+        state.key_abbreviations.push("Lost".into());
+
+        state.display_two_rows = self.settings.display_two_rows;
+        state.updates_frequently = time_lost.updates_frequently;
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Snapshot<'_>) -> key_value::State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new(
+                "Compare Against Best Segments".into(),
+                self.settings.versus_best_segments.into(),
+            ),
+            Field::new("Include Live Segment".into(), self.settings.live.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.display_two_rows = value.into(),
+            3 => self.settings.versus_best_segments = value.into(),
+            4 => self.settings.live = value.into(),
+            5 => self.settings.label_color = value.into(),
+            6 => self.settings.value_color = value.into(),
+            7 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
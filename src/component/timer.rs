@@ -1,10 +1,11 @@
 //! Provides the `Timer` Component and relevant types for using it. The `Timer`
 //! Component is a component that shows the total time of the current attempt as
 //! a digital clock. The color of the time shown is based on a how well the
-//! current attempt is doing compared to the chosen comparison.
+//! current attempt is doing compared to the chosen comparison. Alternatively
+//! the Timer Component can count down the time remaining until a target time.
 
 use crate::{
-    analysis::split_color,
+    analysis::{comparison_single_segment_time, split_color},
     platform::prelude::*,
     settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
     timing::{
@@ -78,6 +79,21 @@ impl DeltaGradient {
         }
     }
 }
+
+/// Describes what the Timer Component counts down to, if anything.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CountdownMode {
+    /// The timer counts up like usual.
+    #[default]
+    Off,
+    /// The timer counts down to the goal time specified in the settings.
+    GoalTime,
+    /// The timer counts down to the final time of the current comparison. If
+    /// the timer is a segment timer, it counts down to the comparison's segment
+    /// time of the current segment instead.
+    Comparison,
+}
+
 /// The Settings for this component.
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -107,6 +123,14 @@ pub struct Settings {
     /// current segment, rather than how much time has passed since the start of
     /// the current attempt.
     pub is_segment_timer: bool,
+    /// Specifies whether the timer counts down the time remaining until a
+    /// target time, rather than counting up. Once the target time is
+    /// exceeded, the time shown becomes negative. If there is no target time,
+    /// the timer counts up like usual.
+    pub countdown: CountdownMode,
+    /// The target time to count down to if the countdown mode is set to the
+    /// goal time.
+    pub goal_time: Option<TimeSpan>,
 }
 
 impl Default for Settings {
@@ -120,6 +144,8 @@ impl Default for Settings {
             digits_format: DigitsFormat::SingleDigitSeconds,
             accuracy: Accuracy::Hundredths,
             is_segment_timer: false,
+            countdown: CountdownMode::Off,
+            goal_time: None,
         }
     }
 }
@@ -202,24 +228,45 @@ impl Component {
 
         let phase = timer.current_phase();
 
-        let (time, semantic_color) = if self.settings.is_segment_timer {
+        let current_comparison = timer.current_comparison();
+
+        let (time, semantic_color, target) = if self.settings.is_segment_timer {
             let last_split_index = if phase == TimerPhase::Ended {
                 timer.run().len() - 1
             } else {
                 timer.current_split_index().unwrap_or_default()
             };
             let mut segment_time = calculate_live_segment_time(timer, method, last_split_index);
+            let mut segment_time_method = method;
 
             if segment_time.is_none() && method == TimingMethod::GameTime {
+                segment_time_method = TimingMethod::RealTime;
                 segment_time =
-                    calculate_live_segment_time(timer, TimingMethod::RealTime, last_split_index);
+                    calculate_live_segment_time(timer, segment_time_method, last_split_index);
             }
 
-            (segment_time, SemanticColor::Default)
+            // The target needs to be based on the same timing method as the
+            // segment time, even if it fell back to Real Time.
+            let target = match self.settings.countdown {
+                CountdownMode::Off => None,
+                CountdownMode::GoalTime => self.settings.goal_time,
+                CountdownMode::Comparison => comparison_single_segment_time(
+                    timer.run(),
+                    last_split_index,
+                    current_comparison,
+                    segment_time_method,
+                ),
+            };
+
+            (segment_time, SemanticColor::Default, target)
         } else {
             let time = timer.current_time();
-            let time = time[method].or(time.real_time).unwrap_or_default();
-            let current_comparison = timer.current_comparison();
+            let time_method = if time[method].is_some() {
+                method
+            } else {
+                TimingMethod::RealTime
+            };
+            let time = time[time_method].unwrap_or_default();
 
             let semantic_color = match phase {
                 TimerPhase::Running if time >= TimeSpan::zero() => {
@@ -261,7 +308,31 @@ impl Component {
                 _ => SemanticColor::NotRunning,
             };
 
-            (Some(time), semantic_color)
+            let target = match self.settings.countdown {
+                CountdownMode::Off => None,
+                CountdownMode::GoalTime => self.settings.goal_time,
+                CountdownMode::Comparison => timer
+                    .run()
+                    .segments()
+                    .last()
+                    .unwrap()
+                    .comparison(current_comparison)[time_method],
+            };
+
+            (Some(time), semantic_color, target)
+        };
+
+        let (time, semantic_color) = match (time, target) {
+            (Some(time), Some(target)) => {
+                let semantic_color =
+                    if time > target && matches!(phase, TimerPhase::Running | TimerPhase::Ended) {
+                        SemanticColor::BehindLosingTime
+                    } else {
+                        semantic_color
+                    };
+                (Some(target - time), semantic_color)
+            }
+            _ => (time, semantic_color),
         };
 
         let not_overwritten_visual_color = semantic_color.visualize(layout_settings);
@@ -324,6 +395,8 @@ impl Component {
             Field::new("Show Gradient".into(), self.settings.show_gradient.into()),
             Field::new("Digits Format".into(), self.settings.digits_format.into()),
             Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new("Countdown".into(), self.settings.countdown.into()),
+            Field::new("Goal Time".into(), self.settings.goal_time.into()),
         ])
     }
 
@@ -344,6 +417,8 @@ impl Component {
             5 => self.settings.show_gradient = value.into(),
             6 => self.settings.digits_format = value.into(),
             7 => self.settings.accuracy = value.into(),
+            8 => self.settings.countdown = value.into(),
+            9 => self.settings.goal_time = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -1,23 +1,45 @@
 //! Provides the Total Playtime Component and relevant types for using it. The
 //! Total Playtime is a component that shows the total amount of time that the
-//! current category has been played for.
+//! current category has been played for. It can alternatively show the amount
+//! of time it has been played for today or during the current session.
 
 use super::key_value;
 use crate::{
-    analysis::total_playtime,
-    platform::prelude::*,
+    analysis::{sessions::CurrentSession, total_playtime},
+    platform::{prelude::*, to_local, RwLock},
     settings::{Color, Field, Gradient, SettingsDescription, Value},
     timing::formatter::{Days, Regular, TimeFormatter},
-    Timer,
+    AtomicDateTime, TimeSpan, Timer,
 };
 use core::fmt::Write;
 use serde::{Deserialize, Serialize};
+use time::Date;
 
 /// The Total Playtime Component is a component that shows the total amount of
 /// time that the current category has been played for.
-#[derive(Default, Clone)]
 pub struct Component {
     settings: Settings,
+    cache: RwLock<Cache>,
+}
+
+/// Caches the playtimes that require analyzing the whole attempt history, so
+/// that they only need to be determined again when the history changes.
+struct Cache {
+    current_session: CurrentSession,
+    playtime_today: Option<(usize, Date, TimeSpan)>,
+}
+
+/// Specifies which attempts the playtime shown by the component is based on.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlaytimeScope {
+    /// The playtime of all the attempts is shown.
+    Total,
+    /// The playtime of the attempts started today is shown.
+    Today,
+    /// The playtime of the attempts of the current session is shown. If no
+    /// sessions were explicitly declared, a session is a cluster of attempts
+    /// that are separated by gaps no longer than the session gap.
+    Session,
 }
 
 /// The Settings for this component.
@@ -38,6 +60,12 @@ pub struct Settings {
     /// The color of the value. If `None` is specified, the color is taken from
     /// the layout.
     pub value_color: Option<Color>,
+    /// Specifies which attempts the playtime is based on.
+    pub scope: PlaytimeScope,
+    /// The longest gap in minutes between two attempts for them to still be
+    /// considered part of the same session. This is only used if the scope is
+    /// the current session and no sessions were explicitly declared.
+    pub session_gap: u64,
 }
 
 impl Default for Settings {
@@ -48,10 +76,24 @@ impl Default for Settings {
             show_days: true,
             label_color: None,
             value_color: None,
+            scope: PlaytimeScope::Total,
+            session_gap: 30,
         }
     }
 }
 
+impl Default for Component {
+    fn default() -> Self {
+        Self::with_settings(Default::default())
+    }
+}
+
+impl Clone for Component {
+    fn clone(&self) -> Self {
+        Self::with_settings(self.settings.clone())
+    }
+}
+
 impl Component {
     /// Creates a new Total Playtime Component.
     pub fn new() -> Self {
@@ -60,7 +102,13 @@ impl Component {
 
     /// Creates a new Total Playtime Component with the given settings.
     pub const fn with_settings(settings: Settings) -> Self {
-        Self { settings }
+        Self {
+            settings,
+            cache: RwLock::new(Cache {
+                current_session: CurrentSession::new(),
+                playtime_today: None,
+            }),
+        }
     }
 
     /// Accesses the settings of the component.
@@ -80,7 +128,31 @@ impl Component {
 
     /// Updates the component's state based on the timer provided.
     pub fn update_state(&self, state: &mut key_value::State, timer: &Timer) {
-        let total_playtime = total_playtime::calculate(timer);
+        let (playtime, key, abbreviation) = match self.settings.scope {
+            PlaytimeScope::Total => (
+                total_playtime::calculate(timer),
+                "Total Playtime",
+                "Playtime",
+            ),
+            PlaytimeScope::Today => (
+                playtime_today(&mut self.cache.write().unwrap(), timer),
+                "Playtime Today",
+                "Today",
+            ),
+            PlaytimeScope::Session => (
+                self.cache
+                    .write()
+                    .unwrap()
+                    .current_session
+                    .calculate(
+                        timer,
+                        TimeSpan::from_seconds(60.0 * self.settings.session_gap as f64),
+                    )
+                    .map_or(TimeSpan::zero(), |session| session.playtime),
+                "Session Playtime",
+                "Session",
+            ),
+        };
 
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
@@ -88,17 +160,17 @@ impl Component {
         state.semantic_color = Default::default();
 
         state.key.clear();
-        state.key.push_str("Total Playtime");
+        state.key.push_str(key);
 
         state.value.clear();
         if self.settings.show_days {
-            let _ = write!(state.value, "{}", Days::new().format(total_playtime));
+            let _ = write!(state.value, "{}", Days::new().format(playtime));
         } else {
-            let _ = write!(state.value, "{}", Regular::new().format(total_playtime));
+            let _ = write!(state.value, "{}", Regular::new().format(playtime));
         }
 
         state.key_abbreviations.clear();
-        state.key_abbreviations.push("Playtime".into());
+        state.key_abbreviations.push(abbreviation.into());
 
         state.display_two_rows = self.settings.display_two_rows;
         state.updates_frequently = timer.current_phase().is_running();
@@ -123,6 +195,11 @@ impl Component {
             Field::new("Show Days (>24h)".into(), self.settings.show_days.into()),
             Field::new("Label Color".into(), self.settings.label_color.into()),
             Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Playtime".into(), self.settings.scope.into()),
+            Field::new(
+                "Session Gap (Minutes)".into(),
+                self.settings.session_gap.into(),
+            ),
         ])
     }
 
@@ -140,7 +217,25 @@ impl Component {
             2 => self.settings.show_days = value.into(),
             3 => self.settings.label_color = value.into(),
             4 => self.settings.value_color = value.into(),
+            5 => self.settings.scope = value.into(),
+            6 => self.settings.session_gap = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
 }
+
+fn playtime_today(cache: &mut Cache, timer: &Timer) -> TimeSpan {
+    let today = to_local(AtomicDateTime::now().time).date();
+    let attempts = timer.run().attempt_history().len();
+
+    let history = match cache.playtime_today {
+        Some((len, date, playtime)) if len == attempts && date == today => playtime,
+        _ => {
+            let playtime = total_playtime::on_date(timer.run(), today);
+            cache.playtime_today = Some((attempts, today, playtime));
+            playtime
+        }
+    };
+
+    history + total_playtime::current_attempt_playtime(timer)
+}
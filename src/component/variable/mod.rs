@@ -0,0 +1,327 @@
+//! Provides the Variable Component and relevant types for using it. The
+//! Variable Component shows the current value of a custom variable, such as
+//! the number of deaths or the current area provided by an auto splitter.
+//! Numeric variables can additionally be visualized as a graph of their values
+//! at each split of the current attempt.
+
+use super::key_value;
+use crate::{
+    platform::prelude::*,
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
+    timing::{formatter, Snapshot},
+    Segment,
+};
+use alloc::borrow::Cow;
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The Variable Component shows the current value of a custom variable. The
+/// value can be surrounded by a prefix and a suffix. Optionally a graph of the
+/// variable's values at each split of the current attempt is shown below it,
+/// as long as the values are numeric.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The name of the custom variable to show.
+    pub variable_name: String,
+    /// The label shown for the variable. If `None` is specified, the name of
+    /// the variable is used instead.
+    pub label: Option<String>,
+    /// Specifies whether to display the label and the value in two separate
+    /// rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The text shown in front of the value.
+    pub prefix: String,
+    /// The text shown after the value.
+    pub suffix: String,
+    /// The text shown instead of the value if the variable does not exist or
+    /// is empty. The prefix and suffix are not shown in that case.
+    pub fallback: String,
+    /// Specifies whether a graph of the variable's values at each split of the
+    /// current attempt should be shown below the value.
+    pub show_graph: bool,
+    /// The height of the graph.
+    pub graph_height: u32,
+    /// The color of the graph's lines and points.
+    pub graph_color: Color,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: key_value::DEFAULT_GRADIENT,
+            variable_name: String::new(),
+            label: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            prefix: String::new(),
+            suffix: String::new(),
+            fallback: String::from(formatter::DASH),
+            show_graph: false,
+            graph_height: 60,
+            graph_color: Color::rgba(1.0, 1.0, 1.0, 1.0),
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Default, Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The label to visualize.
+    pub label: String,
+    /// The value to visualize, including the prefix and the suffix.
+    pub value: String,
+    /// Specifies whether to display the label and the value in two separate
+    /// rows.
+    pub display_two_rows: bool,
+    /// The graph of the variable's values at each split of the current attempt.
+    /// This is `None` if the graph is not supposed to be shown.
+    pub graph: Option<GraphState>,
+}
+
+/// The state object describes the graph of the variable's values to visualize.
+/// All coordinates are in the range `0..1`.
+#[derive(Default, Serialize, Deserialize)]
+pub struct GraphState {
+    /// All of the graph's points, ordered by their x-coordinate. Connect them
+    /// to visualize the graph. The largest value is at the top of the graph.
+    /// If the live value is active, the last point is to be interpreted as the
+    /// current value of the variable, rather than the value at a split.
+    pub points: Vec<Point>,
+    /// Specifies whether the last point is the current value of the variable.
+    pub is_live_value_active: bool,
+    /// The color of the graph's lines and points.
+    pub color: Color,
+    /// The height of the graph.
+    pub height: u32,
+}
+
+/// Describes a point on the graph to visualize.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    /// The x-coordinate of the point.
+    pub x: f32,
+    /// The y-coordinate of the point.
+    pub y: f32,
+}
+
+#[cfg(feature = "std")]
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> serde_json::Result<()>
+    where
+        W: std::io::Write,
+    {
+        serde_json::to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Variable Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Variable Component with the given settings.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub const fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'static, str> {
+        if self.settings.variable_name.is_empty() {
+            "Variable".into()
+        } else {
+            format!("Variable ({})", self.settings.variable_name).into()
+        }
+    }
+
+    /// Updates the component's state based on the timer provided.
+    pub fn update_state(&self, state: &mut State, timer: &Snapshot<'_>) {
+        let settings = &self.settings;
+
+        state.background = settings.background;
+        state.label_color = settings.label_color;
+        state.value_color = settings.value_color;
+        state.display_two_rows = settings.display_two_rows;
+
+        state.label.clear();
+        state
+            .label
+            .push_str(settings.label.as_deref().unwrap_or(&settings.variable_name));
+
+        state.value.clear();
+        match timer
+            .run()
+            .metadata()
+            .custom_variable_value(&settings.variable_name)
+            .filter(|v| !v.trim().is_empty())
+        {
+            Some(value) => {
+                state.value.push_str(&settings.prefix);
+                state.value.push_str(value);
+                state.value.push_str(&settings.suffix);
+            }
+            None => state.value.push_str(&settings.fallback),
+        }
+
+        if settings.show_graph {
+            let graph = state.graph.get_or_insert_with(Default::default);
+            graph.color = settings.graph_color;
+            graph.height = settings.graph_height;
+            self.update_graph(graph, timer);
+        } else {
+            state.graph = None;
+        }
+    }
+
+    fn update_graph(&self, graph: &mut GraphState, timer: &Snapshot<'_>) {
+        graph.points.clear();
+        graph.is_live_value_active = false;
+
+        let Some(current_split_index) = timer.current_split_index() else {
+            return;
+        };
+        let run = timer.run();
+        let len = run.len() as f32;
+        let name = self.settings.variable_name.as_str();
+
+        for (index, segment) in run.segments()[..current_split_index].iter().enumerate() {
+            if let Some(value) = split_value(segment, name) {
+                graph.points.push(Point {
+                    x: (index + 1) as f32 / len,
+                    y: value,
+                });
+            }
+        }
+
+        if !timer.current_phase().is_ended() {
+            if let Some(value) = run.metadata().custom_variable_value(name).and_then(parse) {
+                graph.points.push(Point {
+                    x: (current_split_index + 1) as f32 / len,
+                    y: value,
+                });
+                graph.is_live_value_active = true;
+            }
+        }
+
+        // The values are mapped into the range of the graph such that there is
+        // some space left at the top and the bottom.
+        let (min, max) = graph
+            .points
+            .iter()
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), p| {
+                (min.min(p.y), max.max(p.y))
+            });
+        for point in &mut graph.points {
+            point.y = if max > min {
+                0.9 - 0.8 * (point.y - min) / (max - min)
+            } else {
+                0.5
+            };
+        }
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Snapshot<'_>) -> State {
+        let mut state = Default::default();
+        self.update_state(&mut state, timer);
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Variable".into(),
+                self.settings.variable_name.clone().into(),
+            ),
+            Field::new("Label".into(), self.settings.label.clone().into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Prefix".into(), self.settings.prefix.clone().into()),
+            Field::new("Suffix".into(), self.settings.suffix.clone().into()),
+            Field::new("Fallback".into(), self.settings.fallback.clone().into()),
+            Field::new("Show Graph".into(), self.settings.show_graph.into()),
+            Field::new(
+                "Graph Height".into(),
+                u64::from(self.settings.graph_height).into(),
+            ),
+            Field::new("Graph Color".into(), self.settings.graph_color.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.variable_name = value.into(),
+            2 => self.settings.label = value.into(),
+            3 => self.settings.display_two_rows = value.into(),
+            4 => self.settings.label_color = value.into(),
+            5 => self.settings.value_color = value.into(),
+            6 => self.settings.prefix = value.into(),
+            7 => self.settings.suffix = value.into(),
+            8 => self.settings.fallback = value.into(),
+            9 => self.settings.show_graph = value.into(),
+            10 => self.settings.graph_height = value.into_uint().unwrap() as _,
+            11 => self.settings.graph_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+fn split_value(segment: &Segment, name: &str) -> Option<f32> {
+    parse(segment.variables().get(name)?)
+}
+
+fn parse(value: &str) -> Option<f32> {
+    value.trim().parse::<f32>().ok().filter(|v| v.is_finite())
+}
@@ -0,0 +1,83 @@
+use super::{Component, Point};
+use crate::{
+    timing::formatter,
+    util::tests_helper::{create_timer, start_run},
+    TimeSpan,
+};
+
+#[test]
+fn shows_the_value_with_prefix_and_suffix() {
+    let mut timer = create_timer(&["A"]);
+    timer.set_custom_variable("Deaths", "12");
+
+    let mut component = Component::new();
+    component.settings_mut().variable_name = String::from("Deaths");
+    component.settings_mut().prefix = String::from("☠ ");
+    component.settings_mut().suffix = String::from(" deaths");
+
+    let state = component.state(&timer.snapshot());
+    assert_eq!(state.label, "Deaths");
+    assert_eq!(state.value, "☠ 12 deaths");
+    assert!(state.graph.is_none());
+
+    component.settings_mut().label = Some(String::from("Skulls"));
+    let state = component.state(&timer.snapshot());
+    assert_eq!(state.label, "Skulls");
+}
+
+#[test]
+fn uses_fallback_for_missing_variables() {
+    let mut timer = create_timer(&["A"]);
+
+    let mut component = Component::new();
+    component.settings_mut().variable_name = String::from("Area");
+    component.settings_mut().prefix = String::from("Area: ");
+
+    let state = component.state(&timer.snapshot());
+    assert_eq!(state.value, formatter::DASH);
+
+    timer.set_custom_variable("Area", "  ");
+    component.settings_mut().fallback = String::from("Unknown");
+    let state = component.state(&timer.snapshot());
+    assert_eq!(state.value, "Unknown");
+}
+
+#[test]
+fn graphs_numeric_values_at_each_split() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+
+    let mut component = Component::new();
+    component.settings_mut().variable_name = String::from("Deaths");
+    component.settings_mut().show_graph = true;
+
+    let state = component.state(&timer.snapshot());
+    assert!(state.graph.unwrap().points.is_empty());
+
+    start_run(&mut timer);
+    for (time, deaths) in [(1.0, "0"), (2.0, "not a number"), (3.0, "4")] {
+        timer.set_custom_variable("Deaths", deaths);
+        timer.set_game_time(TimeSpan::from_seconds(time));
+        timer.split();
+    }
+    timer.set_custom_variable("Deaths", "2");
+
+    let graph = component.state(&timer.snapshot()).graph.unwrap();
+    assert!(graph.is_live_value_active);
+    assert_points(&graph.points, &[(0.25, 0.9), (0.75, 0.1), (1.0, 0.5)]);
+
+    timer.set_game_time(TimeSpan::from_seconds(4.0));
+    timer.split();
+
+    let graph = component.state(&timer.snapshot()).graph.unwrap();
+    assert!(!graph.is_live_value_active);
+    assert_points(&graph.points, &[(0.25, 0.9), (0.75, 0.1), (1.0, 0.5)]);
+}
+
+#[track_caller]
+fn assert_points(actual: &[Point], expected: &[(f32, f32)]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual, &(x, y)) in actual.iter().zip(expected) {
+        assert!((actual.x - x).abs() < 1e-6, "{actual:?}");
+        assert!((actual.y - y).abs() < 1e-6, "{actual:?}");
+    }
+}
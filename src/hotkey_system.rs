@@ -3,7 +3,7 @@ use livesplit_hotkey::KeyCode;
 
 use crate::{
     hotkey::{Hook, Hotkey},
-    HotkeyConfig, SharedTimer,
+    HotkeyConfig, KeyEventFeed, SharedTimer,
 };
 
 pub use crate::hotkey::{Error, Result};
@@ -92,6 +92,7 @@ pub struct HotkeySystem {
     hook: Hook,
     timer: SharedTimer,
     is_active: bool,
+    key_listeners: Vec<KeyCode>,
 }
 
 impl HotkeySystem {
@@ -108,6 +109,7 @@ impl HotkeySystem {
             hook: Hook::new()?,
             timer,
             is_active: false,
+            key_listeners: Vec::new(),
         };
         hotkey_system.activate()?;
         Ok(hotkey_system)
@@ -269,6 +271,40 @@ impl HotkeySystem {
         Ok(())
     }
 
+    /// Forwards all the presses and releases of the given keys to the key
+    /// event feed, which is usually the one of an
+    /// [`InputDisplay`](crate::component::InputDisplay) component. Any keys
+    /// that were previously forwarded to a key event feed stop being forwarded.
+    /// The key events are forwarded regardless of whether the Hotkey System is
+    /// active, as they don't control the timer.
+    pub fn set_key_event_feed(
+        &mut self,
+        feed: KeyEventFeed,
+        key_codes: impl IntoIterator<Item = KeyCode>,
+    ) -> Result<()> {
+        self.clear_key_event_feed()?;
+        for key_code in key_codes {
+            if self.key_listeners.contains(&key_code) {
+                continue;
+            }
+            let feed = feed.clone();
+            self.hook
+                .register_key_listener(key_code, move |event| feed.push(key_code, event))?;
+            self.key_listeners.push(key_code);
+        }
+        Ok(())
+    }
+
+    /// Stops forwarding key events to the key event feed that was previously
+    /// set with [`set_key_event_feed`](Self::set_key_event_feed).
+    pub fn clear_key_event_feed(&mut self) -> Result<()> {
+        while let Some(&key_code) = self.key_listeners.last() {
+            self.hook.unregister_key_listener(key_code)?;
+            self.key_listeners.pop();
+        }
+        Ok(())
+    }
+
     /// Resolves the key according to the current keyboard layout.
     pub fn resolve(&self, key_code: KeyCode) -> Cow<'static, str> {
         key_code.resolve(&self.hook)
@@ -0,0 +1,104 @@
+use crate::{
+    hotkey::{KeyCode, KeyEvent},
+    platform::{Arc, RwLock},
+};
+use alloc::collections::VecDeque;
+use hashbrown::HashMap;
+
+/// The maximum number of recent key presses a [`KeyEventFeed`] remembers.
+pub(crate) const MAX_RECENT_PRESSES: usize = 16;
+
+/// A key event feed collects the key presses and releases observed on the
+/// keyboard. The feed can be cloned cheaply and all the clones share the same
+/// key states, so one clone can be handed to whatever observes the keyboard,
+/// such as the [`HotkeySystem`](crate::HotkeySystem), while something like the
+/// [`InputDisplay`](crate::component::InputDisplay) component reads from
+/// another one.
+#[derive(Clone)]
+pub struct KeyEventFeed {
+    keys: Arc<RwLock<Keys>>,
+}
+
+#[derive(Default)]
+struct Keys {
+    statuses: HashMap<KeyCode, KeyStatus>,
+    recent_presses: VecDeque<KeyCode>,
+}
+
+#[derive(Default, Copy, Clone)]
+pub(crate) struct KeyStatus {
+    pub is_pressed: bool,
+    pub press_count: u32,
+}
+
+impl Default for KeyEventFeed {
+    fn default() -> Self {
+        Self {
+            keys: Arc::new(RwLock::new(Keys::default())),
+        }
+    }
+}
+
+impl KeyEventFeed {
+    /// Creates a new key event feed where none of the keys are pressed.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Registers that the key got pressed or released. Pressing a key that is
+    /// already held down is not counted as another press. The last 16 presses
+    /// are remembered in the order they happened.
+    pub fn push(&self, key_code: KeyCode, event: KeyEvent) {
+        let mut keys = self.keys.write().unwrap();
+        let Keys {
+            statuses,
+            recent_presses,
+        } = &mut *keys;
+        let status = statuses.entry(key_code).or_default();
+        match event {
+            KeyEvent::Pressed => {
+                if !status.is_pressed {
+                    status.is_pressed = true;
+                    status.press_count = status.press_count.saturating_add(1);
+                    if recent_presses.len() == MAX_RECENT_PRESSES {
+                        recent_presses.pop_back();
+                    }
+                    recent_presses.push_front(key_code);
+                }
+            }
+            KeyEvent::Released => status.is_pressed = false,
+        }
+    }
+
+    /// Resets the number of times each key got pressed back to zero and
+    /// forgets about the recent key presses.
+    pub fn reset_press_counts(&self) {
+        let mut keys = self.keys.write().unwrap();
+        for status in keys.statuses.values_mut() {
+            status.press_count = 0;
+        }
+        keys.recent_presses.clear();
+    }
+
+    pub(crate) fn status(&self, key_code: KeyCode) -> KeyStatus {
+        self.keys
+            .read()
+            .unwrap()
+            .statuses
+            .get(&key_code)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Calls the function for each of the recently pressed keys, starting
+    /// with the most recent one.
+    pub(crate) fn for_each_recent_press(&self, f: impl FnMut(KeyCode)) {
+        self.keys
+            .read()
+            .unwrap()
+            .recent_presses
+            .iter()
+            .copied()
+            .for_each(f);
+    }
+}
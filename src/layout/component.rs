@@ -1,9 +1,10 @@
 use super::{ComponentSettings, ComponentState, GeneralSettings};
 use crate::{
     component::{
-        blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-        possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-        timer, title, total_playtime,
+        attempt_counter, blank_space, clock, current_comparison, current_pace, delta,
+        detailed_timer, graph, image, input_display, pb_chance, possible_time_save,
+        previous_segment, segment_notes, segment_time, separator, session, splits, sum_of_best,
+        survival_chance, text, time_lost, timer, title, total_playtime, variable,
     },
     platform::prelude::*,
     settings::{SettingsDescription, Value},
@@ -15,8 +16,12 @@ use alloc::borrow::Cow;
 /// visualize. This type can store any of the components provided by this crate.
 #[derive(Clone)]
 pub enum Component {
+    /// The Attempt Counter Component.
+    AttemptCounter(attempt_counter::Component),
     /// The Blank Space Component.
     BlankSpace(blank_space::Component),
+    /// The Clock Component.
+    Clock(clock::Component),
     /// The Current Comparison Component.
     CurrentComparison(current_comparison::Component),
     /// The Current Pace Component.
@@ -27,28 +32,48 @@ pub enum Component {
     DetailedTimer(Box<detailed_timer::Component>),
     /// The Graph Component.
     Graph(graph::Component),
+    /// The Image Component.
+    Image(image::Component),
+    /// The Input Display Component.
+    InputDisplay(input_display::Component),
     /// The PB Chance Component.
     PbChance(pb_chance::Component),
     /// The Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Component),
     /// The Previous Segment Component.
     PreviousSegment(previous_segment::Component),
+    /// The Segment Notes Component.
+    SegmentNotes(segment_notes::Component),
     /// The Segment Time Component.
     SegmentTime(segment_time::Component),
     /// The Separator Component.
     Separator(separator::Component),
+    /// The Session Component.
+    Session(session::Component),
     /// The Splits Component.
     Splits(splits::Component),
     /// The Sum of Best Component.
     SumOfBest(sum_of_best::Component),
+    /// The Survival Chance Component.
+    SurvivalChance(survival_chance::Component),
     /// The Text Component.
     Text(text::Component),
+    /// The Time Lost Component.
+    TimeLost(time_lost::Component),
     /// The Timer Component.
     Timer(timer::Component),
     /// The Title Component.
     Title(title::Component),
     /// The Total Playtime Component.
     TotalPlaytime(total_playtime::Component),
+    /// The Variable Component.
+    Variable(variable::Component),
+}
+
+impl From<attempt_counter::Component> for Component {
+    fn from(component: attempt_counter::Component) -> Self {
+        Self::AttemptCounter(component)
+    }
 }
 
 impl From<blank_space::Component> for Component {
@@ -57,6 +82,12 @@ impl From<blank_space::Component> for Component {
     }
 }
 
+impl From<clock::Component> for Component {
+    fn from(component: clock::Component) -> Self {
+        Self::Clock(component)
+    }
+}
+
 impl From<current_comparison::Component> for Component {
     fn from(component: current_comparison::Component) -> Self {
         Self::CurrentComparison(component)
@@ -87,6 +118,18 @@ impl From<graph::Component> for Component {
     }
 }
 
+impl From<image::Component> for Component {
+    fn from(component: image::Component) -> Self {
+        Self::Image(component)
+    }
+}
+
+impl From<input_display::Component> for Component {
+    fn from(component: input_display::Component) -> Self {
+        Self::InputDisplay(component)
+    }
+}
+
 impl From<pb_chance::Component> for Component {
     fn from(component: pb_chance::Component) -> Self {
         Self::PbChance(component)
@@ -105,6 +148,12 @@ impl From<previous_segment::Component> for Component {
     }
 }
 
+impl From<segment_notes::Component> for Component {
+    fn from(component: segment_notes::Component) -> Self {
+        Self::SegmentNotes(component)
+    }
+}
+
 impl From<segment_time::Component> for Component {
     fn from(component: segment_time::Component) -> Self {
         Self::SegmentTime(component)
@@ -117,6 +166,12 @@ impl From<separator::Component> for Component {
     }
 }
 
+impl From<session::Component> for Component {
+    fn from(component: session::Component) -> Self {
+        Self::Session(component)
+    }
+}
+
 impl From<splits::Component> for Component {
     fn from(component: splits::Component) -> Self {
         Self::Splits(component)
@@ -129,12 +184,24 @@ impl From<sum_of_best::Component> for Component {
     }
 }
 
+impl From<survival_chance::Component> for Component {
+    fn from(component: survival_chance::Component) -> Self {
+        Self::SurvivalChance(component)
+    }
+}
+
 impl From<text::Component> for Component {
     fn from(component: text::Component) -> Self {
         Self::Text(component)
     }
 }
 
+impl From<time_lost::Component> for Component {
+    fn from(component: time_lost::Component) -> Self {
+        Self::TimeLost(component)
+    }
+}
+
 impl From<timer::Component> for Component {
     fn from(component: timer::Component) -> Self {
         Self::Timer(component)
@@ -153,6 +220,12 @@ impl From<total_playtime::Component> for Component {
     }
 }
 
+impl From<variable::Component> for Component {
+    fn from(component: variable::Component) -> Self {
+        Self::Variable(component)
+    }
+}
+
 impl Component {
     /// Updates the component's state based on the timer and settings provided.
     /// The timer provides the information to visualize and the layout settings
@@ -165,9 +238,15 @@ impl Component {
         layout_settings: &GeneralSettings,
     ) {
         match (state, self) {
+            (ComponentState::KeyValue(state), Component::AttemptCounter(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::BlankSpace(state), Component::BlankSpace(component)) => {
                 component.update_state(state)
             }
+            (ComponentState::KeyValue(state), Component::Clock(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::KeyValue(state), Component::CurrentComparison(component)) => {
                 component.update_state(state, timer)
             }
@@ -183,6 +262,12 @@ impl Component {
             (ComponentState::Graph(state), Component::Graph(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
+            (ComponentState::Image(state), Component::Image(component)) => {
+                component.update_state(state, timer)
+            }
+            (ComponentState::InputDisplay(state), Component::InputDisplay(component)) => {
+                component.update_state(state)
+            }
             (ComponentState::KeyValue(state), Component::PbChance(component)) => {
                 component.update_state(state, timer)
             }
@@ -192,21 +277,33 @@ impl Component {
             (ComponentState::KeyValue(state), Component::PreviousSegment(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
+            (ComponentState::SegmentNotes(state), Component::SegmentNotes(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::KeyValue(state), Component::SegmentTime(component)) => {
                 component.update_state(state, timer)
             }
             (ComponentState::Separator(state), Component::Separator(component)) => {
                 component.update_state(state)
             }
+            (ComponentState::KeyValue(state), Component::Session(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::Splits(state), Component::Splits(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
             (ComponentState::KeyValue(state), Component::SumOfBest(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::KeyValue(state), Component::SurvivalChance(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::Text(state), Component::Text(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::KeyValue(state), Component::TimeLost(component)) => {
+                component.update_state(state, timer)
+            }
             (ComponentState::Timer(state), Component::Timer(component)) => {
                 component.update_state(state, timer, layout_settings)
             }
@@ -216,6 +313,9 @@ impl Component {
             (ComponentState::KeyValue(state), Component::TotalPlaytime(component)) => {
                 component.update_state(state, timer)
             }
+            (ComponentState::Variable(state), Component::Variable(component)) => {
+                component.update_state(state, timer)
+            }
             (state, component) => *state = component.state(timer, layout_settings),
         }
     }
@@ -230,7 +330,11 @@ impl Component {
         layout_settings: &GeneralSettings,
     ) -> ComponentState {
         match self {
+            Component::AttemptCounter(component) => {
+                ComponentState::KeyValue(component.state(timer))
+            }
             Component::BlankSpace(component) => ComponentState::BlankSpace(component.state()),
+            Component::Clock(component) => ComponentState::KeyValue(component.state(timer)),
             Component::CurrentComparison(component) => {
                 ComponentState::KeyValue(component.state(timer))
             }
@@ -244,6 +348,8 @@ impl Component {
             Component::Graph(component) => {
                 ComponentState::Graph(component.state(timer, layout_settings))
             }
+            Component::Image(component) => ComponentState::Image(component.state(timer)),
+            Component::InputDisplay(component) => ComponentState::InputDisplay(component.state()),
             Component::PbChance(component) => ComponentState::KeyValue(component.state(timer)),
             Component::PossibleTimeSave(component) => {
                 ComponentState::KeyValue(component.state(timer))
@@ -251,18 +357,27 @@ impl Component {
             Component::PreviousSegment(component) => {
                 ComponentState::KeyValue(component.state(timer, layout_settings))
             }
+            Component::SegmentNotes(component) => {
+                ComponentState::SegmentNotes(component.state(timer))
+            }
             Component::SegmentTime(component) => ComponentState::KeyValue(component.state(timer)),
             Component::Separator(component) => ComponentState::Separator(component.state()),
+            Component::Session(component) => ComponentState::KeyValue(component.state(timer)),
             Component::Splits(component) => {
                 ComponentState::Splits(component.state(timer, layout_settings))
             }
             Component::SumOfBest(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::SurvivalChance(component) => {
+                ComponentState::KeyValue(component.state(timer))
+            }
             Component::Text(component) => ComponentState::Text(component.state(timer)),
+            Component::TimeLost(component) => ComponentState::KeyValue(component.state(timer)),
             Component::Timer(component) => {
                 ComponentState::Timer(component.state(timer, layout_settings))
             }
             Component::Title(component) => ComponentState::Title(component.state(timer)),
             Component::TotalPlaytime(component) => ComponentState::KeyValue(component.state(timer)),
+            Component::Variable(component) => ComponentState::Variable(component.state(timer)),
         }
     }
 
@@ -272,9 +387,13 @@ impl Component {
     /// Settings Description instead.
     pub fn settings(&self) -> ComponentSettings {
         match self {
+            Component::AttemptCounter(component) => {
+                ComponentSettings::AttemptCounter(component.settings().clone())
+            }
             Component::BlankSpace(component) => {
                 ComponentSettings::BlankSpace(component.settings().clone())
             }
+            Component::Clock(component) => ComponentSettings::Clock(component.settings().clone()),
             Component::CurrentComparison(component) => {
                 ComponentSettings::CurrentComparison(component.settings().clone())
             }
@@ -286,6 +405,10 @@ impl Component {
                 ComponentSettings::DetailedTimer(Box::new(component.settings().clone()))
             }
             Component::Graph(component) => ComponentSettings::Graph(component.settings().clone()),
+            Component::Image(component) => ComponentSettings::Image(component.settings().clone()),
+            Component::InputDisplay(component) => {
+                ComponentSettings::InputDisplay(component.settings().clone())
+            }
             Component::PbChance(component) => {
                 ComponentSettings::PbChance(component.settings().clone())
             }
@@ -295,43 +418,67 @@ impl Component {
             Component::PreviousSegment(component) => {
                 ComponentSettings::PreviousSegment(component.settings().clone())
             }
+            Component::SegmentNotes(component) => {
+                ComponentSettings::SegmentNotes(component.settings().clone())
+            }
             Component::SegmentTime(component) => {
                 ComponentSettings::SegmentTime(component.settings().clone())
             }
             Component::Separator(_) => ComponentSettings::Separator,
+            Component::Session(component) => {
+                ComponentSettings::Session(component.settings().clone())
+            }
             Component::Splits(component) => ComponentSettings::Splits(component.settings().clone()),
             Component::SumOfBest(component) => {
                 ComponentSettings::SumOfBest(component.settings().clone())
             }
+            Component::SurvivalChance(component) => {
+                ComponentSettings::SurvivalChance(component.settings().clone())
+            }
             Component::Text(component) => ComponentSettings::Text(component.settings().clone()),
+            Component::TimeLost(component) => {
+                ComponentSettings::TimeLost(component.settings().clone())
+            }
             Component::Timer(component) => ComponentSettings::Timer(component.settings().clone()),
             Component::Title(component) => ComponentSettings::Title(component.settings().clone()),
             Component::TotalPlaytime(component) => {
                 ComponentSettings::TotalPlaytime(component.settings().clone())
             }
+            Component::Variable(component) => {
+                ComponentSettings::Variable(component.settings().clone())
+            }
         }
     }
 
     /// Accesses the name of the component.
     pub fn name(&self) -> Cow<'_, str> {
         match self {
+            Component::AttemptCounter(component) => component.name().into(),
             Component::BlankSpace(component) => component.name().into(),
+            Component::Clock(component) => component.name().into(),
             Component::CurrentComparison(component) => component.name().into(),
             Component::CurrentPace(component) => component.name(),
             Component::Delta(component) => component.name(),
             Component::DetailedTimer(component) => component.name().into(),
             Component::Graph(component) => component.name(),
+            Component::Image(component) => component.name().into(),
+            Component::InputDisplay(component) => component.name().into(),
             Component::PbChance(component) => component.name().into(),
             Component::PossibleTimeSave(component) => component.name(),
             Component::PreviousSegment(component) => component.name(),
+            Component::SegmentNotes(component) => component.name().into(),
             Component::SegmentTime(component) => component.name(),
             Component::Separator(component) => component.name().into(),
+            Component::Session(component) => component.name().into(),
             Component::Splits(component) => component.name().into(),
             Component::SumOfBest(component) => component.name().into(),
+            Component::SurvivalChance(component) => component.name().into(),
             Component::Text(component) => component.name(),
+            Component::TimeLost(component) => component.name(),
             Component::Timer(component) => component.name().into(),
             Component::Title(component) => component.name().into(),
             Component::TotalPlaytime(component) => component.name().into(),
+            Component::Variable(component) => component.name(),
         }
     }
 
@@ -358,6 +505,7 @@ impl Component {
     pub fn remount(&mut self) {
         match self {
             Component::DetailedTimer(component) => component.remount(),
+            Component::Image(component) => component.remount(),
             Component::Splits(component) => component.remount(),
             Component::Title(component) => component.remount(),
             _ => {}
@@ -370,23 +518,32 @@ impl Component {
     /// interface independent way of changing the settings.
     pub fn settings_description(&self) -> SettingsDescription {
         match self {
+            Component::AttemptCounter(component) => component.settings_description(),
             Component::BlankSpace(component) => component.settings_description(),
+            Component::Clock(component) => component.settings_description(),
             Component::CurrentComparison(component) => component.settings_description(),
             Component::CurrentPace(component) => component.settings_description(),
             Component::Delta(component) => component.settings_description(),
             Component::DetailedTimer(component) => component.settings_description(),
             Component::Graph(component) => component.settings_description(),
+            Component::Image(component) => component.settings_description(),
+            Component::InputDisplay(component) => component.settings_description(),
             Component::PbChance(component) => component.settings_description(),
             Component::PossibleTimeSave(component) => component.settings_description(),
             Component::PreviousSegment(component) => component.settings_description(),
+            Component::SegmentNotes(component) => component.settings_description(),
             Component::SegmentTime(component) => component.settings_description(),
             Component::Separator(component) => component.settings_description(),
+            Component::Session(component) => component.settings_description(),
             Component::Splits(component) => component.settings_description(),
             Component::SumOfBest(component) => component.settings_description(),
+            Component::SurvivalChance(component) => component.settings_description(),
             Component::Text(component) => component.settings_description(),
+            Component::TimeLost(component) => component.settings_description(),
             Component::Timer(component) => component.settings_description(),
             Component::Title(component) => component.settings_description(),
             Component::TotalPlaytime(component) => component.settings_description(),
+            Component::Variable(component) => component.settings_description(),
         }
     }
 
@@ -400,23 +557,32 @@ impl Component {
     /// have a compatible type.
     pub fn set_value(&mut self, index: usize, value: Value) {
         match self {
+            Component::AttemptCounter(component) => component.set_value(index, value),
             Component::BlankSpace(component) => component.set_value(index, value),
+            Component::Clock(component) => component.set_value(index, value),
             Component::CurrentComparison(component) => component.set_value(index, value),
             Component::CurrentPace(component) => component.set_value(index, value),
             Component::Delta(component) => component.set_value(index, value),
             Component::DetailedTimer(component) => component.set_value(index, value),
             Component::Graph(component) => component.set_value(index, value),
+            Component::Image(component) => component.set_value(index, value),
+            Component::InputDisplay(component) => component.set_value(index, value),
             Component::PbChance(component) => component.set_value(index, value),
             Component::PossibleTimeSave(component) => component.set_value(index, value),
             Component::PreviousSegment(component) => component.set_value(index, value),
+            Component::SegmentNotes(component) => component.set_value(index, value),
             Component::SegmentTime(component) => component.set_value(index, value),
             Component::Separator(component) => component.set_value(index, value),
+            Component::Session(component) => component.set_value(index, value),
             Component::Splits(component) => component.set_value(index, value),
             Component::SumOfBest(component) => component.set_value(index, value),
+            Component::SurvivalChance(component) => component.set_value(index, value),
             Component::Text(component) => component.set_value(index, value),
+            Component::TimeLost(component) => component.set_value(index, value),
             Component::Timer(component) => component.set_value(index, value),
             Component::Title(component) => component.set_value(index, value),
             Component::TotalPlaytime(component) => component.set_value(index, value),
+            Component::Variable(component) => component.set_value(index, value),
         }
     }
 }
@@ -1,8 +1,9 @@
 use super::Component;
 use crate::component::{
-    blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-    possible_time_save, previous_segment, segment_time, separator, splits, sum_of_best, text,
-    timer, title, total_playtime,
+    attempt_counter, blank_space, clock, current_comparison, current_pace, delta, detailed_timer,
+    graph, image, input_display, pb_chance, possible_time_save, previous_segment, segment_notes,
+    segment_time, separator, session, splits, sum_of_best, survival_chance, text, time_lost, timer,
+    title, total_playtime, variable,
 };
 use crate::platform::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -10,8 +11,12 @@ use serde::{Deserialize, Serialize};
 /// The settings for one of the components available.
 #[derive(Clone, Serialize, Deserialize)]
 pub enum ComponentSettings {
+    /// The Settings for the Attempt Counter Component.
+    AttemptCounter(attempt_counter::Settings),
     /// The Settings for the Blank Space Component.
     BlankSpace(blank_space::Settings),
+    /// The Settings for the Clock Component.
+    Clock(clock::Settings),
     /// The Settings for the Current Comparison Component.
     CurrentComparison(current_comparison::Settings),
     /// The Settings for the Current Pace Component.
@@ -22,36 +27,56 @@ pub enum ComponentSettings {
     DetailedTimer(Box<detailed_timer::Settings>),
     /// The Settings for the Graph Component.
     Graph(graph::Settings),
+    /// The Settings for the Image Component.
+    Image(image::Settings),
+    /// The Settings for the Input Display Component.
+    InputDisplay(input_display::Settings),
     /// The Settings for the PB Chance Component.
     PbChance(pb_chance::Settings),
     /// The Settings for the Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Settings),
     /// The Settings for the Previous Segment Component.
     PreviousSegment(previous_segment::Settings),
+    /// The Settings for the Segment Notes Component.
+    SegmentNotes(segment_notes::Settings),
     /// The Settings for the Segment Time Component.
     SegmentTime(segment_time::Settings),
     /// The Settings for the Separator Component.
     Separator,
+    /// The Settings for the Session Component.
+    Session(session::Settings),
     /// The Settings for the Splits Component.
     Splits(splits::Settings),
     /// The Settings for the Sum Of Best Component.
     SumOfBest(sum_of_best::Settings),
+    /// The Settings for the Survival Chance Component.
+    SurvivalChance(survival_chance::Settings),
     /// The Settings for the Text Component.
     Text(text::Settings),
+    /// The Settings for the Time Lost Component.
+    TimeLost(time_lost::Settings),
     /// The Settings for the Timer Component.
     Timer(timer::Settings),
     /// The Settings for the Title Component.
     Title(title::Settings),
     /// The Settings for the Total Playtime Component.
     TotalPlaytime(total_playtime::Settings),
+    /// The Settings for the Variable Component.
+    Variable(variable::Settings),
 }
 
 impl From<ComponentSettings> for Component {
     fn from(settings: ComponentSettings) -> Self {
         match settings {
+            ComponentSettings::AttemptCounter(settings) => {
+                Component::AttemptCounter(attempt_counter::Component::with_settings(settings))
+            }
             ComponentSettings::BlankSpace(settings) => {
                 Component::BlankSpace(blank_space::Component::with_settings(settings))
             }
+            ComponentSettings::Clock(settings) => {
+                Component::Clock(clock::Component::with_settings(settings))
+            }
             ComponentSettings::CurrentComparison(settings) => {
                 Component::CurrentComparison(current_comparison::Component::with_settings(settings))
             }
@@ -67,6 +92,12 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::Graph(settings) => {
                 Component::Graph(graph::Component::with_settings(settings))
             }
+            ComponentSettings::Image(settings) => {
+                Component::Image(image::Component::with_settings(settings))
+            }
+            ComponentSettings::InputDisplay(settings) => {
+                Component::InputDisplay(input_display::Component::with_settings(settings))
+            }
             ComponentSettings::PbChance(settings) => {
                 Component::PbChance(pb_chance::Component::with_settings(settings))
             }
@@ -76,19 +107,31 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::PreviousSegment(settings) => {
                 Component::PreviousSegment(previous_segment::Component::with_settings(settings))
             }
+            ComponentSettings::SegmentNotes(settings) => {
+                Component::SegmentNotes(segment_notes::Component::with_settings(settings))
+            }
             ComponentSettings::SegmentTime(settings) => {
                 Component::SegmentTime(segment_time::Component::with_settings(settings))
             }
             ComponentSettings::Separator => Component::Separator(separator::Component::new()),
+            ComponentSettings::Session(settings) => {
+                Component::Session(session::Component::with_settings(settings))
+            }
             ComponentSettings::Splits(settings) => {
                 Component::Splits(splits::Component::with_settings(settings))
             }
             ComponentSettings::SumOfBest(settings) => {
                 Component::SumOfBest(sum_of_best::Component::with_settings(settings))
             }
+            ComponentSettings::SurvivalChance(settings) => {
+                Component::SurvivalChance(survival_chance::Component::with_settings(settings))
+            }
             ComponentSettings::Text(settings) => {
                 Component::Text(text::Component::with_settings(settings))
             }
+            ComponentSettings::TimeLost(settings) => {
+                Component::TimeLost(time_lost::Component::with_settings(settings))
+            }
             ComponentSettings::Timer(settings) => {
                 Component::Timer(timer::Component::with_settings(settings))
             }
@@ -98,6 +141,9 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::TotalPlaytime(settings) => {
                 Component::TotalPlaytime(total_playtime::Component::with_settings(settings))
             }
+            ComponentSettings::Variable(settings) => {
+                Component::Variable(variable::Component::with_settings(settings))
+            }
         }
     }
 }
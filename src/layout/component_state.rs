@@ -1,5 +1,6 @@
 use crate::component::{
-    blank_space, detailed_timer, graph, key_value, separator, splits, text, timer, title,
+    blank_space, detailed_timer, graph, image, input_display, key_value, segment_notes, separator,
+    splits, text, timer, title, variable,
 };
 use crate::platform::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -13,8 +14,14 @@ pub enum ComponentState {
     DetailedTimer(Box<detailed_timer::State>),
     /// The state object for the Graph Component.
     Graph(graph::State),
+    /// The state object for the Image Component.
+    Image(image::State),
+    /// The state object for the Input Display Component.
+    InputDisplay(input_display::State),
     /// The state object for a key value based component.
     KeyValue(key_value::State),
+    /// The state object for the Segment Notes Component.
+    SegmentNotes(segment_notes::State),
     /// The state object for the Separator Component.
     Separator(separator::State),
     /// The state object for the Splits Component.
@@ -25,4 +32,6 @@ pub enum ComponentState {
     Timer(timer::State),
     /// The state object for the Title Component.
     Title(title::State),
+    /// The state object for the Variable Component.
+    Variable(variable::State),
 }
@@ -2,7 +2,7 @@
 
 use super::{Component, Layout, LayoutDirection};
 use crate::{
-    component::{separator, timer::DeltaGradient},
+    component::{clock, separator, timer::DeltaGradient},
     platform::{math::f32::powf, prelude::*},
     settings::{
         Alignment, Color, Font, FontStretch, FontStyle, FontWeight, Gradient, ListGradient,
@@ -591,6 +591,7 @@ where
             "Path" => text_as_escaped_string_err(reader, |text| {
                 component = Some(match text {
                     "LiveSplit.BlankSpace.dll" => blank_space::Component::new().into(),
+                    "LiveSplit.Clock.dll" => clock::Component::new().into(),
                     "LiveSplit.CurrentComparison.dll" => {
                         current_comparison::Component::new().into()
                     }
@@ -604,8 +605,13 @@ where
                     "LiveSplit.PossibleTimeSave.dll" => possible_time_save::Component::new().into(),
                     "LiveSplit.PreviousSegment.dll" => previous_segment::Component::new().into(),
                     "" => separator::Component::new().into(),
-                    "LiveSplit.Splits.dll" | "LiveSplit.Subsplits.dll" => {
-                        splits::Component::new().into()
+                    "LiveSplit.Splits.dll" => splits::Component::new().into(),
+                    "LiveSplit.Subsplits.dll" => {
+                        splits::Component::with_settings(splits::Settings {
+                            group_subsplits: true,
+                            ..Default::default()
+                        })
+                        .into()
                     }
                     "LiveSplit.SumOfBest.dll" => sum_of_best::Component::new().into(),
                     "LiveSplit.Text.dll" => text::Component::new().into(),
@@ -622,6 +628,9 @@ where
                 if let Some(component) = &mut component {
                     match component {
                         Component::BlankSpace(c) => blank_space::settings(reader, c),
+                        // The settings of the clock aren't stored in a known
+                        // format, so it keeps its default settings.
+                        Component::Clock(_) => end_tag(reader),
                         Component::CurrentComparison(c) => current_comparison::settings(reader, c),
                         Component::CurrentPace(c) => current_pace::settings(reader, c),
                         Component::Delta(c) => delta::settings(reader, c),
@@ -630,14 +639,25 @@ where
                         Component::PbChance(c) => pb_chance::settings(reader, c),
                         Component::PossibleTimeSave(c) => possible_time_save::settings(reader, c),
                         Component::PreviousSegment(c) => previous_segment::settings(reader, c),
+                        Component::SegmentNotes(_) => end_tag(reader),
                         Component::SegmentTime(_) => end_tag(reader),
                         Component::Separator(_) => end_tag(reader),
+                        Component::Session(_) => end_tag(reader),
                         Component::Splits(c) => splits::settings(reader, c),
                         Component::SumOfBest(c) => sum_of_best::settings(reader, c),
+                        Component::SurvivalChance(_) => end_tag(reader),
                         Component::Text(c) => text::settings(reader, c),
+                        Component::TimeLost(_) => end_tag(reader),
                         Component::Timer(c) => timer::settings(reader, c),
                         Component::Title(c) => title::settings(reader, c),
                         Component::TotalPlaytime(c) => total_playtime::settings(reader, c),
+                        // These components don't exist in the original
+                        // LiveSplit, so there is no Path mapping to them and
+                        // the match only needs to be exhaustive.
+                        Component::AttemptCounter(_)
+                        | Component::Image(_)
+                        | Component::InputDisplay(_)
+                        | Component::Variable(_) => end_tag(reader),
                     }
                 } else {
                     end_tag(reader)
@@ -5,13 +5,13 @@ use super::{
 use crate::{
     component::splits::{
         self, ColumnKind, ColumnSettings, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith,
-        TimeColumn,
+        SubsplitVisibility, TimeColumn,
     },
     platform::prelude::*,
     util::xml::{helper::text_as_escaped_string_err, Reader},
 };
 
-pub use crate::component::splits::Component;
+pub use crate::component::splits::{Component, Settings};
 
 pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()> {
     let settings = component.settings_mut();
@@ -126,7 +126,7 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                             if !b {
                                 let comparison_override =
                                     settings.columns.pop().and_then(|c| match c.kind {
-                                        ColumnKind::Variable(_) => None,
+                                        ColumnKind::Variable(_) | ColumnKind::Chart(_) => None,
                                         ColumnKind::Time(c) => c.comparison_override,
                                     });
 
@@ -160,6 +160,22 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                     }),
                     "DeltasAccuracy" => accuracy(reader, |v| settings.delta_time_accuracy = v),
                     "DropDecimals" => parse_bool(reader, |v| settings.delta_drop_decimals = v),
+                    "HideSubsplits" => parse_bool(reader, |b| {
+                        if b {
+                            settings.subsplit_visibility = SubsplitVisibility::Never;
+                        }
+                    }),
+                    "ShowSubsplits" => parse_bool(reader, |b| {
+                        // Hiding the subsplits takes precedence.
+                        if b && settings.subsplit_visibility != SubsplitVisibility::Never {
+                            settings.subsplit_visibility = SubsplitVisibility::Always;
+                        }
+                    }),
+                    "CurrentSectionOnly" => {
+                        parse_bool(reader, |b| settings.current_section_only = b)
+                    }
+                    "IndentSubsplits" => parse_bool(reader, |b| settings.indent_subsplits = b),
+                    "ShowHeader" => parse_bool(reader, |b| settings.show_section_header = b),
                     _ => {
                         // FIXME:
                         // DisplayIcons
@@ -186,15 +202,10 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                         // FIXME: Subsplits
                         // MinimumMajorSplits
                         // IndentBlankIcons
-                        // IndentSubsplits
-                        // HideSubsplits
-                        // ShowSubsplits
-                        // CurrentSectionOnly
                         // OverrideSubsplitColor
                         // SubsplitTopColor
                         // SubsplitBottomColor
                         // SubsplitGradient
-                        // ShowHeader
                         // IndentSectionSplit
                         // ShowIconSectionSplit
                         // ShowSectionIcon
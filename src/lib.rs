@@ -68,6 +68,7 @@ pub mod component;
 mod hotkey_config;
 #[cfg(feature = "std")]
 mod hotkey_system;
+mod key_event_feed;
 pub mod layout;
 #[cfg(feature = "networking")]
 pub mod networking;
@@ -79,6 +80,7 @@ pub mod timing;
 pub mod util;
 
 pub use crate::{
+    key_event_feed::KeyEventFeed,
     layout::{Component, Editor as LayoutEditor, GeneralSettings as GeneralLayoutSettings, Layout},
     platform::DateTime,
     run::{Attempt, Editor as RunEditor, Run, RunMetadata, Segment, SegmentHistory},
@@ -11,9 +11,28 @@ cfg_if::cfg_if! {
                 x.powf(y)
             }
         }
+
+        pub mod f64 {
+            #[inline(always)]
+            pub fn floor(x: f64) -> f64 {
+                x.floor()
+            }
+
+            #[inline(always)]
+            pub fn sqrt(x: f64) -> f64 {
+                x.sqrt()
+            }
+
+            // The standard library doesn't provide the error function.
+            pub use libm::erf;
+        }
     } else {
         pub mod f32 {
             pub use libm::{fabsf as abs, powf};
         }
+
+        pub mod f64 {
+            pub use libm::{erf, floor, sqrt};
+        }
     }
 }
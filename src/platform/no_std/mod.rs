@@ -4,7 +4,7 @@ pub use self::time::*;
 pub struct RwLock<T>(core::cell::RefCell<T>);
 
 impl<T> RwLock<T> {
-    pub fn new(value: T) -> Self {
+    pub const fn new(value: T) -> Self {
         Self(core::cell::RefCell::new(value))
     }
 
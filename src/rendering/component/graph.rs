@@ -1,24 +1,49 @@
+use core::marker::PhantomData;
+
 use crate::{
-    component::graph::State,
+    component::graph::{LineStyle, Point, State},
     layout::LayoutState,
-    rendering::{PathBuilder, RenderContext, ResourceAllocator},
-    settings::Gradient,
+    platform::{math::f64::sqrt, prelude::*},
+    rendering::{
+        consts::{DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_CENTER},
+        font::CachedLabel,
+        scene::Layer,
+        solid, PathBuilder, RenderContext, ResourceAllocator,
+    },
+    settings::{Color, Gradient},
 };
 
-pub(in crate::rendering) fn render(
-    context: &mut RenderContext<'_, impl ResourceAllocator>,
+const GRID_LINE_WIDTH: f32 = 0.015;
+const LINE_WIDTH: f32 = 0.025;
+const CIRCLE_RADIUS: f32 = 0.035;
+const AXIS_LABEL_SCALE: f32 = 0.6 * DEFAULT_TEXT_SIZE;
+
+pub struct Cache<I, L> {
+    axis_labels: Vec<CachedLabel<L>>,
+    _image: PhantomData<I>,
+}
+
+impl<I, L> Cache<I, L> {
+    pub const fn new() -> Self {
+        Self {
+            axis_labels: Vec::new(),
+            _image: PhantomData,
+        }
+    }
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
     [width, height]: [f32; 2],
     component: &State,
-    _layout_state: &LayoutState,
+    layout_state: &LayoutState,
 ) {
     let old_transform = context.transform;
     context.scale(height);
+    let unscaled_width = width;
     let width = width / height;
 
-    const GRID_LINE_WIDTH: f32 = 0.015;
-    const LINE_WIDTH: f32 = 0.025;
-    const CIRCLE_RADIUS: f32 = 0.035;
-
     context.render_top_rectangle(
         [0.0, 0.0],
         [width, component.middle],
@@ -46,6 +71,36 @@ pub(in crate::rendering) fn render(
         );
     }
 
+    for bar in &component.bars {
+        let (top, bottom) = if bar.y < component.middle {
+            (bar.y, component.middle)
+        } else {
+            (component.middle, bar.y)
+        };
+        context.render_top_rectangle(
+            [width * bar.start, top],
+            [width * bar.end, bottom],
+            &Gradient::Plain(bar.color),
+        );
+    }
+
+    for line in &component.comparison_lines {
+        render_line(context, width, &line.points, line.color, line.line_style);
+    }
+
+    if !component.bars.is_empty() {
+        context.transform = old_transform;
+        render_axis_labels(
+            cache,
+            context,
+            unscaled_width,
+            height,
+            component,
+            layout_state,
+        );
+        return;
+    }
+
     let len = if component.is_live_delta_active {
         let p1 = &component.points[component.points.len() - 2];
         let p2 = &component.points[component.points.len() - 1];
@@ -105,4 +160,99 @@ pub(in crate::rendering) fn render(
     }
 
     context.transform = old_transform;
+    render_axis_labels(
+        cache,
+        context,
+        unscaled_width,
+        height,
+        component,
+        layout_state,
+    );
+}
+
+fn render_line(
+    context: &mut RenderContext<'_, impl ResourceAllocator>,
+    width: f32,
+    points: &[Point],
+    color: Color,
+    line_style: LineStyle,
+) {
+    // The length of a dash and the distance between the start of two dashes.
+    let (dash_length, period) = match line_style {
+        LineStyle::Solid => {
+            if let [first, rest @ ..] = points {
+                if !rest.is_empty() {
+                    let mut builder = context.handles.path_builder();
+                    builder.move_to(width * first.x, first.y);
+                    for p in rest {
+                        builder.line_to(width * p.x, p.y);
+                    }
+                    let line_path = builder.finish();
+                    context.top_layer_stroke_path(line_path, color, LINE_WIDTH);
+                }
+            }
+            return;
+        }
+        LineStyle::Dashed => (0.08, 0.13),
+        LineStyle::Dotted => (0.0, 0.07),
+    };
+
+    for points in points.windows(2) {
+        let (x1, y1) = (width * points[0].x, points[0].y);
+        let (x2, y2) = (width * points[1].x, points[1].y);
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let length = sqrt((dx * dx + dy * dy) as f64) as f32;
+        if length <= 0.0 {
+            continue;
+        }
+
+        let mut distance = 0.0;
+        while distance < length {
+            let start = distance / length;
+            if dash_length > 0.0 {
+                let end = (distance + dash_length).min(length) / length;
+                let mut builder = context.handles.path_builder();
+                builder.move_to(x1 + dx * start, y1 + dy * start);
+                builder.line_to(x1 + dx * end, y1 + dy * end);
+                let dash_path = builder.finish();
+                context.top_layer_stroke_path(dash_path, color, LINE_WIDTH);
+            } else {
+                let dot_path =
+                    context
+                        .handles
+                        .build_circle(x1 + dx * start, y1 + dy * start, LINE_WIDTH);
+                context.top_layer_path(dot_path, color);
+            }
+            distance += period;
+        }
+    }
+}
+
+fn render_axis_labels<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
+    width: f32,
+    height: f32,
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    cache
+        .axis_labels
+        .resize_with(component.axis_labels.len(), CachedLabel::new);
+
+    let text_color = solid(&layout_state.text_color);
+
+    for (label, cache) in component.axis_labels.iter().zip(&mut cache.axis_labels) {
+        context.render_text_right_align(
+            &label.text,
+            cache,
+            Layer::Top,
+            [
+                width - PADDING,
+                height * label.y + AXIS_LABEL_SCALE / DEFAULT_TEXT_SIZE * TEXT_ALIGN_CENTER,
+            ],
+            AXIS_LABEL_SCALE,
+            text_color,
+        );
+    }
 }
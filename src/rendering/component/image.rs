@@ -0,0 +1,57 @@
+use core::marker::PhantomData;
+
+use crate::{
+    component::image::{AspectMode, ImageAlignment, State},
+    rendering::{icon::Icon, resource::ResourceAllocator, RenderContext},
+};
+
+pub struct Cache<I, L> {
+    image: Option<Icon<I>>,
+    _label: PhantomData<L>,
+}
+
+impl<I, L> Cache<I, L> {
+    pub const fn new() -> Self {
+        Self {
+            image: None,
+            _label: PhantomData,
+        }
+    }
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+) {
+    context.render_background([width, height], &component.background);
+
+    if let Some(image) = &component.image_change {
+        cache.image = context.create_icon(image);
+    }
+
+    let Some(image) = &cache.image else {
+        return;
+    };
+
+    if component.aspect_mode == AspectMode::Stretch {
+        context.render_image([0.0, 0.0], [width, height], image);
+        return;
+    }
+
+    let (image_width, image_height) = if width / height > image.aspect_ratio {
+        (height * image.aspect_ratio, height)
+    } else {
+        (width, width / image.aspect_ratio)
+    };
+
+    let x = match component.alignment {
+        ImageAlignment::Left => 0.0,
+        ImageAlignment::Center => 0.5 * (width - image_width),
+        ImageAlignment::Right => width - image_width,
+    };
+    let y = 0.5 * (height - image_height);
+
+    context.render_image([x, y], [image_width, image_height], image);
+}
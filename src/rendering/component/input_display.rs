@@ -0,0 +1,171 @@
+use core::marker::PhantomData;
+
+use crate::{
+    component::input_display::State,
+    layout::LayoutState,
+    platform::prelude::*,
+    rendering::{
+        consts::{
+            DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, TEXT_ALIGN_BOTTOM, TEXT_ALIGN_CENTER,
+            TEXT_ALIGN_TOP, TWO_ROW_HEIGHT,
+        },
+        font::CachedLabel,
+        resource::ResourceAllocator,
+        solid, Layer, RenderContext,
+    },
+    settings::Gradient,
+};
+
+const KEY_WIDTH: f32 = 2.0;
+const KEY_SPACING: f32 = 0.1;
+
+pub struct Cache<I, L> {
+    keys: Vec<KeyCache<L>>,
+    recent_keys: String,
+    recent_keys_label: CachedLabel<L>,
+    _image: PhantomData<I>,
+}
+
+struct KeyCache<L> {
+    label: CachedLabel<L>,
+    press_count: CachedLabel<L>,
+}
+
+impl<I, L> Cache<I, L> {
+    pub const fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            recent_keys: String::new(),
+            recent_keys_label: CachedLabel::new(),
+            _image: PhantomData,
+        }
+    }
+}
+
+fn row_count(component: &State) -> usize {
+    let columns = component.keys_per_row.max(1) as usize;
+    (component.keys.len() + columns - 1) / columns
+}
+
+const fn row_height(component: &State) -> f32 {
+    if component.show_press_counts {
+        TWO_ROW_HEIGHT
+    } else {
+        DEFAULT_COMPONENT_HEIGHT
+    }
+}
+
+pub(super) fn width(component: &State) -> f32 {
+    let columns = component.keys.len().min(component.keys_per_row as usize);
+    columns as f32 * KEY_WIDTH
+}
+
+const fn recent_keys_height(component: &State) -> f32 {
+    if component.show_recent_keys {
+        DEFAULT_COMPONENT_HEIGHT
+    } else {
+        0.0
+    }
+}
+
+pub(super) fn height(component: &State) -> f32 {
+    row_count(component) as f32 * row_height(component) + recent_keys_height(component)
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_background([width, height], &component.background);
+
+    let row_count = row_count(component);
+    if row_count == 0 {
+        return;
+    }
+
+    let columns = component.keys_per_row.max(1) as usize;
+    let key_width = width / columns as f32;
+    let grid_height = height - recent_keys_height(component);
+    let key_height = grid_height / row_count as f32;
+    let released_text_color = component
+        .released_text_color
+        .unwrap_or(layout_state.text_color);
+
+    cache.keys.resize_with(component.keys.len(), || KeyCache {
+        label: CachedLabel::new(),
+        press_count: CachedLabel::new(),
+    });
+
+    // The keys are expected to change their state frequently, so they are
+    // placed on the top layer. This way pressing a key doesn't cause the
+    // bottom layer to be rerendered.
+    for (index, (key, key_cache)) in component.keys.iter().zip(&mut cache.keys).enumerate() {
+        let left = (index % columns) as f32 * key_width + KEY_SPACING;
+        let top = (index / columns) as f32 * key_height + KEY_SPACING;
+        let right = left + key_width - 2.0 * KEY_SPACING;
+        let bottom = top + key_height - 2.0 * KEY_SPACING;
+
+        let (key_color, text_color) = if key.is_pressed {
+            (component.pressed_key_color, component.pressed_text_color)
+        } else {
+            (component.released_key_color, released_text_color)
+        };
+
+        context.render_top_rectangle([left, top], [right, bottom], &Gradient::Plain(key_color));
+
+        let center_x = 0.5 * (left + right);
+        let label_y = if key.press_count.is_empty() {
+            0.5 * (top + bottom) + TEXT_ALIGN_CENTER
+        } else {
+            context.render_text_centered(
+                &key.press_count,
+                &mut key_cache.press_count,
+                Layer::Top,
+                left,
+                right,
+                [center_x, bottom + KEY_SPACING + TEXT_ALIGN_BOTTOM],
+                DEFAULT_TEXT_SIZE,
+                solid(&text_color),
+            );
+            top - KEY_SPACING + TEXT_ALIGN_TOP
+        };
+
+        context.render_text_centered(
+            &key.label,
+            &mut key_cache.label,
+            Layer::Top,
+            left,
+            right,
+            [center_x, label_y],
+            DEFAULT_TEXT_SIZE,
+            solid(&text_color),
+        );
+    }
+
+    if component.show_recent_keys {
+        cache.recent_keys.clear();
+        for (index, label) in component.recent_keys.iter().enumerate() {
+            if index != 0 {
+                cache.recent_keys.push(' ');
+            }
+            cache.recent_keys.push_str(label);
+        }
+
+        context.render_text_centered(
+            &cache.recent_keys,
+            &mut cache.recent_keys_label,
+            Layer::Top,
+            KEY_SPACING,
+            width - KEY_SPACING,
+            [
+                0.5 * width,
+                grid_height + 0.5 * DEFAULT_COMPONENT_HEIGHT + TEXT_ALIGN_CENTER,
+            ],
+            DEFAULT_TEXT_SIZE,
+            solid(&released_text_color),
+        );
+    }
+}
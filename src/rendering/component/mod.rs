@@ -9,21 +9,30 @@ use super::{
 pub mod blank_space;
 pub mod detailed_timer;
 pub mod graph;
+pub mod image;
+pub mod input_display;
 pub mod key_value;
+pub mod segment_notes;
 pub mod separator;
 pub mod splits;
 pub mod text;
 pub mod timer;
 pub mod title;
+pub mod variable;
 
-pub enum Cache<I, L> {
+pub enum Cache<P, I, L> {
     Empty,
     DetailedTimer(detailed_timer::Cache<I, L>),
+    Graph(graph::Cache<I, L>),
+    Image(image::Cache<I, L>),
+    InputDisplay(input_display::Cache<I, L>),
     KeyValue(key_value::Cache<I, L>),
-    Splits(splits::Cache<I, L>),
+    SegmentNotes(segment_notes::Cache<I, L>),
+    Splits(splits::Cache<P, I, L>),
     Text(text::Cache<I, L>),
     Timer(timer::Cache<I, L>),
     Title(title::Cache<I, L>),
+    Variable(variable::Cache<I, L>),
 }
 
 macro_rules! accessors {
@@ -42,15 +51,20 @@ macro_rules! accessors {
     };
 }
 
-impl<I, L> Cache<I, L> {
+impl<P, I, L> Cache<P, I, L> {
     pub const fn new(component: &ComponentState) -> Self {
         match component {
             ComponentState::DetailedTimer(_) => Self::DetailedTimer(detailed_timer::Cache::new()),
+            ComponentState::Graph(_) => Self::Graph(graph::Cache::new()),
+            ComponentState::Image(_) => Self::Image(image::Cache::new()),
+            ComponentState::InputDisplay(_) => Self::InputDisplay(input_display::Cache::new()),
             ComponentState::KeyValue(_) => Self::KeyValue(key_value::Cache::new()),
+            ComponentState::SegmentNotes(_) => Self::SegmentNotes(segment_notes::Cache::new()),
             ComponentState::Splits(_) => Self::Splits(splits::Cache::new()),
             ComponentState::Text(_) => Self::Text(text::Cache::new()),
             ComponentState::Timer(_) => Self::Timer(timer::Cache::new()),
             ComponentState::Title(_) => Self::Title(title::Cache::new()),
+            ComponentState::Variable(_) => Self::Variable(variable::Cache::new()),
             _ => Self::Empty,
         }
     }
@@ -61,11 +75,26 @@ impl<I, L> Cache<I, L> {
 
     accessors! {
         DetailedTimer detailed_timer,
+        Graph graph,
+        Image image,
+        InputDisplay input_display,
         KeyValue key_value,
-        Splits splits,
+        SegmentNotes segment_notes,
         Text text,
         Timer timer,
-        Title title
+        Title title,
+        Variable variable
+    }
+
+    // The splits additionally cache paths, so they don't fit the accessors.
+    fn splits(&mut self) -> &mut splits::Cache<P, I, L> {
+        match self {
+            Self::Splits(c) => c,
+            _ => {
+                *self = Self::Splits(splits::Cache::new());
+                self.splits()
+            }
+        }
     }
 }
 
@@ -82,7 +111,10 @@ pub fn width(component: &ComponentState) -> f32 {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
         ComponentState::DetailedTimer(_) => 7.0,
         ComponentState::Graph(_) => 7.0,
+        ComponentState::Image(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::InputDisplay(state) => input_display::width(state),
         ComponentState::KeyValue(_) => 6.0,
+        ComponentState::SegmentNotes(_) => 6.0,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
             let column_count = 2.0; // FIXME: Not always 2.
@@ -93,6 +125,7 @@ pub fn width(component: &ComponentState) -> f32 {
         ComponentState::Text(_) => 6.0,
         ComponentState::Timer(_) => 8.25,
         ComponentState::Title(_) => 8.0,
+        ComponentState::Variable(_) => 6.0,
     }
 }
 
@@ -103,6 +136,8 @@ pub fn height(component: &ComponentState) -> f32 {
             (state.timer.height + state.segment_timer.height) as f32 * PSEUDO_PIXELS
         }
         ComponentState::Graph(state) => state.height as f32 * PSEUDO_PIXELS,
+        ComponentState::Image(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::InputDisplay(state) => input_display::height(state),
         ComponentState::KeyValue(state) => {
             if state.display_two_rows {
                 TWO_ROW_HEIGHT
@@ -110,6 +145,7 @@ pub fn height(component: &ComponentState) -> f32 {
                 DEFAULT_COMPONENT_HEIGHT
             }
         }
+        ComponentState::SegmentNotes(state) => state.lines as f32 * DEFAULT_COMPONENT_HEIGHT,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
             state.splits.len() as f32
@@ -133,11 +169,14 @@ pub fn height(component: &ComponentState) -> f32 {
         }
         ComponentState::Timer(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::Title(_) => TWO_ROW_HEIGHT,
+        ComponentState::Variable(state) => {
+            variable::row_height(state) + state.graph.as_ref().map_or(0.0, variable::graph_height)
+        }
     }
 }
 
 pub(super) fn render<A: ResourceAllocator>(
-    cache: &mut Cache<A::Image, A::Label>,
+    cache: &mut Cache<A::Path, A::Image, A::Label>,
     context: &mut RenderContext<'_, A>,
     component: &ComponentState,
     state: &LayoutState,
@@ -152,12 +191,18 @@ pub(super) fn render<A: ResourceAllocator>(
             detailed_timer::render(cache.detailed_timer(), context, dim, component, state)
         }
         ComponentState::Graph(component) => {
-            cache.make_empty();
-            graph::render(context, dim, component, state)
+            graph::render(cache.graph(), context, dim, component, state)
+        }
+        ComponentState::Image(component) => image::render(cache.image(), context, dim, component),
+        ComponentState::InputDisplay(component) => {
+            input_display::render(cache.input_display(), context, dim, component, state)
         }
         ComponentState::KeyValue(component) => {
             key_value::render(cache.key_value(), context, dim, component, state)
         }
+        ComponentState::SegmentNotes(component) => {
+            segment_notes::render(cache.segment_notes(), context, dim, component, state)
+        }
         ComponentState::Separator(component) => {
             cache.make_empty();
             separator::render(context, dim, component, state)
@@ -174,5 +219,8 @@ pub(super) fn render<A: ResourceAllocator>(
         ComponentState::Title(component) => {
             title::render(cache.title(), context, dim, component, state)
         }
+        ComponentState::Variable(component) => {
+            variable::render(cache.variable(), context, dim, component, state)
+        }
     }
 }
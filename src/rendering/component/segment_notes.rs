@@ -0,0 +1,105 @@
+use core::marker::PhantomData;
+
+use crate::{
+    component::segment_notes::State,
+    layout::LayoutState,
+    platform::prelude::*,
+    rendering::{
+        consts::{DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_TOP},
+        font::CachedLabel,
+        resource::ResourceAllocator,
+        solid, Layer, RenderContext,
+    },
+};
+
+pub struct Cache<I, L> {
+    name: CachedLabel<L>,
+    lines: Vec<CachedLabel<L>>,
+    line_buffer: String,
+    _image: PhantomData<I>,
+}
+
+impl<I, L> Cache<I, L> {
+    pub const fn new() -> Self {
+        Self {
+            name: CachedLabel::new(),
+            lines: Vec::new(),
+            line_buffer: String::new(),
+            _image: PhantomData,
+        }
+    }
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_background([width, height], &component.background);
+
+    let row_count = ((height / DEFAULT_COMPONENT_HEIGHT) as usize).max(1);
+    let mut y = TEXT_ALIGN_TOP;
+    let mut rows_left = row_count;
+
+    if let Some(name) = &component.segment_name {
+        context.render_text_centered(
+            name,
+            &mut cache.name,
+            Layer::Bottom,
+            PADDING,
+            width - PADDING,
+            [0.5 * width, y],
+            DEFAULT_TEXT_SIZE,
+            solid(&component.name_color.unwrap_or(layout_state.text_color)),
+        );
+        y += DEFAULT_COMPONENT_HEIGHT;
+        rows_left -= 1;
+    }
+
+    let text_color = solid(&component.text_color.unwrap_or(layout_state.text_color));
+
+    let lines = component.notes.lines().take(rows_left);
+    for (index, line) in lines.enumerate() {
+        if index >= cache.lines.len() {
+            cache.lines.push(CachedLabel::new());
+        }
+
+        let line = if component.is_markdown {
+            strip_markdown(line, &mut cache.line_buffer)
+        } else {
+            line
+        };
+
+        context.render_text_ellipsis(
+            line,
+            &mut cache.lines[index],
+            [PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            text_color,
+            width - PADDING,
+        );
+        y += DEFAULT_COMPONENT_HEIGHT;
+    }
+}
+
+/// The default renderer can't render rich text, so we strip the most common
+/// Markdown syntax in order to not show it as is.
+fn strip_markdown<'a>(line: &'a str, buffer: &'a mut String) -> &'a str {
+    let trimmed = line.trim_start();
+    if let Some(heading) = trimmed.strip_prefix('#') {
+        return heading.trim_start_matches('#').trim_start();
+    }
+    if let Some(item) = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+    {
+        buffer.clear();
+        buffer.push_str(&line[..line.len() - trimmed.len()]);
+        buffer.push_str("• ");
+        buffer.push_str(item);
+        return buffer;
+    }
+    line
+}
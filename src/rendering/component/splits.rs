@@ -1,7 +1,7 @@
 use core::iter;
 
 use crate::{
-    component::splits::State,
+    component::splits::{ColumnChart, State},
     layout::{LayoutDirection, LayoutState},
     platform::prelude::*,
     rendering::{
@@ -11,36 +11,56 @@ use crate::{
         },
         font::CachedLabel,
         icon::Icon,
-        resource::ResourceAllocator,
+        resource::{Handle, ResourceAllocator, SharedOwnership},
         scene::Layer,
-        solid, RenderContext,
+        solid, PathBuilder, RenderContext,
     },
-    settings::{Gradient, ListGradient},
+    settings::{Color, Gradient, ListGradient},
 };
 
-pub struct Cache<I, L> {
+pub struct Cache<P, I, L> {
     icons: Vec<Option<Icon<I>>>,
-    splits: Vec<SplitCache<L>>,
+    splits: Vec<SplitCache<P, L>>,
     column_labels: Vec<CachedLabel<L>>,
     column_width_label: CachedLabel<L>,
     column_label_widths: Vec<f32>,
 }
 
-struct SplitCache<L> {
+struct SplitCache<P, L> {
     name: CachedLabel<L>,
     columns: Vec<CachedLabel<L>>,
+    charts: Vec<CachedChart<P>>,
 }
 
-impl<L> SplitCache<L> {
+/// The path of a chart only gets built again when the chart changes. Otherwise
+/// a chart on the bottom layer would cause it to be rerendered every frame.
+struct CachedChart<P> {
+    values: Vec<f32>,
+    bounds: [f32; 4],
+    path: Option<Handle<P>>,
+}
+
+impl<P, L> SplitCache<P, L> {
     const fn new() -> Self {
         Self {
             name: CachedLabel::new(),
             columns: Vec::new(),
+            charts: Vec::new(),
+        }
+    }
+}
+
+impl<P> CachedChart<P> {
+    const fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            bounds: [0.0; 4],
+            path: None,
         }
     }
 }
 
-impl<I, L> Cache<I, L> {
+impl<P, I, L> Cache<P, I, L> {
     pub const fn new() -> Self {
         Self {
             icons: Vec::new(),
@@ -53,7 +73,7 @@ impl<I, L> Cache<I, L> {
 }
 
 pub(in crate::rendering) fn render<A: ResourceAllocator>(
-    cache: &mut Cache<A::Image, A::Label>,
+    cache: &mut Cache<A::Path, A::Image, A::Label>,
     context: &mut RenderContext<'_, A>,
     [width, height]: [f32; 2],
     component: &State,
@@ -179,8 +199,18 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         }
 
         {
-            if let Some(Some(icon)) = cache.icons.get(split.index) {
-                context.render_icon([PADDING, icon_y], [icon_size, icon_size], icon);
+            let indent = if component.indent_subsplits && split.is_subsplit {
+                BOTH_PADDINGS
+            } else {
+                0.0
+            };
+
+            // Section headers and summaries don't show a single segment, so
+            // there is no icon to show.
+            if !split.is_section_header && !split.is_summary {
+                if let Some(Some(icon)) = cache.icons.get(split.index) {
+                    context.render_icon([PADDING + indent, icon_y], [icon_size, icon_size], icon);
+                }
             }
 
             let mut left_x = split_width - PADDING;
@@ -189,9 +219,16 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             split_cache
                 .columns
                 .resize_with(split.columns.len(), CachedLabel::new);
+            split_cache
+                .charts
+                .resize_with(split.columns.len(), CachedChart::new);
 
-            for ((column, column_cache), column_label_width) in
-                split.columns.iter().zip(&mut split_cache.columns).zip(
+            for (((column, column_cache), chart_cache), column_label_width) in split
+                .columns
+                .iter()
+                .zip(&mut split_cache.columns)
+                .zip(&mut split_cache.charts)
+                .zip(
                     cache
                         .column_label_widths
                         .iter()
@@ -199,7 +236,22 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                         .chain(iter::repeat(max_column_width)),
                 )
             {
-                if !column.value.is_empty() {
+                let column_width = max_column_width.max(column_label_width);
+                if let Some(chart) = &column.chart {
+                    left_x = right_x - column_width;
+                    render_column_chart(
+                        context,
+                        chart_cache,
+                        Layer::from_updates_frequently(column.updates_frequently),
+                        chart,
+                        [left_x, right_x],
+                        [
+                            split_height - DEFAULT_COMPONENT_HEIGHT + vertical_padding,
+                            split_height - vertical_padding,
+                        ],
+                        column.visual_color,
+                    );
+                } else if !column.value.is_empty() {
                     left_x = context.render_numbers(
                         &column.value,
                         column_cache,
@@ -209,7 +261,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                         solid(&column.visual_color),
                     );
                 }
-                right_x -= max_column_width.max(column_label_width) + COLUMN_PADDING;
+                right_x -= column_width + COLUMN_PADDING;
             }
 
             if display_two_rows {
@@ -219,7 +271,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             context.render_text_ellipsis(
                 &split.name,
                 &mut split_cache.name,
-                [icon_right, TEXT_ALIGN_TOP],
+                [icon_right + indent, TEXT_ALIGN_TOP],
                 DEFAULT_TEXT_SIZE,
                 text_color,
                 left_x - PADDING,
@@ -243,3 +295,77 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
     }
     context.transform = transform;
 }
+
+fn render_column_chart<A: ResourceAllocator>(
+    context: &mut RenderContext<'_, A>,
+    cache: &mut CachedChart<A::Path>,
+    layer: Layer,
+    chart: &ColumnChart,
+    [left, right]: [f32; 2],
+    [top, bottom]: [f32; 2],
+    color: Color,
+) {
+    const LINE_WIDTH: f32 = 0.04;
+    const BAR_GAP: f32 = 0.03;
+
+    let height = bottom - top;
+    match chart {
+        ColumnChart::Sparkline(values) => {
+            let bounds = [left, right, top, bottom];
+            if cache.path.is_none() || cache.values != *values || cache.bounds != bounds {
+                let (top, height) = (top + LINE_WIDTH, height - 2.0 * LINE_WIDTH);
+                cache.path = if let [value] = values[..] {
+                    Some(context.handles.build_circle(
+                        right,
+                        top + (1.0 - value) * height,
+                        LINE_WIDTH,
+                    ))
+                } else if let [first, rest @ ..] = &values[..] {
+                    let step = (right - left) / rest.len() as f32;
+                    let mut builder = context.handles.path_builder();
+                    builder.move_to(left, top + (1.0 - first) * height);
+                    for (i, value) in rest.iter().enumerate() {
+                        builder.line_to(left + (i + 1) as f32 * step, top + (1.0 - value) * height);
+                    }
+                    Some(builder.finish())
+                } else {
+                    None
+                };
+                cache.values.clone_from(values);
+                cache.bounds = bounds;
+            }
+
+            if let Some(path) = &cache.path {
+                if values.len() == 1 {
+                    context.layer_path(layer, path.share(), color);
+                } else {
+                    context.layer_stroke_path(layer, path.share(), color, LINE_WIDTH);
+                }
+            }
+        }
+        ColumnChart::Histogram(values) => {
+            let bar_width = (right - left) / values.len() as f32;
+            for (i, &value) in values.iter().enumerate() {
+                if value > 0.0 {
+                    let x = left + i as f32 * bar_width;
+                    let (top_left, bottom_right) = (
+                        [x, bottom - value * height],
+                        [x + bar_width - BAR_GAP, bottom],
+                    );
+                    match layer {
+                        Layer::Bottom => context.render_rectangle(
+                            top_left,
+                            bottom_right,
+                            &Gradient::Plain(color),
+                        ),
+                        Layer::Top => context.render_top_rectangle(
+                            top_left,
+                            bottom_right,
+                            &Gradient::Plain(color),
+                        ),
+                    }
+                }
+            }
+        }
+    }
+}
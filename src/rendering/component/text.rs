@@ -7,7 +7,7 @@ use crate::{
         consts::{DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_TOP},
         font::{AbbreviatedLabel, CachedLabel},
         resource::ResourceAllocator,
-        solid, RenderContext,
+        solid, Layer, RenderContext,
     },
 };
 
@@ -39,6 +39,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         TextState::Center(text) => context.render_text_centered(
             text,
             &mut cache.label2,
+            Layer::Bottom,
             PADDING,
             width - PADDING,
             [0.5 * width, TEXT_ALIGN_TOP],
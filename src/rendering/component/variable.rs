@@ -0,0 +1,114 @@
+use core::marker::PhantomData;
+
+use crate::{
+    component::variable::{GraphState, State},
+    layout::{LayoutDirection, LayoutState},
+    rendering::{
+        consts::{DEFAULT_COMPONENT_HEIGHT, PSEUDO_PIXELS, TWO_ROW_HEIGHT},
+        font::{AbbreviatedLabel, CachedLabel},
+        resource::ResourceAllocator,
+        PathBuilder, RenderContext,
+    },
+};
+
+pub struct Cache<I, L> {
+    label: AbbreviatedLabel<L>,
+    value: CachedLabel<L>,
+    _image: PhantomData<I>,
+}
+
+impl<I, L> Cache<I, L> {
+    pub const fn new() -> Self {
+        Self {
+            label: AbbreviatedLabel::new(),
+            value: CachedLabel::new(),
+            _image: PhantomData,
+        }
+    }
+}
+
+pub(super) const fn row_height(component: &State) -> f32 {
+    if component.display_two_rows {
+        TWO_ROW_HEIGHT
+    } else {
+        DEFAULT_COMPONENT_HEIGHT
+    }
+}
+
+pub(super) fn graph_height(graph: &GraphState) -> f32 {
+    graph.height as f32 * PSEUDO_PIXELS
+}
+
+pub(in crate::rendering) fn render<A: ResourceAllocator>(
+    cache: &mut Cache<A::Image, A::Label>,
+    context: &mut RenderContext<'_, A>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_background([width, height], &component.background);
+
+    let row_height = match &component.graph {
+        Some(graph) => {
+            let row_height = row_height(component);
+            height * row_height / (row_height + graph_height(graph))
+        }
+        None => height,
+    };
+
+    context.render_key_value_component(
+        &component.label,
+        &[],
+        &mut cache.label,
+        &component.value,
+        &mut cache.value,
+        false,
+        [width, row_height],
+        component.label_color.unwrap_or(layout_state.text_color),
+        component.value_color.unwrap_or(layout_state.text_color),
+        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal,
+    );
+
+    if let Some(graph) = &component.graph {
+        let graph_height = height - row_height;
+        if graph_height > 0.0 {
+            let old_transform = context.transform;
+            context.translate(0.0, row_height);
+            render_graph(context, [width, graph_height], graph);
+            context.transform = old_transform;
+        }
+    }
+}
+
+fn render_graph(
+    context: &mut RenderContext<'_, impl ResourceAllocator>,
+    [width, height]: [f32; 2],
+    graph: &GraphState,
+) {
+    const LINE_WIDTH: f32 = 0.025;
+    const CIRCLE_RADIUS: f32 = 0.035;
+
+    context.scale(height);
+    let width = width / height;
+
+    for points in graph.points.windows(2) {
+        let mut builder = context.handles.path_builder();
+        builder.move_to(width * points[0].x, points[0].y);
+        builder.line_to(width * points[1].x, points[1].y);
+        let line_path = builder.finish();
+        context.top_layer_stroke_path(line_path, graph.color, LINE_WIDTH);
+    }
+
+    let len = if graph.is_live_value_active {
+        graph.points.len().saturating_sub(1)
+    } else {
+        graph.points.len()
+    };
+
+    for point in &graph.points[..len] {
+        let circle_path = context
+            .handles
+            .build_circle(width * point.x, point.y, CIRCLE_RADIUS);
+        context.top_layer_path(circle_path, graph.color);
+    }
+}
@@ -160,7 +160,7 @@ enum CachedSize {
 /// curves, fonts and labels.
 pub struct SceneManager<P, I, F, L> {
     scene: Scene<P, I, L>,
-    components: Vec<component::Cache<I, L>>,
+    components: Vec<component::Cache<P, I, L>>,
     next_id: usize,
     cached_size: Option<CachedSize>,
     fonts: FontCache<F>,
@@ -429,13 +429,27 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
     }
 
     fn top_layer_path(&mut self, path: Handle<A::Path>, color: Color) {
+        self.layer_path(Layer::Top, path, color);
+    }
+
+    fn top_layer_stroke_path(&mut self, path: Handle<A::Path>, color: Color, stroke_width: f32) {
+        self.layer_stroke_path(Layer::Top, path, color, stroke_width);
+    }
+
+    fn layer_path(&mut self, layer: Layer, path: Handle<A::Path>, color: Color) {
         self.scene
-            .top_layer_mut()
+            .layer_mut(layer)
             .push(Entity::FillPath(path, solid(&color), self.transform));
     }
 
-    fn top_layer_stroke_path(&mut self, path: Handle<A::Path>, color: Color, stroke_width: f32) {
-        self.scene.top_layer_mut().push(Entity::StrokePath(
+    fn layer_stroke_path(
+        &mut self,
+        layer: Layer,
+        path: Handle<A::Path>,
+        color: Color,
+        stroke_width: f32,
+    ) {
+        self.scene.layer_mut(layer).push(Entity::StrokePath(
             path,
             stroke_width,
             color.to_array(),
@@ -496,6 +510,10 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             height = new_height;
         }
 
+        self.render_image([x, y], [width, height], icon);
+    }
+
+    fn render_image(&mut self, [x, y]: Pos, [width, height]: Pos, icon: &Icon<A::Image>) {
         let transform = self.transform.pre_translate(x, y).pre_scale(width, height);
 
         self.scene
@@ -594,6 +612,7 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         &mut self,
         text: &str,
         label: &mut CachedLabel<A::Label>,
+        layer: Layer,
         min_x: f32,
         max_x: f32,
         pos: Pos,
@@ -607,7 +626,7 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
             Some((max_x - min_x) / scale),
         );
 
-        self.scene.bottom_layer_mut().push(Entity::Label(
+        self.scene.layer_mut(layer).push(Entity::Label(
             label.share(),
             shader,
             font::centered(
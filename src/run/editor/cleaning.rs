@@ -4,12 +4,17 @@
 //! split, the combined segment time might be faster than the sum of the
 //! individual best segments. The Sum of Best Cleaner will point out all
 //! occurrences of this and allows you to delete them individually if any of
-//! them seem wrong.
+//! them seem wrong. Additionally the Sum of Best Cleaner can point out best
+//! segments that are likely to be the result of a mistake, such as splitting
+//! too early.
 
 use time::UtcOffset;
 
 use crate::{
-    analysis::sum_of_segments::{best, track_branch, Prediction},
+    analysis::{
+        sum_of_segments::{best, track_branch, Prediction},
+        suspicious_golds::{self, SuspiciousGold},
+    },
     platform::{prelude::*, to_local},
     timing::formatter::{SegmentTime, TimeFormatter},
     Attempt, Run, Segment, TimeSpan, TimingMethod,
@@ -26,6 +31,8 @@ pub struct SumOfBestCleaner<'r> {
     run: &'r mut Run,
     predictions: Vec<Option<Prediction>>,
     state: State,
+    suspicious_gold_threshold: Option<f64>,
+    presented_golds: Vec<(usize, i32, TimingMethod)>,
 }
 
 enum State {
@@ -34,6 +41,7 @@ enum State {
     WithTimingMethod(TimingMethod),
     IteratingRun(IteratingRunState),
     IteratingHistory(IteratingHistoryState),
+    SuspiciousGolds,
 }
 
 struct IteratingRunState {
@@ -55,16 +63,26 @@ pub struct PotentialCleanUp<'r> {
     starting_segment: Option<&'r Segment>,
     ending_segment: &'r Segment,
     time_between: TimeSpan,
-    combined_sum_of_best: Option<TimeSpan>,
+    reason: Reason,
     attempt: &'r Attempt,
     method: TimingMethod,
     clean_up: CleanUp,
 }
 
+enum Reason {
+    CombinedSegment {
+        combined_sum_of_best: Option<TimeSpan>,
+    },
+    SuspiciousGold {
+        typical_time: TimeSpan,
+    },
+}
+
 /// Describes an actual clean up that is about to be applied.
 pub struct CleanUp {
     ending_index: usize,
     run_index: i32,
+    replaces_best_segment: bool,
 }
 
 impl fmt::Display for PotentialCleanUp<'_> {
@@ -90,12 +108,22 @@ impl fmt::Display for PotentialCleanUp<'_> {
 
         write!(f, " and {}", self.ending_segment.name())?;
 
-        if let Some(combined) = self.combined_sum_of_best {
-            write!(
+        match self.reason {
+            Reason::CombinedSegment {
+                combined_sum_of_best: Some(combined),
+            } => write!(
                 f,
                 ", which is faster than the combined best segments of {}",
                 short.format(combined)
-            )?;
+            )?,
+            Reason::CombinedSegment {
+                combined_sum_of_best: None,
+            } => {}
+            Reason::SuspiciousGold { typical_time } => write!(
+                f,
+                ", which is your best segment, but a lot faster than your typical segment time of {}",
+                short.format(typical_time)
+            )?,
         }
 
         if let Some(started) = self.attempt.started() {
@@ -134,6 +162,16 @@ impl From<PotentialCleanUp<'_>> for CleanUp {
     }
 }
 
+impl From<&SuspiciousGold> for CleanUp {
+    fn from(suspicious_gold: &SuspiciousGold) -> Self {
+        Self {
+            ending_index: suspicious_gold.segment_index,
+            run_index: suspicious_gold.attempt_index,
+            replaces_best_segment: true,
+        }
+    }
+}
+
 impl<'r> SumOfBestCleaner<'r> {
     /// Creates a new Sum of Best Cleaner for the provided Run object.
     pub fn new(run: &'r mut Run) -> Self {
@@ -142,17 +180,46 @@ impl<'r> SumOfBestCleaner<'r> {
             run,
             predictions,
             state: State::WithTimingMethod(TimingMethod::RealTime),
+            suspicious_gold_threshold: None,
+            presented_golds: Vec::new(),
         }
     }
 
-    /// Applies a clean up to the Run.
+    /// Creates a new Sum of Best Cleaner for the provided Run object that, in
+    /// addition to the usual potential clean ups, points out the best segments
+    /// that are likely to be the result of a mistake. Only best segments with a
+    /// score of at least the threshold provided are pointed out. Use
+    /// [`suspicious_golds::DEFAULT_THRESHOLD`] if you don't have any specific
+    /// requirements.
+    pub fn with_suspicious_golds(run: &'r mut Run, threshold: f64) -> Self {
+        Self {
+            suspicious_gold_threshold: Some(threshold),
+            ..Self::new(run)
+        }
+    }
+
+    /// Applies a clean up to the Run. If the clean up removes a suspicious best
+    /// segment, the best segment gets replaced by the next best segment time in
+    /// the segment history.
     #[allow(clippy::needless_pass_by_value)]
     pub fn apply(&mut self, clean_up: CleanUp) {
-        self.run
+        let removed = self
+            .run
             .segment_mut(clean_up.ending_index)
             .segment_history_mut()
             .remove(clean_up.run_index);
 
+        if let Some(removed) = removed.filter(|_| clean_up.replaces_best_segment) {
+            let segments = self.run.segments_mut();
+            for method in TimingMethod::all() {
+                let best_segment = segments[clean_up.ending_index].best_segment_time()[method];
+                if removed[method].is_some() && removed[method] == best_segment {
+                    let next_best = next_best_segment(segments, clean_up.ending_index, method);
+                    segments[clean_up.ending_index].best_segment_time_mut()[method] = next_best;
+                }
+            }
+        }
+
         self.run.mark_as_modified();
     }
 
@@ -180,6 +247,8 @@ impl<'r> SumOfBestCleaner<'r> {
                         })
                     } else if state.method == TimingMethod::RealTime {
                         State::WithTimingMethod(TimingMethod::GameTime)
+                    } else if self.suspicious_gold_threshold.is_some() {
+                        State::SuspiciousGolds
                     } else {
                         State::Done
                     };
@@ -226,11 +295,90 @@ impl<'r> SumOfBestCleaner<'r> {
                         segment_index: state.parent.segment_index + 1,
                     });
                 }
+                State::SuspiciousGolds => {
+                    let threshold = self.suspicious_gold_threshold.unwrap_or_default();
+                    let segments = self.run.segments();
+                    let presented_golds = &self.presented_golds;
+                    let Some(suspicious_gold) =
+                        TimingMethod::all().into_iter().find_map(|method| {
+                            suspicious_golds::calculate(segments, method, threshold)
+                                .into_iter()
+                                .find(|gold| {
+                                    !presented_golds.contains(&(
+                                        gold.segment_index,
+                                        gold.attempt_index,
+                                        gold.method,
+                                    ))
+                                })
+                        })
+                    else {
+                        self.state = State::Done;
+                        continue;
+                    };
+
+                    self.presented_golds.push((
+                        suspicious_gold.segment_index,
+                        suspicious_gold.attempt_index,
+                        suspicious_gold.method,
+                    ));
+                    self.state = State::SuspiciousGolds;
+
+                    let Some(attempt) = self
+                        .run
+                        .attempt_history()
+                        .iter()
+                        .find(|attempt| attempt.index() == suspicious_gold.attempt_index)
+                    else {
+                        continue;
+                    };
+
+                    return Some(PotentialCleanUp {
+                        starting_segment: suspicious_gold
+                            .segment_index
+                            .checked_sub(1)
+                            .map(|i| self.run.segment(i)),
+                        ending_segment: self.run.segment(suspicious_gold.segment_index),
+                        time_between: suspicious_gold.time,
+                        reason: Reason::SuspiciousGold {
+                            typical_time: suspicious_gold.typical_time,
+                        },
+                        attempt,
+                        method: suspicious_gold.method,
+                        clean_up: CleanUp::from(&suspicious_gold),
+                    });
+                }
             };
         }
     }
 }
 
+/// Determines the fastest segment time in the segment history of the segment,
+/// skipping the combined segment times.
+fn next_best_segment(
+    segments: &[Segment],
+    segment_index: usize,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let previous_segment = segment_index.checked_sub(1).map(|i| &segments[i]);
+    segments[segment_index]
+        .segment_history()
+        .iter()
+        .filter_map(|&(id, time)| {
+            let time = time[method]?;
+            // Skip all the combined segments
+            let skip = catch! {
+                previous_segment?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+            if skip {
+                None
+            } else {
+                Some(time)
+            }
+        })
+        .min()
+}
+
 fn check_prediction<'a>(
     run: &'a Run,
     predictions: &[Option<Prediction>],
@@ -254,12 +402,14 @@ fn check_prediction<'a>(
                     ending_segment: run.segment(ending_index),
                     time_between: segment_history_element[method]
                         .expect("Cleanup path is shorter but doesn't have a time"),
-                    combined_sum_of_best: predictions[ending_index + 1].map(|p| {
-                        p.time
-                            - predictions[(starting_index + 1) as usize]
-                                .expect("Start time must not be empty")
-                                .time
-                    }),
+                    reason: Reason::CombinedSegment {
+                        combined_sum_of_best: predictions[ending_index + 1].map(|p| {
+                            p.time
+                                - predictions[(starting_index + 1) as usize]
+                                    .expect("Start time must not be empty")
+                                    .time
+                        }),
+                    },
                     attempt: run
                         .attempt_history()
                         .iter()
@@ -269,6 +419,7 @@ fn check_prediction<'a>(
                     clean_up: CleanUp {
                         ending_index,
                         run_index,
+                        replaces_best_segment: false,
                     },
                 });
             }
@@ -6,9 +6,9 @@
 
 use super::{ComparisonError, ComparisonResult, LinkedLayout};
 use crate::{
-    comparison,
+    comparison::{self, ComparisonGenerator},
     platform::prelude::*,
-    settings::{CachedImageId, Image},
+    settings::{CachedImageId, Image, SettingsDescription, Value},
     timing::ParseError as ParseTimeSpanError,
     util::PopulateString,
     Run, Segment, Time, TimeSpan, TimingMethod,
@@ -27,7 +27,10 @@ pub use self::{
     cleaning::SumOfBestCleaner,
     fuzzy_list::FuzzyList,
     segment_row::SegmentRow,
-    state::{Buttons as ButtonsState, Segment as SegmentState, SelectionState, State},
+    state::{
+        Buttons as ButtonsState, ComparisonGenerator as ComparisonGeneratorState,
+        Segment as SegmentState, SelectionState, State,
+    },
 };
 
 /// Describes an Error that occurred while parsing a time.
@@ -863,6 +866,138 @@ impl Editor {
         Ok(())
     }
 
+    /// Adds a new Comparison Generator. It can't be added if the name of its
+    /// comparison starts with `[Race]` or already exists. You can use a
+    /// [`Registry`](comparison::Registry) to create Comparison Generators by
+    /// their kind.
+    pub fn add_comparison_generator(
+        &mut self,
+        generator: Box<dyn ComparisonGenerator>,
+    ) -> ComparisonResult<()> {
+        self.run.add_comparison_generator(generator)?;
+        self.run.regenerate_comparisons();
+        self.raise_run_edited();
+        Ok(())
+    }
+
+    /// Adds a new [`LinkedComparison`](comparison::LinkedComparison) that
+    /// compares against the Personal Best of the run provided. The path of the
+    /// file the run comes from is stored, so that the comparison can be
+    /// refreshed whenever the file changes. It can't be added if the name of
+    /// the comparison starts with `[Race]` or already exists.
+    pub fn add_linked_comparison(
+        &mut self,
+        comparison: &str,
+        path: &str,
+        source: &Run,
+    ) -> ComparisonResult<()> {
+        let mut linked = comparison::LinkedComparison::new(comparison.into(), path.into());
+        linked.set_source(source);
+        self.add_comparison_generator(Box::new(linked))
+    }
+
+    /// Refreshes the [`LinkedComparison`](comparison::LinkedComparison) at the
+    /// index specified with the Personal Best of the run provided. Returns
+    /// `false` if the Comparison Generator at that index is not a Linked
+    /// Comparison. The index is based on the `comparison_generators` field of
+    /// the Run Editor's `State`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the index is out of bounds.
+    pub fn refresh_linked_comparison(&mut self, index: usize, source: &Run) -> bool {
+        let generator = &mut self.run.comparison_generators_mut()[index];
+        let Some(mut linked) = comparison::linked::from_settings_of(&**generator) else {
+            return false;
+        };
+        linked.set_source(source);
+        *generator = Box::new(linked);
+        self.run.regenerate_comparisons();
+        self.raise_run_edited();
+        true
+    }
+
+    /// Removes the Comparison Generator at the index specified, along with the
+    /// comparison times it generated. The index is based on the
+    /// `comparison_generators` field of the Run Editor's `State`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the index is out of bounds.
+    pub fn remove_comparison_generator(&mut self, index: usize) {
+        let generator = self.run.comparison_generators_mut().remove(index);
+        self.remove_generated_comparison(generator.name());
+        self.raise_run_edited();
+    }
+
+    /// Accesses a generic description of the settings available for the
+    /// Comparison Generator at the index specified and their current values.
+    /// The index is based on the `comparison_generators` field of the Run
+    /// Editor's `State`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the index is out of bounds.
+    pub fn comparison_generator_settings_description(&self, index: usize) -> SettingsDescription {
+        self.run.comparison_generators()[index].settings_description()
+    }
+
+    /// Sets a setting's value of the Comparison Generator at the index
+    /// specified by its setting index to the given value. The comparison gets
+    /// regenerated afterwards. If the setting renames the comparison, the
+    /// value can't be set if the new name of the comparison starts with
+    /// `[Race]` or it already exists. The index is based on the
+    /// `comparison_generators` field of the Run Editor's `State`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if either of the
+    /// indices is out of bounds.
+    pub fn set_comparison_generator_value(
+        &mut self,
+        index: usize,
+        setting_index: usize,
+        value: Value,
+    ) -> ComparisonResult<()> {
+        let generators = self.run.comparison_generators_mut();
+        let old_name = generators[index].name().to_owned();
+        let old_value = generators[index]
+            .settings_description()
+            .fields
+            .swap_remove(setting_index)
+            .value;
+        generators[index].set_value(setting_index, value);
+
+        if generators[index].name() != old_name {
+            // The generator itself needs to be excluded while validating its
+            // new name, as it would otherwise always be a duplicate.
+            let generator = generators.remove(index);
+            let result = self.run.validate_comparison_name(generator.name());
+            let generators = self.run.comparison_generators_mut();
+            generators.insert(index, generator);
+            if let Err(error) = result {
+                generators[index].set_value(setting_index, old_value);
+                return Err(error);
+            }
+            self.remove_generated_comparison(&old_name);
+        }
+
+        self.run.regenerate_comparisons();
+        self.raise_run_edited();
+        Ok(())
+    }
+
+    fn remove_generated_comparison(&mut self, comparison: &str) {
+        if self.run.comparisons().any(|c| c == comparison) {
+            return;
+        }
+
+        for segment in self.run.segments_mut() {
+            segment.comparisons_mut().remove(comparison);
+        }
+    }
+
     /// Generates a custom goal comparison based on the goal time provided. The
     /// comparison's times are automatically balanced based on the runner's
     /// history such that it roughly represents what split times for the goal
@@ -937,6 +1072,18 @@ impl Editor {
     pub fn clean_sum_of_best(&mut self) -> SumOfBestCleaner<'_> {
         SumOfBestCleaner::new(&mut self.run)
     }
+
+    /// Creates a Sum of Best Cleaner that, in addition to the combined segment
+    /// times, also points out best segments that are likely to be the result
+    /// of a mistake, such as splitting too early. Only best segments with a
+    /// suspiciousness score of at least the threshold provided are pointed
+    /// out.
+    pub fn clean_sum_of_best_with_suspicious_golds(
+        &mut self,
+        threshold: f64,
+    ) -> SumOfBestCleaner<'_> {
+        SumOfBestCleaner::with_suspicious_golds(&mut self.run, threshold)
+    }
 }
 
 fn parse_positive(time: &str) -> Result<Option<TimeSpan>, ParseError> {
@@ -33,6 +33,8 @@ pub struct State {
     pub segments: Vec<Segment>,
     /// The names of all the custom comparisons that exist for this Run.
     pub comparison_names: Vec<String>,
+    /// The state of all the Comparison Generators that are in use by this Run.
+    pub comparison_generators: Vec<ComparisonGenerator>,
     /// Describes which actions are currently available.
     pub buttons: Buttons,
     /// Additional metadata of this Run, like the platform and region of the
@@ -67,6 +69,8 @@ pub struct Segment {
     pub icon_change: Option<ImageData>,
     /// The name of the segment.
     pub name: String,
+    /// The notes of the segment. These may be formatted as Markdown.
+    pub notes: String,
     /// The segment's split time for the active timing method.
     pub split_time: String,
     /// The segment time for the active timing method.
@@ -81,6 +85,17 @@ pub struct Segment {
     pub selected: SelectionState,
 }
 
+/// Describes the current state of a Comparison Generator.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComparisonGenerator {
+    /// The name of the comparison the Comparison Generator generates.
+    pub name: String,
+    /// The kind of the Comparison Generator.
+    pub kind: String,
+    /// Specifies whether the Comparison Generator has any settings.
+    pub has_settings: bool,
+}
+
 /// Describes a segment's selection state.
 #[derive(Debug, Serialize, Deserialize)]
 pub enum SelectionState {
@@ -132,6 +147,17 @@ impl Editor {
             .cloned()
             .collect::<Vec<_>>();
 
+        let comparison_generators = self
+            .run
+            .comparison_generators()
+            .iter()
+            .map(|generator| ComparisonGenerator {
+                name: generator.name().to_owned(),
+                kind: generator.kind().to_owned(),
+                has_settings: !generator.settings_description().fields.is_empty(),
+            })
+            .collect();
+
         let buttons = Buttons {
             can_remove: self.can_remove_segments(),
             can_move_up: self.can_move_segments_up(),
@@ -143,10 +169,11 @@ impl Editor {
             .resize(self.run.len(), CachedImageId::default());
 
         for segment_index in 0..self.run.len() {
-            let (name, split_time, segment_time, best_segment_time, comparison_times);
+            let (name, notes, split_time, segment_time, best_segment_time, comparison_times);
             {
                 let row = SegmentRow::new(segment_index, self);
                 name = row.name().to_string();
+                notes = row.notes().to_string();
                 split_time = formatter.format(row.split_time()).to_string();
                 segment_time = formatter.format(row.segment_time()).to_string();
                 best_segment_time = formatter.format(row.best_segment_time()).to_string();
@@ -171,6 +198,7 @@ impl Editor {
             segments.push(Segment {
                 icon_change,
                 name,
+                notes,
                 split_time,
                 segment_time,
                 best_segment_time,
@@ -188,6 +216,7 @@ impl Editor {
             timing_method,
             segments,
             comparison_names,
+            comparison_generators,
             buttons,
             metadata: self.run.metadata().clone(),
         }
@@ -21,8 +21,10 @@ mod linked_layout;
 pub mod parser;
 mod run_metadata;
 pub mod saver;
+mod section;
 mod segment;
 mod segment_history;
+mod session;
 
 #[cfg(test)]
 mod tests;
@@ -32,11 +34,16 @@ pub use comparisons::Comparisons;
 pub use editor::{Editor, RenameError};
 pub use linked_layout::LinkedLayout;
 pub use run_metadata::{CustomVariable, RunMetadata};
+pub use section::Section;
 pub use segment::Segment;
 pub use segment_history::SegmentHistory;
+pub use session::Session;
 
 use crate::{
-    comparison::{default_generators, personal_best, ComparisonGenerator, RACE_COMPARISON_PREFIX},
+    comparison::{
+        default_generators, linked, personal_best, ComparisonGenerator, Registry,
+        RACE_COMPARISON_PREFIX,
+    },
     platform::prelude::*,
     settings::Image,
     util::PopulateString,
@@ -69,6 +76,7 @@ pub struct Run {
     offset: TimeSpan,
     attempt_count: u32,
     attempt_history: Vec<Attempt>,
+    sessions: Vec<Session>,
     metadata: RunMetadata,
     has_been_modified: bool,
     segments: Vec<Segment>,
@@ -83,10 +91,16 @@ struct ComparisonGenerators(Vec<Box<dyn ComparisonGenerator>>);
 
 impl PartialEq for ComparisonGenerators {
     fn eq(&self, other: &ComparisonGenerators) -> bool {
-        self.0
-            .iter()
-            .map(|c| c.name())
-            .eq(other.0.iter().map(|c| c.name()))
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(&other.0).all(|(a, b)| {
+                a.name() == b.name()
+                    && a.kind() == b.kind()
+                    && a.settings_description()
+                        .fields
+                        .iter()
+                        .map(|f| &f.value)
+                        .eq(b.settings_description().fields.iter().map(|f| &f.value))
+            })
     }
 }
 
@@ -113,6 +127,7 @@ impl Run {
             offset: TimeSpan::zero(),
             attempt_count: 0,
             attempt_history: Vec::new(),
+            sessions: Vec::new(),
             metadata: RunMetadata::new(),
             has_been_modified: false,
             segments: Vec::new(),
@@ -216,6 +231,13 @@ impl Run {
         &self.segments
     }
 
+    /// Iterates over the sections that the segments are grouped into. Segments
+    /// are grouped into sections by following the naming convention of
+    /// LiveSplit's Subsplits. See [`Section`] for more information.
+    pub fn sections(&self) -> impl Iterator<Item = Section> + '_ {
+        section::sections(&self.segments)
+    }
+
     /// Grants mutable access to the Segments of this Run object.
     #[inline]
     pub fn segments_mut(&mut self) -> &mut Vec<Segment> {
@@ -300,6 +322,52 @@ impl Run {
         &mut self.comparison_generators.0
     }
 
+    /// Adds a new Comparison Generator. It can't be added if the name of its
+    /// comparison starts with `[Race]` or already exists.
+    pub fn add_comparison_generator(
+        &mut self,
+        generator: Box<dyn ComparisonGenerator>,
+    ) -> ComparisonResult<()> {
+        self.validate_comparison_name(generator.name())?;
+        self.comparison_generators.0.push(generator);
+        Ok(())
+    }
+
+    /// Recreates all the Comparison Generators whose kind is registered in the
+    /// registry provided, while keeping their settings. This resolves the
+    /// Comparison Generators that were unknown while the Run got parsed, so
+    /// make sure to call this after parsing a Run if you provide your own
+    /// Comparison Generators. The comparisons get regenerated afterwards.
+    pub fn resolve_comparison_generators(&mut self, registry: &Registry) {
+        for generator in &mut self.comparison_generators.0 {
+            if let Some(resolved) = registry.recreate(&**generator) {
+                *generator = resolved;
+            }
+        }
+        self.regenerate_comparisons();
+    }
+
+    /// Refreshes the splits of all the
+    /// [`LinkedComparison`](crate::comparison::LinkedComparison) Comparison
+    /// Generators. The function provided gets called with the path of the file
+    /// the splits of each of them come from and is expected to load the run
+    /// stored in that file. If it returns `None`, the splits stay as they are.
+    /// The comparisons get regenerated afterwards.
+    pub fn refresh_linked_comparisons<F>(&mut self, mut load: F)
+    where
+        F: FnMut(&str) -> Option<Run>,
+    {
+        for generator in &mut self.comparison_generators.0 {
+            if let Some(mut linked) = linked::from_settings_of(&**generator) {
+                if let Some(source) = load(linked.path()) {
+                    linked.set_source(&source);
+                    *generator = Box::new(linked);
+                }
+            }
+        }
+        self.regenerate_comparisons();
+    }
+
     /// Accesses the Auto Splitter Settings that are encoded as XML.
     #[inline]
     pub fn auto_splitter_settings(&self) -> &str {
@@ -432,6 +500,43 @@ impl Run {
         self.attempt_history.push(attempt);
     }
 
+    /// Accesses the sessions the runner explicitly declared. They are ordered
+    /// from the oldest to the most recent. Only the most recent session may
+    /// still be active.
+    #[inline]
+    pub fn sessions(&self) -> &[Session] {
+        &self.sessions
+    }
+
+    /// Starts a new session at the point in time provided. If there is a
+    /// session that is still active, it gets ended at that point in time.
+    /// Sessions are never started automatically. If the Run is used with a
+    /// Timer, [`Timer::start_session`](crate::Timer::start_session) starts a
+    /// session at the current point in time.
+    pub fn start_session(&mut self, started: AtomicDateTime) {
+        self.end_session(started);
+        self.sessions.push(Session::new(started, None));
+    }
+
+    /// Ends the session that is still active at the point in time provided. If
+    /// there is no active session, nothing happens. Sessions are never ended
+    /// automatically. If the Run is used with a Timer,
+    /// [`Timer::end_session`](crate::Timer::end_session) ends the session at
+    /// the current point in time.
+    pub fn end_session(&mut self, ended: AtomicDateTime) {
+        if let Some(session) = self.sessions.last_mut().filter(|s| s.is_active()) {
+            *session = Session::new(session.started(), Some(ended));
+        }
+    }
+
+    /// Adds a session as the most recent session. This is meant for restoring
+    /// sessions, such as when parsing a splits file. Use
+    /// [`start_session`](Self::start_session) for declaring a new session.
+    #[inline]
+    pub fn push_session(&mut self, session: Session) {
+        self.sessions.push(session);
+    }
+
     /// Clears the speedrun.com Run ID of this Run, as the current Run does not
     /// reflect the run on speedrun.com anymore. This may be the case if a new
     /// Personal Best is achieved for example.
@@ -452,6 +557,12 @@ impl Run {
         Ok(())
     }
 
+    /// Returns whether the Comparison Generators in use are the ones that are
+    /// active by default with their default settings.
+    pub(crate) fn has_default_comparison_generators(&self) -> bool {
+        self.comparison_generators == ComparisonGenerators(default_generators())
+    }
+
     /// Recalculates all the comparison times the Comparison Generators provide.
     #[inline]
     pub fn regenerate_comparisons(&mut self) {
@@ -541,9 +652,11 @@ impl Run {
         self.reattach_unattached_segment_history_elements();
     }
 
-    /// Clears out the Attempt History and the Segment Histories of all the segments.
+    /// Clears out the Attempt History, the Segment Histories of all the
+    /// segments and the sessions.
     pub fn clear_history(&mut self) {
         self.attempt_history.clear();
+        self.sessions.clear();
         for segment in &mut self.segments {
             segment.segment_history_mut().clear();
         }
@@ -32,7 +32,7 @@ use super::{
     source_live_timer, speedrun_igt, splits_io, splitterino, splitterz, splitty,
     time_split_tracker, urn, wsplit, TimerKind,
 };
-use crate::{platform::path::Path, Run};
+use crate::{comparison::Registry, platform::path::Path, Run};
 use core::{result::Result as StdResult, str};
 
 /// The Error type for splits files that couldn't be parsed by the Composite
@@ -95,9 +95,21 @@ pub fn parse_and_fix<'source>(
 pub fn parse<'source>(
     source: &'source [u8],
     load_files_path: Option<&Path>,
+) -> Result<ParsedRun<'source>> {
+    parse_with_registry(source, load_files_path, &Registry::new())
+}
+
+/// Attempts to parse a splits file by invoking the corresponding parser for the
+/// file format detected. Unlike [`parse`], the Comparison Generators of the
+/// splits file are recreated from the registry provided, so kinds of Comparison
+/// Generators registered at runtime can be loaded as well.
+pub fn parse_with_registry<'source>(
+    source: &'source [u8],
+    load_files_path: Option<&Path>,
+    registry: &Registry,
 ) -> Result<ParsedRun<'source>> {
     if let Ok(source) = simdutf8::basic::from_utf8(source) {
-        if let Ok(run) = livesplit::parse(source) {
+        if let Ok(run) = livesplit::parse_with_registry(source, registry) {
             return Ok(parsed(run, TimerKind::LiveSplit));
         }
 
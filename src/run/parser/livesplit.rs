@@ -2,13 +2,14 @@
 
 use super::super::ComparisonError;
 use crate::{
+    comparison::{registry::Unresolved, Registry},
     platform::prelude::*,
-    run::LinkedLayout,
+    run::{LinkedLayout, Session},
     util::xml::{
         helper::{
             attribute, attribute_escaped_err, end_tag, optional_attribute_escaped_err,
             parse_attributes, parse_base, parse_children, reencode_children, text,
-            text_as_escaped_string_err, text_parsed, Error as XmlError,
+            text_as_escaped_string_err, text_err, text_parsed, Error as XmlError,
         },
         Reader,
     },
@@ -51,6 +52,8 @@ pub enum Error {
     },
     /// Failed to parse a boolean.
     ParseBool,
+    /// Failed to parse the setting of a comparison generator.
+    ParseSetting,
 }
 
 impl From<XmlError> for Error {
@@ -292,6 +295,7 @@ fn parse_segment(
     parse_children(reader, |reader, tag, _| match tag.name() {
         "Name" => text(reader, |t| segment.set_name(t)),
         "Icon" => image(reader, image_buf, |i| segment.set_icon(i)),
+        "Notes" => text(reader, |t| segment.set_notes(t)),
         "SplitTimes" => {
             if version >= Version(1, 3, 0, 0) {
                 parse_children(reader, |reader, tag, attributes| {
@@ -427,8 +431,86 @@ fn parse_attempt_history(version: Version, reader: &mut Reader<'_>, run: &mut Ru
     }
 }
 
-/// Attempts to parse a LiveSplit splits file.
+fn parse_sessions(reader: &mut Reader<'_>, run: &mut Run) -> Result<()> {
+    parse_children(reader, |reader, _, attributes| {
+        let (mut started, mut started_synced) = (None, false);
+        let (mut ended, mut ended_synced) = (None, false);
+
+        type_hint(parse_attributes(attributes, |k, v| {
+            match k {
+                "started" => started = Some(parse_date_time(v.escaped())?),
+                "isStartedSynced" => started_synced = parse_bool(v.escaped())?,
+                "ended" => ended = Some(parse_date_time(v.escaped())?),
+                "isEndedSynced" => ended_synced = parse_bool(v.escaped())?,
+                _ => {}
+            }
+            Ok(true)
+        }))?;
+
+        let started = started.ok_or(Error::Xml {
+            source: XmlError::AttributeNotFound,
+        })?;
+
+        run.push_session(Session::new(
+            AtomicDateTime::new(started, started_synced),
+            ended.map(|t| AtomicDateTime::new(t, ended_synced)),
+        ));
+
+        end_tag(reader)
+    })
+}
+
+fn parse_comparison_generators(
+    reader: &mut Reader<'_>,
+    registry: &Registry,
+    run: &mut Run,
+) -> Result<()> {
+    run.comparison_generators_mut().clear();
+
+    parse_children(reader, |reader, _, attributes| {
+        let (mut kind, mut name) = (Cow::Borrowed(""), Cow::Borrowed(""));
+        type_hint(attribute(attributes, "kind", |t| kind = t))?;
+        type_hint(attribute(attributes, "name", |t| name = t))?;
+
+        let mut settings = Vec::new();
+        type_hint(parse_children(reader, |reader, tag, _| {
+            if tag.name() == "Setting" {
+                text_err(reader, |text| {
+                    let value = serde_json::from_str(&text).map_err(|_| Error::ParseSetting)?;
+                    settings.push(value);
+                    Ok(())
+                })
+            } else {
+                end_tag(reader)
+            }
+        }))?;
+
+        let generator = registry
+            .create_with_settings(&kind, settings.iter().cloned())
+            .unwrap_or_else(|| {
+                Box::new(Unresolved::new(
+                    kind.into_owned(),
+                    name.into_owned(),
+                    settings,
+                ))
+            });
+
+        run.comparison_generators_mut().push(generator);
+
+        Ok(())
+    })
+}
+
+/// Attempts to parse a LiveSplit splits file. The Comparison Generators are
+/// recreated with the Comparison Generators provided by this crate.
 pub fn parse(source: &str) -> Result<Run> {
+    parse_with_registry(source, &Registry::new())
+}
+
+/// Attempts to parse a LiveSplit splits file. The Comparison Generators are
+/// recreated from the registry provided. Kinds of Comparison Generators that
+/// are not registered are kept as [`Unresolved`] Comparison Generators.
+pub fn parse_with_registry(source: &str, registry: &Registry) -> Result<Run> {
     let mut reader = Reader::new(source);
 
     let mut image_buf = Vec::new();
@@ -467,6 +549,7 @@ pub fn parse(source: &str) -> Result<Run> {
             }
             "AttemptHistory" => parse_attempt_history(version, reader, &mut run),
             "RunHistory" => parse_run_history(version, reader, &mut run),
+            "Sessions" => parse_sessions(reader, &mut run),
             "Metadata" => parse_metadata(version, reader, run.metadata_mut()),
             "Segments" => {
                 required_flags |= 1 << 5;
@@ -480,6 +563,7 @@ pub fn parse(source: &str) -> Result<Run> {
                     }
                 })
             }
+            "ComparisonGenerators" => parse_comparison_generators(reader, registry, &mut run),
             "AutoSplitterSettings" => {
                 let settings = run.auto_splitter_settings_mut();
                 reencode_children(reader, settings).map_err(Into::into)
@@ -271,11 +271,32 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
             },
         )?;
 
+        if !run.sessions().is_empty() {
+            scoped_iter(writer, "Sessions", run.sessions(), |writer, session| {
+                writer.tag("Session", |mut tag| {
+                    date(&mut tag, "started", session.started().time)?;
+                    tag.attribute(
+                        "isStartedSynced",
+                        bool(session.started().synced_with_atomic_clock),
+                    )?;
+                    if let Some(ended) = session.ended() {
+                        date(&mut tag, "ended", ended.time)?;
+                        tag.attribute("isEndedSynced", bool(ended.synced_with_atomic_clock))?;
+                    }
+                    Ok(())
+                })
+            })?;
+        }
+
         scoped_iter(writer, "Segments", run.segments(), |writer, segment| {
             writer.tag_with_content("Segment", NO_ATTRIBUTES, |writer| {
                 writer.tag_with_text_content("Name", NO_ATTRIBUTES, segment.name())?;
                 image(writer, "Icon", segment.icon(), base64_buf, image_buf)?;
 
+                if !segment.notes().is_empty() {
+                    writer.tag_with_text_content("Notes", NO_ATTRIBUTES, segment.notes())?;
+                }
+
                 scoped_iter(
                     writer,
                     "SplitTimes",
@@ -306,6 +327,36 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
             })
         })?;
 
+        if !run.has_default_comparison_generators() {
+            scoped_iter(
+                writer,
+                "ComparisonGenerators",
+                run.comparison_generators(),
+                |writer, generator| {
+                    writer.tag("ComparisonGenerator", |mut tag| {
+                        tag.attribute("kind", generator.kind())?;
+                        tag.attribute("name", generator.name())?;
+                        let fields = generator.settings_description().fields;
+                        if fields.is_empty() {
+                            return Ok(());
+                        }
+                        tag.content(|writer| {
+                            for field in fields {
+                                let value =
+                                    serde_json::to_string(&field.value).map_err(|_| fmt::Error)?;
+                                writer.tag_with_text_content(
+                                    "Setting",
+                                    NO_ATTRIBUTES,
+                                    value.as_str(),
+                                )?;
+                            }
+                            Ok(())
+                        })
+                    })
+                },
+            )?;
+        }
+
         writer.tag_with_text_content(
             "AutoSplitterSettings",
             NO_ATTRIBUTES,
@@ -0,0 +1,69 @@
+use super::Segment;
+
+/// A `Section` groups consecutive segments of a run, following the naming
+/// convention of LiveSplit's Subsplits. Segments whose names start with `-` are
+/// subsplits and belong to the section of the next segment that isn't a
+/// subsplit. That segment ends the section and may specify the section's name
+/// in curly braces at the beginning of its own name, such as `{World 1}Boss`.
+/// Segments without any subsplits before them form a section on their own.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Section {
+    start: usize,
+    end: usize,
+}
+
+impl Section {
+    /// Creates a new `Section` that covers all the segments from the start
+    /// index up to and including the end index.
+    pub const fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the index of the first segment of the section.
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the index of the last segment of the section. This is the
+    /// segment that ends the section.
+    pub const fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns whether the section consists of more than a single segment.
+    pub const fn has_subsplits(&self) -> bool {
+        self.start < self.end
+    }
+
+    /// Returns whether the segment with the index provided belongs to the
+    /// section.
+    pub const fn contains(&self, segment_index: usize) -> bool {
+        segment_index >= self.start && segment_index <= self.end
+    }
+
+    /// Returns the name of the section. This is the name specified in curly
+    /// braces by the segment ending the section. If there is none, the name of
+    /// the segment ending the section is used instead.
+    pub fn name<'s>(&self, segments: &'s [Segment]) -> &'s str {
+        let segment = &segments[self.end];
+        segment
+            .section_name()
+            .unwrap_or_else(|| segment.display_name())
+    }
+}
+
+/// Iterates over all the sections of the segments provided.
+pub(super) fn sections(segments: &[Segment]) -> impl Iterator<Item = Section> + '_ {
+    let mut start = 0;
+    segments
+        .iter()
+        .enumerate()
+        .filter_map(move |(index, segment)| {
+            if segment.is_subsplit() && index + 1 != segments.len() {
+                return None;
+            }
+            let section = Section::new(start, index);
+            start = index + 1;
+            Some(section)
+        })
+}
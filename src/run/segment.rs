@@ -8,7 +8,8 @@ use crate::{
 
 /// A `Segment` describes a point in a speedrun that is suitable for storing a
 /// split time. This stores the name of that `Segment`, an icon, the split times
-/// of different comparisons, and a history of segment times.
+/// of different comparisons, a history of segment times and the runner's notes
+/// for the segment.
 ///
 /// # Examples
 ///
@@ -24,6 +25,7 @@ use crate::{
 pub struct Segment {
     name: String,
     icon: Image,
+    notes: String,
     best_segment_time: Time,
     split_time: Time,
     segment_history: SegmentHistory,
@@ -49,6 +51,35 @@ impl Segment {
         &self.name
     }
 
+    /// Accesses the name of the segment without the markers used for grouping
+    /// segments into sections. Subsplits start with `-` and the segment ending
+    /// a section may start with the section's name in curly braces. Both of
+    /// these are stripped.
+    pub fn display_name(&self) -> &str {
+        let name = self.name.strip_prefix('-').unwrap_or(&self.name);
+        match name.strip_prefix('{').and_then(|rest| rest.split_once('}')) {
+            Some((_, name)) => name,
+            None => name,
+        }
+    }
+
+    /// Returns whether the segment is a subsplit, which is the case if its name
+    /// starts with `-`. Subsplits are grouped into a section together with the
+    /// next segment that isn't a subsplit.
+    pub fn is_subsplit(&self) -> bool {
+        self.name.starts_with('-')
+    }
+
+    /// Accesses the name of the section that this segment ends, if its name
+    /// starts with a section name in curly braces, such as `{World 1}Boss`.
+    pub fn section_name(&self) -> Option<&str> {
+        if self.is_subsplit() {
+            return None;
+        }
+        let (section_name, _) = self.name.strip_prefix('{')?.split_once('}')?;
+        Some(section_name)
+    }
+
     /// Sets the name of the segment.
     #[inline]
     pub fn set_name<S>(&mut self, name: S)
@@ -70,6 +101,23 @@ impl Segment {
         self.icon = image.into();
     }
 
+    /// Accesses the notes of the segment. These are arbitrary, potentially
+    /// multi-line, notes the runner stores for the segment, like route notes.
+    /// They may be formatted as Markdown.
+    #[inline]
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+
+    /// Sets the notes of the segment.
+    #[inline]
+    pub fn set_notes<S>(&mut self, notes: S)
+    where
+        S: PopulateString,
+    {
+        notes.populate(&mut self.notes);
+    }
+
     /// Grants mutable access to the comparison times stored in the Segment.
     /// This includes both the custom comparisons and the generated ones.
     #[inline]
@@ -9,10 +9,34 @@ use core::{
 /// with an index larger than 0 are considered times actually achieved by the
 /// runner, while the others are artifacts of route changes and similar
 /// algorithmic changes.
-#[derive(Clone, Default, Debug, PartialEq, Eq)]
-pub struct SegmentHistory(Vec<(i32, Time)>);
+///
+/// Additionally a Segment History counts its revisions, which allows detecting
+/// that it got modified. The revision is not considered when comparing Segment
+/// Histories.
+#[derive(Clone, Default, Debug)]
+pub struct SegmentHistory(Vec<(i32, Time)>, usize);
+
+impl PartialEq for SegmentHistory {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for SegmentHistory {}
 
 impl SegmentHistory {
+    /// Accesses the revision of the Segment History. The revision increases
+    /// whenever the Segment History is modified, so it can be used to detect
+    /// changes.
+    #[inline]
+    pub const fn revision(&self) -> usize {
+        self.1
+    }
+
+    fn modified(&mut self) {
+        self.1 = self.1.wrapping_add(1);
+    }
+
     /// Returns the minimum index of all the segment times. Returns `None` if
     /// there's no segment times in this history.
     pub fn try_get_min_index(&self) -> Option<i32> {
@@ -47,6 +71,7 @@ impl SegmentHistory {
     pub fn insert(&mut self, index: i32, time: Time) {
         if let Err(pos) = self.get_pos(index) {
             self.0.insert(pos, (index, time));
+            self.modified();
         }
     }
 
@@ -63,6 +88,7 @@ impl SegmentHistory {
     #[inline]
     pub fn get_mut(&mut self, index: i32) -> Option<&mut Time> {
         let pos = self.get_pos(index).ok()?;
+        self.modified();
         Some(&mut self.0.get_mut(pos)?.1)
     }
 
@@ -71,6 +97,7 @@ impl SegmentHistory {
     #[inline]
     pub fn remove(&mut self, index: i32) -> Option<Time> {
         let pos = self.get_pos(index).ok()?;
+        self.modified();
         Some(self.0.remove(pos).1)
     }
 
@@ -78,6 +105,7 @@ impl SegmentHistory {
     #[inline]
     pub fn clear(&mut self) {
         self.0.clear();
+        self.modified();
     }
 
     /// Removes all the segment times from the Segment History, where the given
@@ -88,6 +116,7 @@ impl SegmentHistory {
         F: FnMut(&(i32, Time)) -> bool,
     {
         self.0.retain(f);
+        self.modified();
     }
 
     /// Iterates over all the segment times and their indices.
@@ -104,6 +133,7 @@ impl SegmentHistory {
     /// stay in rising order.
     #[inline]
     pub fn iter_mut(&mut self) -> IterMut<'_, (i32, Time)> {
+        self.modified();
         self.0.iter_mut()
     }
 
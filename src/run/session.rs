@@ -0,0 +1,41 @@
+use crate::AtomicDateTime;
+
+/// A `Session` describes a period of time the runner explicitly declared as a
+/// single play session. Sessions are started and ended through the
+/// [`Timer`](crate::Timer). All the attempts that were started while a session
+/// was active belong to that session.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Session {
+    started: AtomicDateTime,
+    ended: Option<AtomicDateTime>,
+}
+
+impl Session {
+    /// Creates a new `Session` that started at the point in time provided. If
+    /// the session already ended, the point in time it ended at can be
+    /// provided as well.
+    pub const fn new(started: AtomicDateTime, ended: Option<AtomicDateTime>) -> Self {
+        Self { started, ended }
+    }
+
+    /// Returns the point in time the session was started at.
+    pub const fn started(&self) -> AtomicDateTime {
+        self.started
+    }
+
+    /// Returns the point in time the session ended at. This is `None` if the
+    /// session is still active.
+    pub const fn ended(&self) -> Option<AtomicDateTime> {
+        self.ended
+    }
+
+    /// Returns whether the session is still active.
+    pub const fn is_active(&self) -> bool {
+        self.ended.is_none()
+    }
+
+    /// Returns whether the point in time provided lies within the session.
+    pub fn contains(&self, time: AtomicDateTime) -> bool {
+        time.time >= self.started.time && self.ended.map_or(true, |ended| time.time < ended.time)
+    }
+}
@@ -4,3 +4,5 @@ mod extended_category_name;
 mod fixing;
 mod linked_layout;
 mod metadata;
+mod segment_history;
+mod sessions;
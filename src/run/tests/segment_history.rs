@@ -0,0 +1,49 @@
+use crate::{SegmentHistory, Time, TimeSpan};
+
+fn time(seconds: f64) -> Time {
+    Time::new().with_game_time(Some(TimeSpan::from_seconds(seconds)))
+}
+
+#[test]
+fn modifications_increase_the_revision() {
+    let mut history = SegmentHistory::default();
+    let mut revision = history.revision();
+
+    history.insert(1, time(10.0));
+    assert_ne!(history.revision(), revision);
+    revision = history.revision();
+
+    history.insert(1, time(20.0));
+    assert_eq!(history.revision(), revision);
+
+    history.get_mut(1).unwrap().game_time = Some(TimeSpan::from_seconds(15.0));
+    assert_ne!(history.revision(), revision);
+    revision = history.revision();
+
+    history.remove(1);
+    assert_ne!(history.revision(), revision);
+}
+
+#[test]
+fn reading_keeps_the_revision() {
+    let mut history = SegmentHistory::default();
+    history.insert(1, time(10.0));
+    let revision = history.revision();
+
+    assert!(history.get(1).is_some());
+    assert_eq!(history.iter().count(), 1);
+    assert_eq!(history.revision(), revision);
+}
+
+#[test]
+fn the_revision_is_ignored_for_equality() {
+    let mut history = SegmentHistory::default();
+    history.insert(1, time(10.0));
+
+    let mut modified = history.clone();
+    modified.remove(1);
+    modified.insert(1, time(10.0));
+
+    assert_ne!(modified.revision(), history.revision());
+    assert_eq!(modified, history);
+}
@@ -0,0 +1,49 @@
+use crate::{
+    platform::{prelude::*, DateTime},
+    run::{parser, saver, Session},
+    AtomicDateTime, Run, Segment,
+};
+
+fn at(seconds: i64) -> AtomicDateTime {
+    AtomicDateTime::new(DateTime::from_unix_timestamp(seconds).unwrap(), false)
+}
+
+fn save(run: &Run) -> String {
+    let mut buf = String::new();
+    saver::livesplit::save_run(run, &mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn starting_a_session_ends_the_active_one() {
+    let mut run = Run::new();
+    run.start_session(at(1000));
+    run.start_session(at(2000));
+    run.end_session(at(3000));
+    run.end_session(at(4000));
+
+    assert_eq!(
+        run.sessions(),
+        [
+            Session::new(at(1000), Some(at(2000))),
+            Session::new(at(2000), Some(at(3000))),
+        ]
+    );
+    assert!(run.sessions()[0].contains(at(1500)));
+    assert!(!run.sessions()[0].contains(at(2000)));
+}
+
+#[test]
+fn sessions_are_only_saved_when_there_are_any() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("s"));
+    assert!(!save(&run).contains("<Sessions"));
+
+    run.start_session(at(1_600_000_000));
+    run.end_session(at(1_600_003_600));
+    run.start_session(at(1_600_100_000));
+
+    let parsed = parser::livesplit::parse(&save(&run)).unwrap();
+    assert_eq!(parsed.sessions(), run.sessions());
+    assert!(parsed.sessions()[1].is_active());
+}
@@ -51,33 +51,40 @@ impl Serialize for ImageData {
     where
         S: Serializer,
     {
-        if serializer.is_human_readable() {
-            if !self.0.is_empty() {
-                let mut buf = String::from("data:;base64,");
-
-                // SAFETY: We encode Base64 to the end of the string, which is
-                // always valid UTF-8. Once we've written it, we simply increase
-                // the length of the buffer by the amount of bytes written.
-                unsafe {
-                    let buf = buf.as_mut_vec();
-                    let encoded_len = base64_simd::STANDARD.encoded_length(self.0.len());
-                    buf.reserve_exact(encoded_len);
-                    let additional_len = base64_simd::STANDARD
-                        .encode(
-                            &self.0,
-                            base64_simd::Out::from_uninit_slice(buf.spare_capacity_mut()),
-                        )
-                        .len();
-                    buf.set_len(buf.len() + additional_len);
-                }
-
-                serializer.serialize_str(&buf)
-            } else {
-                serializer.serialize_str("")
+        serialize_data(&self.0, serializer)
+    }
+}
+
+fn serialize_data<S>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        if !data.is_empty() {
+            let mut buf = String::from("data:;base64,");
+
+            // SAFETY: We encode Base64 to the end of the string, which is
+            // always valid UTF-8. Once we've written it, we simply increase
+            // the length of the buffer by the amount of bytes written.
+            unsafe {
+                let buf = buf.as_mut_vec();
+                let encoded_len = base64_simd::STANDARD.encoded_length(data.len());
+                buf.reserve_exact(encoded_len);
+                let additional_len = base64_simd::STANDARD
+                    .encode(
+                        data,
+                        base64_simd::Out::from_uninit_slice(buf.spare_capacity_mut()),
+                    )
+                    .len();
+                buf.set_len(buf.len() + additional_len);
             }
+
+            serializer.serialize_str(&buf)
         } else {
-            serializer.serialize_bytes(&self.0)
+            serializer.serialize_str("")
         }
+    } else {
+        serializer.serialize_bytes(data)
     }
 }
 
@@ -105,6 +112,25 @@ impl<'de> Deserialize<'de> for ImageData {
     }
 }
 
+impl Serialize for Image {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_data(&self.data, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Image {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = ImageData::deserialize(deserializer)?;
+        Ok(Image::new(&data))
+    }
+}
+
 impl PartialEq for Image {
     fn eq(&self, other: &Image) -> bool {
         self.id == other.id || self.data == other.data
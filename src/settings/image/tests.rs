@@ -1,7 +1,18 @@
-use super::ImageData;
+use super::{Image, ImageData};
 
 #[test]
 fn serializes_to_json_as_data_url() {
     let json = serde_json::to_string(&ImageData(vec![1, 2, 3].into_boxed_slice())).unwrap();
     assert_eq!(r#""data:;base64,AQID""#, json);
 }
+
+#[test]
+fn images_roundtrip_through_json() {
+    let image = Image::new(&[1, 2, 3]);
+    let json = serde_json::to_string(&image).unwrap();
+    assert_eq!(r#""data:;base64,AQID""#, json);
+
+    let deserialized: Image = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.data(), image.data());
+    assert_ne!(deserialized.id(), image.id());
+}
@@ -1,14 +1,22 @@
 use crate::{
     component::{
-        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
-        timer::DeltaGradient,
+        attempt_counter::AttemptStatistic,
+        clock::ClockMode,
+        graph::LineStyle,
+        image::{AspectMode, ImageAlignment, ImageMode},
+        session::SessionStatistic,
+        splits::{
+            ChartKind, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, SubsplitVisibility,
+        },
+        timer::{CountdownMode, DeltaGradient},
+        total_playtime::PlaytimeScope,
     },
     hotkey::Hotkey,
     layout::LayoutDirection,
     platform::prelude::*,
-    settings::{Alignment, Color, Font, Gradient, ListGradient},
+    settings::{Alignment, Color, Font, Gradient, Image, ListGradient},
     timing::formatter::{Accuracy, DigitsFormat},
-    TimingMethod,
+    TimeSpan, TimingMethod,
 };
 use core::result::Result as StdResult;
 use serde::{Deserialize, Serialize};
@@ -20,6 +28,8 @@ pub enum ColumnKind {
     Time,
     /// The column shows a variable.
     Variable,
+    /// The column shows a chart of the recent segment times.
+    Chart,
 }
 
 /// Describes a setting's value. Such a value can be of a variety of different
@@ -74,6 +84,37 @@ pub enum Value {
     /// A gradient that may or may not take one of it's colors from the current
     /// delta.
     DeltaGradient(DeltaGradient),
+    /// A value describing which attempts the playtime shown by the Total
+    /// Playtime Component is based on.
+    PlaytimeScope(PlaytimeScope),
+    /// A value describing which statistic about the current session the
+    /// Session Component shows.
+    SessionStatistic(SessionStatistic),
+    /// A value describing when the subsplits of the sections are shown by the
+    /// Splits Component.
+    SubsplitVisibility(SubsplitVisibility),
+    /// A value describing the style of a line shown by the Graph Component.
+    LineStyle(LineStyle),
+    /// A value describing the kind of chart shown by a chart column of the
+    /// Splits Component.
+    ChartKind(ChartKind),
+    /// A value describing what the Timer Component counts down to.
+    CountdownMode(CountdownMode),
+    /// An optional time.
+    OptionalTimeSpan(Option<TimeSpan>),
+    /// A value describing what the Clock Component shows.
+    ClockMode(ClockMode),
+    /// A value describing which count the Attempt Counter Component shows.
+    AttemptStatistic(AttemptStatistic),
+    /// An image.
+    Image(Image),
+    /// A value describing which image the Image Component shows.
+    ImageMode(ImageMode),
+    /// A value describing how the image is fit into the space of the Image
+    /// Component.
+    AspectMode(AspectMode),
+    /// A value describing how the image of the Image Component is aligned.
+    ImageAlignment(ImageAlignment),
 }
 
 impl From<bool> for Value {
@@ -196,12 +237,90 @@ impl From<DeltaGradient> for Value {
     }
 }
 
+impl From<PlaytimeScope> for Value {
+    fn from(x: PlaytimeScope) -> Self {
+        Value::PlaytimeScope(x)
+    }
+}
+
+impl From<SessionStatistic> for Value {
+    fn from(x: SessionStatistic) -> Self {
+        Value::SessionStatistic(x)
+    }
+}
+
+impl From<SubsplitVisibility> for Value {
+    fn from(x: SubsplitVisibility) -> Self {
+        Value::SubsplitVisibility(x)
+    }
+}
+
 impl From<ColumnKind> for Value {
     fn from(x: ColumnKind) -> Self {
         Value::ColumnKind(x)
     }
 }
 
+impl From<LineStyle> for Value {
+    fn from(x: LineStyle) -> Self {
+        Value::LineStyle(x)
+    }
+}
+
+impl From<ChartKind> for Value {
+    fn from(x: ChartKind) -> Self {
+        Value::ChartKind(x)
+    }
+}
+
+impl From<CountdownMode> for Value {
+    fn from(x: CountdownMode) -> Self {
+        Value::CountdownMode(x)
+    }
+}
+
+impl From<Option<TimeSpan>> for Value {
+    fn from(x: Option<TimeSpan>) -> Self {
+        Value::OptionalTimeSpan(x)
+    }
+}
+
+impl From<ClockMode> for Value {
+    fn from(x: ClockMode) -> Self {
+        Value::ClockMode(x)
+    }
+}
+
+impl From<AttemptStatistic> for Value {
+    fn from(x: AttemptStatistic) -> Self {
+        Value::AttemptStatistic(x)
+    }
+}
+
+impl From<Image> for Value {
+    fn from(x: Image) -> Self {
+        Value::Image(x)
+    }
+}
+
+impl From<ImageMode> for Value {
+    fn from(x: ImageMode) -> Self {
+        Value::ImageMode(x)
+    }
+}
+
+impl From<AspectMode> for Value {
+    fn from(x: AspectMode) -> Self {
+        Value::AspectMode(x)
+    }
+}
+
+impl From<ImageAlignment> for Value {
+    fn from(x: ImageAlignment) -> Self {
+        Value::ImageAlignment(x)
+    }
+}
+
 /// The Error type for values that couldn't be converted.
 #[derive(Debug, snafu::Snafu)]
 pub enum Error {
@@ -385,6 +504,30 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into a playtime scope.
+    pub fn into_playtime_scope(self) -> Result<PlaytimeScope> {
+        match self {
+            Value::PlaytimeScope(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a session statistic.
+    pub fn into_session_statistic(self) -> Result<SessionStatistic> {
+        match self {
+            Value::SessionStatistic(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a subsplit visibility.
+    pub fn into_subsplit_visibility(self) -> Result<SubsplitVisibility> {
+        match self {
+            Value::SubsplitVisibility(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into a column kind.
     pub fn into_column_kind(self) -> Result<ColumnKind> {
         match self {
@@ -392,6 +535,86 @@ impl Value {
             _ => Err(Error::WrongType),
         }
     }
+
+    /// Tries to convert the value into a line style.
+    pub fn into_line_style(self) -> Result<LineStyle> {
+        match self {
+            Value::LineStyle(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a chart kind.
+    pub fn into_chart_kind(self) -> Result<ChartKind> {
+        match self {
+            Value::ChartKind(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a countdown mode.
+    pub fn into_countdown_mode(self) -> Result<CountdownMode> {
+        match self {
+            Value::CountdownMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an optional time.
+    pub fn into_optional_time_span(self) -> Result<Option<TimeSpan>> {
+        match self {
+            Value::OptionalTimeSpan(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a clock mode.
+    pub fn into_clock_mode(self) -> Result<ClockMode> {
+        match self {
+            Value::ClockMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an attempt statistic.
+    pub fn into_attempt_statistic(self) -> Result<AttemptStatistic> {
+        match self {
+            Value::AttemptStatistic(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an image.
+    pub fn into_image(self) -> Result<Image> {
+        match self {
+            Value::Image(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an image mode.
+    pub fn into_image_mode(self) -> Result<ImageMode> {
+        match self {
+            Value::ImageMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an aspect mode.
+    pub fn into_aspect_mode(self) -> Result<AspectMode> {
+        match self {
+            Value::AspectMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an image alignment.
+    pub fn into_image_alignment(self) -> Result<ImageAlignment> {
+        match self {
+            Value::ImageAlignment(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
 }
 
 impl From<Value> for bool {
@@ -514,8 +737,86 @@ impl From<Value> for DeltaGradient {
     }
 }
 
+impl From<Value> for PlaytimeScope {
+    fn from(value: Value) -> Self {
+        value.into_playtime_scope().unwrap()
+    }
+}
+
+impl From<Value> for SessionStatistic {
+    fn from(value: Value) -> Self {
+        value.into_session_statistic().unwrap()
+    }
+}
+
+impl From<Value> for SubsplitVisibility {
+    fn from(value: Value) -> Self {
+        value.into_subsplit_visibility().unwrap()
+    }
+}
+
 impl From<Value> for ColumnKind {
     fn from(value: Value) -> Self {
         value.into_column_kind().unwrap()
     }
 }
+
+impl From<Value> for LineStyle {
+    fn from(value: Value) -> Self {
+        value.into_line_style().unwrap()
+    }
+}
+
+impl From<Value> for ChartKind {
+    fn from(value: Value) -> Self {
+        value.into_chart_kind().unwrap()
+    }
+}
+
+impl From<Value> for CountdownMode {
+    fn from(value: Value) -> Self {
+        value.into_countdown_mode().unwrap()
+    }
+}
+
+impl From<Value> for Option<TimeSpan> {
+    fn from(value: Value) -> Self {
+        value.into_optional_time_span().unwrap()
+    }
+}
+
+impl From<Value> for ClockMode {
+    fn from(value: Value) -> Self {
+        value.into_clock_mode().unwrap()
+    }
+}
+
+impl From<Value> for AttemptStatistic {
+    fn from(value: Value) -> Self {
+        value.into_attempt_statistic().unwrap()
+    }
+}
+
+impl From<Value> for Image {
+    fn from(value: Value) -> Self {
+        value.into_image().unwrap()
+    }
+}
+
+impl From<Value> for ImageMode {
+    fn from(value: Value) -> Self {
+        value.into_image_mode().unwrap()
+    }
+}
+
+impl From<Value> for AspectMode {
+    fn from(value: Value) -> Self {
+        value.into_aspect_mode().unwrap()
+    }
+}
+
+impl From<Value> for ImageAlignment {
+    fn from(value: Value) -> Self {
+        value.into_image_alignment().unwrap()
+    }
+}
@@ -3,6 +3,7 @@ use crate::{
     util::ascii_char::AsciiChar,
 };
 use core::{
+    iter::Sum,
     num::ParseIntError,
     ops::{Add, AddAssign, Neg, Sub, SubAssign},
     str::FromStr,
@@ -190,6 +191,12 @@ impl SubAssign for TimeSpan {
     }
 }
 
+impl Sum for TimeSpan {
+    fn sum<I: Iterator<Item = TimeSpan>>(iter: I) -> TimeSpan {
+        iter.fold(TimeSpan::zero(), Add::add)
+    }
+}
+
 impl Neg for TimeSpan {
     type Output = TimeSpan;
     fn neg(self) -> TimeSpan {
@@ -198,7 +205,31 @@ impl Neg for TimeSpan {
 }
 
 use core::fmt;
-use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{
+    de::{self, Deserialize, Deserializer, Visitor},
+    Serialize, Serializer,
+};
+
+impl Serialize for TimeSpan {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // The time is stored as plain seconds, as that's what the parser can
+        // read back in without losing any precision.
+        let (seconds, nanoseconds) = self.to_seconds_and_subsec_nanoseconds();
+        let sign = if seconds < 0 || nanoseconds < 0 {
+            "-"
+        } else {
+            ""
+        };
+        serializer.collect_str(&format_args!(
+            "{sign}{}.{:09}",
+            seconds.unsigned_abs(),
+            nanoseconds.unsigned_abs(),
+        ))
+    }
+}
 
 impl<'de> Deserialize<'de> for TimeSpan {
     fn deserialize<D>(deserializer: D) -> Result<TimeSpan, D::Error>
@@ -264,4 +295,13 @@ mod tests {
             (10, 98765432)
         );
     }
+
+    #[test]
+    fn serialization_roundtrips() {
+        for time in ["-12:37:30.12", "30:00:00.000000001", "0", "-0.5"] {
+            let time = TimeSpan::from_str(time).unwrap();
+            let serialized = serde_json::to_string(&time).unwrap();
+            assert_eq!(serde_json::from_str::<TimeSpan>(&serialized).unwrap(), time);
+        }
+    }
 }
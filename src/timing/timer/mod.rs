@@ -431,6 +431,23 @@ impl Timer {
             || self.current_attempt_has_new_personal_best(self.current_timing_method)
     }
 
+    /// Starts a new play session. If there is a session that is still active,
+    /// it gets ended. All the attempts that are started from now on belong to
+    /// the new session until it gets ended.
+    pub fn start_session(&mut self) {
+        self.run.start_session(AtomicDateTime::now());
+        self.run.mark_as_modified();
+    }
+
+    /// Ends the play session that is still active. If there is no active
+    /// session, nothing happens.
+    pub fn end_session(&mut self) {
+        if self.run.sessions().last().is_some_and(|s| s.is_active()) {
+            self.run.end_session(AtomicDateTime::now());
+            self.run.mark_as_modified();
+        }
+    }
+
     /// Resets the current attempt if there is one in progress. If the splits
     /// are to be updated, all the information of the current attempt is stored
     /// in the Run's history. Otherwise the current attempt's information is
@@ -29,3 +29,21 @@ fn escaping_works_for_auto_splitter_settings() {
     run = parser::livesplit::parse(&buf).unwrap();
     assert_eq!(run.auto_splitter_settings(), "<Hi>A &lt; B</Hi>");
 }
+
+#[test]
+fn escaping_works_for_segment_notes() {
+    let mut run = Run::new();
+    let mut segment = Segment::new("A");
+    segment.set_notes("Jump < Dash\n- Grab the key\n\n- Skip the cutscene");
+    run.push_segment(segment);
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(buf.contains("Jump &lt; Dash"));
+
+    run = parser::livesplit::parse(&buf).unwrap();
+    assert_eq!(
+        run.segment(0).notes(),
+        "Jump < Dash\n- Grab the key\n\n- Skip the cutscene"
+    );
+}
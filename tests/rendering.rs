@@ -228,7 +228,7 @@ fn subsplits_layout() {
     check_dims(
         &layout.state(&timer.snapshot()),
         [300, 800],
-        "8/vz8/Pz/+c=",
+        "8/vz9////+c=",
         "subsplits_layout",
     );
 }